@@ -5,7 +5,10 @@ use binius_field::{packed::set_packed_slice, BinaryField1b, BinaryField32b, Fiel
 use binius_macros::arith_expr;
 use binius_maybe_rayon::prelude::*;
 
-use crate::{builder::ConstraintSystemBuilder, transparent};
+use crate::{
+	builder::{types::F, ConstraintSystemBuilder},
+	transparent,
+};
 
 pub fn packed(
 	builder: &mut ConstraintSystemBuilder,
@@ -262,6 +265,135 @@ pub fn shr(
 	Ok(shifted)
 }
 
+/// Rotates each `B32` value of `input` left by `N` bits.
+///
+/// Bit rotation is a linear map over GF(2), so it needs no lookup: the input is decomposed into
+/// committed bits that are constrained to pack back into it, the bits are rotated with a circular
+/// shift, and the rotated bits are packed into the `B32` output.
+pub fn rotl32<const N: u32>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	if N % 32 == 0 {
+		return Ok(input);
+	}
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	let bits = builder.add_committed("bits", log_rows + 5, BinaryField1b::TOWER_LEVEL);
+	let rotated =
+		builder.add_shifted("rotated", bits, (N % 32) as usize, 5, ShiftVariant::CircularLeft)?;
+	if let Some(witness) = builder.witness() {
+		let input = witness.get::<BinaryField32b>(input)?.as_slice::<u32>();
+		let mut bits = witness.new_column::<BinaryField1b>(bits);
+		let mut rotated = witness.new_column::<BinaryField1b>(rotated);
+		(bits.as_mut_slice::<u32>(), rotated.as_mut_slice::<u32>(), input)
+			.into_par_iter()
+			.for_each(|(bits, rotated, input)| {
+				*bits = *input;
+				*rotated = input.rotate_left(N);
+			});
+	}
+
+	let bits_packed = packed(builder, "bits_packed", bits)?;
+	builder.assert_zero(
+		"decompose",
+		[bits_packed, input],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+	let output = packed(builder, "output", rotated)?;
+
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Reverses the byte order of each `B32` value of `input`, converting between big-endian and
+/// little-endian words.
+///
+/// Byte reversal is a bit permutation and hence linear over GF(2): the input is decomposed into
+/// committed bits that are constrained to pack back into it, and the output is the linear
+/// combination of the projected bits with the basis elements of their byte-swapped positions.
+pub fn bswap32(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	let bits = builder.add_committed("bits", log_rows + 5, BinaryField1b::TOWER_LEVEL);
+	if let Some(witness) = builder.witness() {
+		let input = witness.get::<BinaryField32b>(input)?.as_slice::<u32>();
+		let mut bits = witness.new_column::<BinaryField1b>(bits);
+		(bits.as_mut_slice::<u32>(), input)
+			.into_par_iter()
+			.for_each(|(bits, input)| *bits = *input);
+	}
+
+	let bits_packed = packed(builder, "bits_packed", bits)?;
+	builder.assert_zero(
+		"decompose",
+		[bits_packed, input],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+
+	let terms = (0..32)
+		.map(|i| {
+			let bit = select_bit(builder, format!("bit{i}"), bits, i)?;
+			let swapped = (3 - i / 8) * 8 + i % 8;
+			Ok((bit, <F as TowerField>::basis(0, swapped)?))
+		})
+		.collect::<Result<Vec<_>, anyhow::Error>>()?;
+	let output = builder.add_linear_combination("output", log_rows, terms)?;
+	if let Some(witness) = builder.witness() {
+		let input = witness.get::<BinaryField32b>(input)?.as_slice::<u32>();
+		let mut output = witness.new_column::<BinaryField32b>(output);
+		(output.as_mut_slice::<u32>(), input)
+			.into_par_iter()
+			.for_each(|(output, input)| *output = input.swap_bytes());
+	}
+
+	builder.pop_namespace();
+	Ok(output)
+}
+
+/// Asserts that the `B32` values of `input`, read as unsigned integers, are non-decreasing.
+///
+/// The input is decomposed into committed bits constrained to pack back into it. Shifting the bits
+/// by a whole row aligns each value with its predecessor, with zero preceding the first row, and a
+/// checked subtraction of the predecessor from each value rules out any row smaller than the one
+/// before it. The gadget only constrains the column; the witness must already be sorted.
+pub fn assert_sorted(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<(), anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	let bits = builder.add_committed("bits", log_rows + 5, BinaryField1b::TOWER_LEVEL);
+	let prev = builder.add_shifted("prev", bits, 32, log_rows + 5, ShiftVariant::LogicalLeft)?;
+	if let Some(witness) = builder.witness() {
+		let input = witness.get::<BinaryField32b>(input)?.as_slice::<u32>();
+		let mut bits = witness.new_column::<BinaryField1b>(bits);
+		let mut prev = witness.new_column::<BinaryField1b>(prev);
+		bits.as_mut_slice::<u32>().copy_from_slice(input);
+		let prev = prev.as_mut_slice::<u32>();
+		prev[0] = 0;
+		prev[1..].copy_from_slice(&input[..input.len() - 1]);
+	}
+
+	let bits_packed = packed(builder, "bits_packed", bits)?;
+	builder.assert_zero(
+		"decompose",
+		[bits_packed, input],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+	sub(builder, "diff", bits, prev, super::Flags::Checked)?;
+
+	builder.pop_namespace();
+	Ok(())
+}
+
 pub fn select_bit(
 	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString,
@@ -330,9 +462,17 @@ pub fn constant(
 
 #[cfg(test)]
 mod tests {
-	use binius_field::{BinaryField1b, TowerField};
+	use std::iter::repeat_with;
 
-	use crate::{arithmetic, builder::test_utils::test_circuit, unconstrained::unconstrained};
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField1b, BinaryField32b, TowerField};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use crate::{
+		arithmetic,
+		builder::{test_utils::test_circuit, ConstraintSystemBuilder},
+		unconstrained::unconstrained,
+	};
 
 	#[test]
 	fn test_mul_const() {
@@ -373,4 +513,122 @@ mod tests {
 		})
 		.unwrap();
 	}
+
+	fn test_rotl32_by<const N: u32>() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let input = unconstrained::<BinaryField32b>(builder, "input", log_size)?;
+			let output = arithmetic::u32::rotl32::<N>(builder, "rotl32", input)?;
+			if let Some(witness) = builder.witness() {
+				let input = witness.get::<BinaryField32b>(input)?.as_slice::<u32>();
+				let output = witness.get::<BinaryField32b>(output)?.as_slice::<u32>();
+				for (&input, &output) in input.iter().zip(output) {
+					assert_eq!(output, input.rotate_left(N));
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_bswap32() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let input = unconstrained::<BinaryField32b>(builder, "input", log_size)?;
+			let output = arithmetic::u32::bswap32(builder, "bswap32", input)?;
+			if let Some(witness) = builder.witness() {
+				let input = witness.get::<BinaryField32b>(input)?.as_slice::<u32>();
+				let output = witness.get::<BinaryField32b>(output)?.as_slice::<u32>();
+				for (&input, &output) in input.iter().zip(output) {
+					assert_eq!(output, input.swap_bytes());
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	fn sorted_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		swap_adjacent: bool,
+	) -> Result<(), anyhow::Error> {
+		let log_size = 6;
+		let input = builder.add_committed("input", log_size, BinaryField32b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut values = repeat_with(|| rng.gen::<u32>())
+				.take(1 << log_size)
+				.collect::<Vec<_>>();
+			values.sort_unstable();
+			if swap_adjacent {
+				values.swap(10, 11);
+			}
+			witness
+				.new_column::<BinaryField32b>(input)
+				.as_mut_slice::<u32>()
+				.copy_from_slice(&values);
+		}
+		arithmetic::u32::assert_sorted(builder, "assert_sorted", input)
+	}
+
+	#[test]
+	fn test_assert_sorted() {
+		test_circuit(|builder| {
+			sorted_circuit(builder, false)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_assert_sorted_rejects_swapped_adjacent_elements() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		sorted_circuit(&mut builder, true).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+
+	#[test]
+	fn test_rotl32() {
+		let rotations: [fn(); 32] = [
+			test_rotl32_by::<0>,
+			test_rotl32_by::<1>,
+			test_rotl32_by::<2>,
+			test_rotl32_by::<3>,
+			test_rotl32_by::<4>,
+			test_rotl32_by::<5>,
+			test_rotl32_by::<6>,
+			test_rotl32_by::<7>,
+			test_rotl32_by::<8>,
+			test_rotl32_by::<9>,
+			test_rotl32_by::<10>,
+			test_rotl32_by::<11>,
+			test_rotl32_by::<12>,
+			test_rotl32_by::<13>,
+			test_rotl32_by::<14>,
+			test_rotl32_by::<15>,
+			test_rotl32_by::<16>,
+			test_rotl32_by::<17>,
+			test_rotl32_by::<18>,
+			test_rotl32_by::<19>,
+			test_rotl32_by::<20>,
+			test_rotl32_by::<21>,
+			test_rotl32_by::<22>,
+			test_rotl32_by::<23>,
+			test_rotl32_by::<24>,
+			test_rotl32_by::<25>,
+			test_rotl32_by::<26>,
+			test_rotl32_by::<27>,
+			test_rotl32_by::<28>,
+			test_rotl32_by::<29>,
+			test_rotl32_by::<30>,
+			test_rotl32_by::<31>,
+		];
+		for rotation in rotations {
+			rotation();
+		}
+	}
 }
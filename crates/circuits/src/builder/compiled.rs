@@ -0,0 +1,99 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{cell::RefCell, rc::Rc};
+
+use anyhow::anyhow;
+use binius_core::{constraint_system::ConstraintSystem, witness::MultilinearExtensionIndex};
+use binius_utils::bail;
+
+use super::{
+	types::{F, U},
+	witness,
+};
+
+/// A constraint system that has been built once and can be proven with many witnesses.
+///
+/// Building a circuit with [`ConstraintSystemBuilder`](super::ConstraintSystemBuilder) re-runs
+/// every gadget, both to add its oracles and constraints and to populate its witness columns. A
+/// compiled constraint system separates the two: the structure is built once in verifier mode
+/// with [`ConstraintSystemBuilder::compile`](super::ConstraintSystemBuilder::compile), and each
+/// witness is then populated against the frozen oracle set with [`Self::witness_builder`] and
+/// checked with [`Self::bind_witness`].
+///
+/// ## Scope
+///
+/// Only circuits made of committed and linear combination oracles can be compiled. Gadgets
+/// populate their witness columns inline, while adding their oracles to a
+/// [`ConstraintSystemBuilder`](super::ConstraintSystemBuilder), and that logic is not exposed
+/// separately, so the caller fills every column of a compiled system by hand. This is practical
+/// for committed columns and their linear combinations, but not for the transparent, shifted,
+/// projected or packed columns that lookup-based gadgets such as `u8mul` rely on. Such circuits
+/// are proven by rebuilding them with a witness-mode builder.
+#[derive(Debug, Clone)]
+pub struct CompiledConstraintSystem {
+	constraint_system: ConstraintSystem<F>,
+}
+
+/// A compiled constraint system together with a witness for all of its oracles.
+pub struct BoundConstraintSystem<'a, 'arena> {
+	pub constraint_system: &'a ConstraintSystem<F>,
+	pub witness: MultilinearExtensionIndex<'arena, U, F>,
+}
+
+impl CompiledConstraintSystem {
+	pub(super) const fn new(constraint_system: ConstraintSystem<F>) -> Self {
+		Self { constraint_system }
+	}
+
+	pub const fn constraint_system(&self) -> &ConstraintSystem<F> {
+		&self.constraint_system
+	}
+
+	pub fn into_constraint_system(self) -> ConstraintSystem<F> {
+		self.constraint_system
+	}
+
+	/// Returns a witness builder over the oracles of the compiled constraint system.
+	///
+	/// The oracle ids returned while compiling the circuit stay valid, so columns can be allocated
+	/// with [`witness::Builder::new_column`] without re-adding any oracles or constraints.
+	///
+	/// Every column, including the internal columns of gadgets, must be populated by the caller;
+	/// see the scope documented on [`CompiledConstraintSystem`].
+	pub fn witness_builder<'arena>(
+		&self,
+		allocator: &'arena bumpalo::Bump,
+	) -> witness::Builder<'arena> {
+		let oracles = Rc::new(RefCell::new(self.constraint_system.oracles.clone()));
+		witness::Builder::new(allocator, oracles)
+	}
+
+	/// Binds a witness to the compiled constraint system.
+	///
+	/// The witness must contain a multilinear with the right number of variables for every oracle
+	/// of the constraint system. The constraints themselves are checked only when proving.
+	pub fn bind_witness<'a, 'arena>(
+		&'a self,
+		witness: MultilinearExtensionIndex<'arena, U, F>,
+	) -> Result<BoundConstraintSystem<'a, 'arena>, anyhow::Error> {
+		let oracles = &self.constraint_system.oracles;
+		for id in 0..oracles.size() {
+			if !witness.has(id) {
+				bail!(anyhow!("Witness for {} is missing", oracles.label(id)));
+			}
+			let n_vars = witness.get_multilin_poly(id)?.n_vars();
+			if n_vars != oracles.n_vars(id) {
+				bail!(anyhow!(
+					"Witness for {} has {n_vars} variables, expected {}",
+					oracles.label(id),
+					oracles.n_vars(id)
+				));
+			}
+		}
+
+		Ok(BoundConstraintSystem {
+			constraint_system: &self.constraint_system,
+			witness,
+		})
+	}
+}
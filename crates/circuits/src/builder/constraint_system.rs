@@ -5,24 +5,27 @@ use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use anyhow::{anyhow, ensure};
 use binius_core::{
 	constraint_system::{
-		channel::{ChannelId, Flush, FlushDirection},
-		ConstraintSystem,
+		channel::{Boundary, ChannelId, Flush, FlushDirection},
+		ConstraintSystem, PublicInput,
 	},
 	oracle::{
-		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId,
-		ProjectionVariant, ShiftVariant,
+		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, MultilinearPolyVariant,
+		OracleId, ProjectionVariant, ShiftVariant,
 	},
 	polynomial::MultivariatePoly,
 	transparent::step_down::StepDown,
 	witness::MultilinearExtensionIndex,
 };
-use binius_field::{as_packed_field::PackScalar, BinaryField1b};
+use binius_field::{as_packed_field::PackScalar, BinaryField1b, BinaryField32b, Field, TowerField};
 use binius_math::ArithExpr;
 use binius_utils::bail;
 
-use crate::builder::{
-	types::{F, U},
-	witness,
+use crate::{
+	builder::{
+		types::{F, U},
+		witness, CompiledConstraintSystem, ConstraintSystemIR,
+	},
+	transparent,
 };
 
 #[derive(Default)]
@@ -31,7 +34,9 @@ pub struct ConstraintSystemBuilder<'arena> {
 	constraints: ConstraintSetBuilder<F>,
 	non_zero_oracle_ids: Vec<OracleId>,
 	flushes: Vec<Flush>,
+	public_inputs: Vec<PublicInput>,
 	step_down_dedup: HashMap<(usize, usize), OracleId>,
+	shared_tables: HashMap<&'static str, OracleId>,
 	witness: Option<witness::Builder<'arena>>,
 	next_channel_id: ChannelId,
 	namespace_path: Vec<String>,
@@ -69,9 +74,47 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 				})?
 				.into_inner(),
 			flushes: self.flushes,
+			public_inputs: self.public_inputs,
+		})
+	}
+
+	/// Builds the constraint system into its serializable [`ConstraintSystemIR`].
+	pub fn build_ir(self) -> Result<ConstraintSystemIR, anyhow::Error> {
+		Ok(ConstraintSystemIR {
+			constraint_system: self.build()?,
 		})
 	}
 
+	/// Builds the constraint system once into a [`CompiledConstraintSystem`].
+	///
+	/// The builder is expected to be in verifier mode. Witnesses for the compiled system are then
+	/// populated and bound with [`CompiledConstraintSystem::witness_builder`] and
+	/// [`CompiledConstraintSystem::bind_witness`], without rebuilding the constraints.
+	///
+	/// Only circuits made of committed and linear combination oracles can be compiled, see
+	/// [`CompiledConstraintSystem`] for why.
+	pub fn compile(self) -> Result<CompiledConstraintSystem, anyhow::Error> {
+		if self.witness.is_some() {
+			bail!(anyhow!("Cannot compile a ConstraintSystemBuilder that holds a witness"));
+		}
+		{
+			let oracles = self.oracles.borrow();
+			for id in 0..oracles.size() {
+				if !matches!(
+					oracles.oracle(id).variant,
+					MultilinearPolyVariant::Committed
+						| MultilinearPolyVariant::LinearCombination(_)
+				) {
+					bail!(anyhow!(
+						"Cannot compile {}: only committed and linear combination oracles are supported",
+						oracles.label(id)
+					));
+				}
+			}
+		}
+		Ok(CompiledConstraintSystem::new(self.build()?))
+	}
+
 	pub fn witness(&mut self) -> Option<&mut witness::Builder<'arena>> {
 		self.witness.as_mut()
 	}
@@ -216,6 +259,55 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.committed(n_vars, tower_level)
 	}
 
+	/// Adds a committed oracle whose values are part of the public statement.
+	///
+	/// The witness column is populated by the caller, as for [`Self::add_committed`]. Every row is
+	/// pulled from a dedicated channel together with its row index, so the verifier has to push
+	/// the claimed public values for the proof to verify, see
+	/// [`Self::public_input_boundaries`].
+	pub fn add_public_input(
+		&mut self,
+		name: impl ToString,
+		log_size: usize,
+		tower_level: usize,
+	) -> anyhow::Result<OracleId>
+	where
+		U: PackScalar<BinaryField1b>,
+	{
+		ensure!(log_size <= 32, "Public input row indices must fit in BinaryField32b");
+
+		let oracle_id = self.add_committed(name.to_string(), log_size, tower_level);
+
+		self.push_namespace(name);
+		let index_values = (0..1u64 << log_size)
+			.map(|index| BinaryField32b::new(index as u32))
+			.collect::<Vec<_>>();
+		let index = transparent::make_transparent(self, "index", &index_values)?;
+		self.pop_namespace();
+
+		let channel_id = self.add_channel();
+		self.receive(channel_id, 1 << log_size, [index, oracle_id])?;
+		self.public_inputs.push(PublicInput {
+			oracle_id,
+			channel_id,
+		});
+		Ok(oracle_id)
+	}
+
+	/// Returns the boundaries asserting that the public input `oracle_id` equals `values`.
+	pub fn public_input_boundaries(
+		&self,
+		oracle_id: OracleId,
+		values: impl IntoIterator<Item = F>,
+	) -> anyhow::Result<Vec<Boundary<F>>> {
+		let public_input = self
+			.public_inputs
+			.iter()
+			.find(|public_input| public_input.oracle_id == oracle_id)
+			.ok_or_else(|| anyhow!("Oracle {oracle_id} is not a public input"))?;
+		Ok(public_input.boundaries(values))
+	}
+
 	pub fn add_committed_multiple<const N: usize>(
 		&mut self,
 		name: impl ToString,
@@ -327,6 +419,114 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.zero_padded(id, n_vars)
 	}
 
+	/// Adds an oracle viewing the rows `[offset, offset + 2^log_size)` of `source`.
+	///
+	/// The slice is a projection of the high variables of `source`, so `offset` must be a multiple
+	/// of `2^log_size`. The witness of the slice shares the data of the source witness whenever the
+	/// rows cover whole packed elements.
+	pub fn add_slice(
+		&mut self,
+		name: impl ToString,
+		source: OracleId,
+		offset: usize,
+		log_size: usize,
+	) -> Result<OracleId, anyhow::Error> {
+		let n_vars = self.oracles.borrow().n_vars(source);
+		ensure!(log_size <= n_vars, "add_slice: slice is larger than the source");
+		ensure!(
+			offset % (1 << log_size) == 0,
+			"add_slice: offset must be a multiple of the slice size"
+		);
+		ensure!(
+			offset + (1 << log_size) <= 1 << n_vars,
+			"add_slice: slice exceeds the rows of the source"
+		);
+
+		let chunk_index = offset >> log_size;
+		let values = (0..n_vars - log_size)
+			.map(|i| {
+				if (chunk_index >> i) & 1 == 1 {
+					F::ONE
+				} else {
+					F::ZERO
+				}
+			})
+			.collect();
+		let slice = self.add_projected(name, source, values, ProjectionVariant::LastVars)?;
+
+		if let Some(witness) = self.witness() {
+			witness.alias_rows(slice, source, offset)?;
+		}
+
+		Ok(slice)
+	}
+
+	/// Adds a committed oracle interleaving `2^log_count` components round-robin.
+	///
+	/// Returns the interleaved oracle together with its components. Row `i * k + j` of the
+	/// interleaved oracle is row `i` of component `j`, where `k = 2^log_count`, so the low
+	/// `log_count` variables select the component. Only the interleaved oracle is committed; each
+	/// component is the projection of it onto its index, so openings of the interleaved oracle
+	/// cover all components at once.
+	///
+	/// The witness columns of the components are populated by the caller, after which the
+	/// interleaved column is filled with [`witness::Builder::interleave_rows`].
+	pub fn add_interleaved(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		log_count: usize,
+		tower_level: usize,
+	) -> Result<(OracleId, Vec<OracleId>), OracleError> {
+		self.push_namespace(name);
+		let interleaved = self.add_committed("interleaved", n_vars + log_count, tower_level);
+		let components = (0..1 << log_count)
+			.map(|index| {
+				let values = (0..log_count)
+					.map(|i| {
+						if (index >> i) & 1 == 1 {
+							F::ONE
+						} else {
+							F::ZERO
+						}
+					})
+					.collect();
+				self.add_projected(
+					format!("component_{index}"),
+					interleaved,
+					values,
+					ProjectionVariant::FirstVars,
+				)
+			})
+			.collect::<Result<Vec<_>, _>>();
+		self.pop_namespace();
+		Ok((interleaved, components?))
+	}
+
+	/// Returns a lookup table shared by all gadgets in this constraint system, building it on first
+	/// use.
+	///
+	/// `key` identifies the table contents: every call with the same key must build an identical
+	/// table, and subsequent calls return the oracle built by the first one. The table is built
+	/// outside of the current namespace, so its name does not depend on the first caller.
+	pub fn shared_table(
+		&mut self,
+		key: &'static str,
+		build: impl FnOnce(&mut Self) -> Result<OracleId, anyhow::Error>,
+	) -> Result<OracleId, anyhow::Error> {
+		if let Some(&table) = self.shared_tables.get(key) {
+			return Ok(table);
+		}
+
+		let namespace_path = std::mem::take(&mut self.namespace_path);
+		let table = build(self);
+		self.namespace_path = namespace_path;
+
+		let table = table?;
+		self.shared_tables.insert(key, table);
+		Ok(table)
+	}
+
 	fn scoped_name(&self, name: impl ToString) -> String {
 		let name = name.to_string();
 		if self.namespace_path.is_empty() {
@@ -389,4 +589,262 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		}
 		Ok(log_rows)
 	}
+
+	/// Returns the tower level of the values of a column.
+	pub fn tower_level(&self, oracle_id: OracleId) -> usize {
+		self.oracles.borrow().tower_level(oracle_id)
+	}
+
+	/// Merges committed columns whose witness contents are identical, returning the merges.
+	///
+	/// Committed columns are grouped by number of rows, tower level and contents. Every column of
+	/// a group but the first is replaced by an alias of the first, see
+	/// [`MultilinearOracleSet::alias_committed`], so that it is no longer committed. Constraints,
+	/// flushes and derived columns that refer to a merged column are unaffected.
+	///
+	/// The merges depend on the witness, so the builder must hold one. The verifier must prove
+	/// against the same constraint system, hence the returned `(merged, kept)` pairs must be
+	/// replayed on the verifier's builder with [`Self::merge_committed_columns`].
+	pub fn deduplicate_committed_columns(
+		&mut self,
+	) -> Result<Vec<(OracleId, OracleId)>, anyhow::Error> {
+		let Some(witness) = &self.witness else {
+			bail!(anyhow!("Deduplicating committed columns requires a witness"));
+		};
+
+		let mut merges = Vec::new();
+		{
+			let oracles = self.oracles.borrow();
+			let mut kept_columns = HashMap::new();
+			for oracle in oracles.iter() {
+				if !matches!(oracle.variant, MultilinearPolyVariant::Committed) {
+					continue;
+				}
+				let Some((tower_level, bytes)) = witness.column_bytes(oracle.id) else {
+					continue;
+				};
+				let kept = *kept_columns
+					.entry((oracle.n_vars, tower_level, bytes))
+					.or_insert(oracle.id);
+				if kept != oracle.id {
+					merges.push((oracle.id, kept));
+				}
+			}
+		}
+
+		self.merge_committed_columns(&merges)?;
+		Ok(merges)
+	}
+
+	/// Replaces each committed column `merged` with an alias of the committed column `kept`.
+	///
+	/// See [`Self::deduplicate_committed_columns`].
+	pub fn merge_committed_columns(
+		&mut self,
+		merges: &[(OracleId, OracleId)],
+	) -> Result<(), anyhow::Error> {
+		let mut oracles = self.oracles.borrow_mut();
+		for &(merged, kept) in merges {
+			oracles.alias_committed(merged, kept)?;
+		}
+		Ok(())
+	}
+
+	/// Returns the tower level of the values of every column added so far.
+	///
+	/// For committed columns this is the tower level the column is committed at, which together
+	/// with [`Self::log_rows`] determines the size of the committed data. Linear combinations
+	/// report the smallest tower level containing their inner columns, coefficients and offset,
+	/// which can be higher than the level of the oracle itself, since the latter only accounts for
+	/// the inner columns. Other derived columns report the level of their oracle.
+	pub fn column_tower_levels(&self) -> HashMap<OracleId, usize> {
+		let oracles = self.oracles.borrow();
+		let mut levels = HashMap::with_capacity(oracles.size());
+		// Oracles only refer to oracles with smaller ids, so the inner levels are already known.
+		for id in 0..oracles.size() {
+			let level = match oracles.oracle(id).variant {
+				MultilinearPolyVariant::LinearCombination(lincom) => lincom
+					.polys()
+					.map(|inner_id| levels[&inner_id])
+					.chain(lincom.coefficients().map(|coeff| coeff.min_tower_level()))
+					.fold(lincom.offset().min_tower_level(), usize::max),
+				_ => oracles.tower_level(id),
+			};
+			levels.insert(id, level);
+		}
+		levels
+	}
+
+	/// Describes a linear combination oracle in a human-readable form, as a debugging aid.
+	///
+	/// Each term is printed as its coefficient followed by the name of the contributing oracle.
+	/// Coefficients that are powers of two in the canonical representation are printed as `2^k`
+	/// and unit coefficients are omitted; any other coefficient is printed in hex. Oracles that
+	/// are not linear combinations are described by their label.
+	///
+	/// Example
+	/// ```
+	/// use binius_circuits::builder::{types::F, ConstraintSystemBuilder};
+	/// use binius_field::{BinaryField8b, Field, TowerField};
+	///
+	/// let mut builder = ConstraintSystemBuilder::new();
+	/// let a = builder.add_committed("a", 4, BinaryField8b::TOWER_LEVEL);
+	/// let b = builder.add_committed("b", 4, BinaryField8b::TOWER_LEVEL);
+	/// let lc = builder
+	///     .add_linear_combination(
+	///         "lc",
+	///         4,
+	///         [
+	///             (a, <F as TowerField>::basis(BinaryField8b::TOWER_LEVEL, 3).unwrap()),
+	///             (b, F::ONE),
+	///         ],
+	///     )
+	///     .unwrap();
+	///
+	/// assert_eq!(builder.describe_linear_combination(lc), "lc = 2^24·a + b");
+	/// ```
+	pub fn describe_linear_combination(&self, oracle: OracleId) -> String {
+		let oracles = self.oracles.borrow();
+		let oracle = oracles.oracle(oracle);
+		let MultilinearPolyVariant::LinearCombination(linear_combination) = &oracle.variant else {
+			return oracle.label();
+		};
+
+		let describe_coeff = |coeff: F| {
+			let val = coeff.val();
+			if val == 1 {
+				String::new()
+			} else if val.is_power_of_two() {
+				format!("2^{}·", val.trailing_zeros())
+			} else {
+				format!("{val:#x}·")
+			}
+		};
+
+		let mut terms = linear_combination
+			.polys()
+			.zip(linear_combination.coefficients())
+			.map(|(id, coeff)| {
+				let name = oracles
+					.oracle(id)
+					.name()
+					.map_or_else(|| format!("id={id}"), ToString::to_string);
+				format!("{}{name}", describe_coeff(coeff))
+			})
+			.collect::<Vec<_>>();
+		let offset = linear_combination.offset();
+		if offset != F::ZERO {
+			terms.push(format!("{:#x}", offset.val()));
+		}
+
+		let name = oracle
+			.name()
+			.map_or_else(|| format!("id={}", oracle.id()), ToString::to_string);
+		let terms = if terms.is_empty() {
+			"0".to_string()
+		} else {
+			terms.join(" + ")
+		};
+		format!("{name} = {terms}")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField32b, TowerField};
+
+	use super::*;
+
+	fn tables_circuit(builder: &mut ConstraintSystemBuilder) -> [OracleId; 4] {
+		let log_size = 8;
+		let [t1, t2, t3] = ["a", "b", "c"].map(|name| {
+			builder.push_namespace(name);
+			let lookup_t = builder.add_committed("lookup_t", log_size, BinaryField32b::TOWER_LEVEL);
+			builder.pop_namespace();
+			lookup_t
+		});
+		if let Some(witness) = builder.witness() {
+			for (lookup_t, offset) in [(t1, 0), (t2, 0), (t3, 1)] {
+				let mut column = witness.new_column::<BinaryField32b>(lookup_t);
+				for (i, value) in column.as_mut_slice::<u32>().iter_mut().enumerate() {
+					*value = (i as u32) << 16 | (i as u32 + offset);
+				}
+			}
+		}
+
+		let sum = builder
+			.add_linear_combination("sum", log_size, [(t2, F::ONE), (t3, F::ONE)])
+			.unwrap();
+		if let Some(witness) = builder.witness() {
+			let t2 = witness.get::<BinaryField32b>(t2).unwrap();
+			let t3 = witness.get::<BinaryField32b>(t3).unwrap();
+			let mut column = witness.new_column::<BinaryField32b>(sum);
+			for ((sum, t2), t3) in column
+				.as_mut_slice::<u32>()
+				.iter_mut()
+				.zip(t2.as_slice::<u32>())
+				.zip(t3.as_slice::<u32>())
+			{
+				*sum = t2 ^ t3;
+			}
+		}
+		[t1, t2, t3, sum]
+	}
+
+	fn n_committed(constraint_system: &ConstraintSystem<F>) -> usize {
+		constraint_system
+			.oracles
+			.iter()
+			.filter(|oracle| matches!(oracle.variant, MultilinearPolyVariant::Committed))
+			.count()
+	}
+
+	#[test]
+	fn test_deduplicate_committed_columns() {
+		let allocator = bumpalo::Bump::new();
+		let mut prover_builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let [t1, t2, _, _] = tables_circuit(&mut prover_builder);
+		let merges = prover_builder.deduplicate_committed_columns().unwrap();
+		assert_eq!(merges, [(t2, t1)]);
+		let witness = prover_builder.take_witness().unwrap();
+		let prover_constraint_system = prover_builder.build().unwrap();
+
+		let mut verifier_builder = ConstraintSystemBuilder::new();
+		tables_circuit(&mut verifier_builder);
+		assert!(verifier_builder.deduplicate_committed_columns().is_err());
+		verifier_builder.merge_committed_columns(&merges).unwrap();
+		let verifier_constraint_system = verifier_builder.build().unwrap();
+
+		assert_eq!(n_committed(&verifier_constraint_system), 2);
+		assert_eq!(
+			ConstraintSystemIR {
+				constraint_system: prover_constraint_system
+			}
+			.to_bytes()
+			.unwrap(),
+			ConstraintSystemIR {
+				constraint_system: verifier_constraint_system.clone()
+			}
+			.to_bytes()
+			.unwrap()
+		);
+		validate_witness(&verifier_constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_merge_committed_columns_rejects_invalid_merges() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let [t1, t2, _, sum] = tables_circuit(&mut builder);
+		// The kept column must precede the merged one, and both must be committed.
+		assert!(builder.merge_committed_columns(&[(t1, t2)]).is_err());
+		assert!(builder.merge_committed_columns(&[(sum, t1)]).is_err());
+		// Out-of-range oracle ids in a merge pair are rejected rather than panicking.
+		assert!(builder
+			.merge_committed_columns(&[(t2, usize::MAX)])
+			.is_err());
+		assert!(builder
+			.merge_committed_columns(&[(usize::MAX, t1)])
+			.is_err());
+	}
 }
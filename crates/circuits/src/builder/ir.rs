@@ -0,0 +1,113 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::constraint_system::ConstraintSystem;
+use binius_utils::{DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
+
+use super::types::F;
+
+/// A serializable representation of a built constraint system.
+///
+/// The IR holds the oracles, table constraints, non-zero oracles, channel flushes and public inputs
+/// of the constraint system, which is everything the prover and verifier need besides the witness.
+/// It is encoded with the canonical tower [`SerializeBytes`] encoding behind a format version, so
+/// that serialized circuits can be diffed, cached and loaded by external tools.
+#[derive(Debug, Clone)]
+pub struct ConstraintSystemIR {
+	pub constraint_system: ConstraintSystem<F>,
+}
+
+impl ConstraintSystemIR {
+	/// Version of the serialized format, bumped on every incompatible change.
+	pub const FORMAT_VERSION: u32 = 1;
+
+	pub fn to_bytes(&self) -> Result<Vec<u8>, SerializationError> {
+		let mut bytes = Vec::new();
+		self.serialize(&mut bytes, SerializationMode::CanonicalTower)?;
+		Ok(bytes)
+	}
+
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationError> {
+		Self::deserialize(bytes, SerializationMode::CanonicalTower)
+	}
+
+	pub fn into_constraint_system(self) -> ConstraintSystem<F> {
+		self.constraint_system
+	}
+}
+
+impl SerializeBytes for ConstraintSystemIR {
+	fn serialize(
+		&self,
+		mut write_buf: impl bytes::BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		Self::FORMAT_VERSION.serialize(&mut write_buf, mode)?;
+		self.constraint_system.serialize(&mut write_buf, mode)
+	}
+}
+
+impl DeserializeBytes for ConstraintSystemIR {
+	fn deserialize(
+		mut read_buf: impl bytes::Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError> {
+		if u32::deserialize(&mut read_buf, mode)? != Self::FORMAT_VERSION {
+			return Err(SerializationError::InvalidConstruction {
+				name: "ConstraintSystemIR::version",
+			});
+		}
+		Ok(Self {
+			constraint_system: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField32b, BinaryField8b};
+
+	use super::*;
+	use crate::{
+		builder::ConstraintSystemBuilder,
+		lasso::{self, batch::LookupBatch, lookups::u8_arithmetic::mul_lookup},
+		unconstrained::unconstrained,
+	};
+
+	fn u8mul_circuit(builder: &mut ConstraintSystemBuilder) -> Result<(), anyhow::Error> {
+		let log_size = 10;
+		let mult_a = unconstrained::<BinaryField8b>(builder, "mult_a", log_size)?;
+		let mult_b = unconstrained::<BinaryField8b>(builder, "mult_b", log_size)?;
+		let mul_lookup_table = mul_lookup(builder, "mul table")?;
+		let mut lookup_batch = LookupBatch::new([mul_lookup_table]);
+		lasso::u8mul(builder, &mut lookup_batch, "lasso_u8mul", mult_a, mult_b, 1 << log_size)?;
+		lookup_batch.execute::<BinaryField32b>(builder)
+	}
+
+	#[test]
+	fn test_u8mul_constraint_system_ir_roundtrip() {
+		let mut verifier_builder = ConstraintSystemBuilder::new();
+		u8mul_circuit(&mut verifier_builder).unwrap();
+		let ir = verifier_builder.build_ir().unwrap();
+
+		let bytes = ir.to_bytes().unwrap();
+		let restored = ConstraintSystemIR::from_bytes(&bytes).unwrap();
+		assert_eq!(restored.to_bytes().unwrap(), bytes);
+
+		// The restored constraint system accepts the witness of the original circuit.
+		let allocator = bumpalo::Bump::new();
+		let mut prover_builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		u8mul_circuit(&mut prover_builder).unwrap();
+		let witness = prover_builder.take_witness().unwrap();
+		validate_witness(&restored.into_constraint_system(), &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_constraint_system_ir_rejects_unknown_version() {
+		let mut builder = ConstraintSystemBuilder::new();
+		u8mul_circuit(&mut builder).unwrap();
+		let mut bytes = builder.build_ir().unwrap().to_bytes().unwrap();
+		bytes[0] ^= 0xFF;
+		assert!(ConstraintSystemIR::from_bytes(&bytes).is_err());
+	}
+}
@@ -1,8 +1,12 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+pub mod compiled;
 pub mod constraint_system;
+pub mod ir;
 pub mod test_utils;
 pub mod types;
 pub mod witness;
 
+pub use compiled::{BoundConstraintSystem, CompiledConstraintSystem};
 pub use constraint_system::ConstraintSystemBuilder;
+pub use ir::ConstraintSystemIR;
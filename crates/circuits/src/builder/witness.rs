@@ -9,14 +9,19 @@ use binius_core::{
 };
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
+	packed::{get_packed_slice, set_packed_slice},
 	underlier::WithUnderlier,
-	ExtensionField, PackedField, TowerField,
+	BinaryField128b, BinaryField16b, BinaryField1b, BinaryField2b, BinaryField32b, BinaryField4b,
+	BinaryField64b, BinaryField8b, ExtensionField, PackedField, TowerField,
 };
 use binius_math::MultilinearExtension;
 use binius_utils::bail;
 use bytemuck::{must_cast_slice, must_cast_slice_mut, Pod};
 
-use super::types::{F, U};
+use super::{
+	types::{F, U},
+	ConstraintSystemBuilder,
+};
 
 pub struct Builder<'arena> {
 	bump: &'arena bumpalo::Bump,
@@ -63,6 +68,46 @@ impl<'arena> Builder<'arena> {
 		}
 	}
 
+	/// Allocates a new column like [`Self::new_column`], with the buffer aligned to at least
+	/// `align` bytes.
+	///
+	/// Column buffers are always aligned to the underlier type. A larger alignment guarantees that
+	/// the column data can be reinterpreted as wider SIMD types with [`bytemuck::must_cast_slice`]
+	/// and friends. `align` must be a power of two.
+	pub fn new_column_aligned<FS: TowerField>(
+		&self,
+		id: OracleId,
+		align: usize,
+	) -> Result<EntryBuilder<'arena, FS>, Error>
+	where
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		if !align.is_power_of_two() {
+			bail!(anyhow!("column alignment {align} is not a power of two"));
+		}
+
+		let oracles = self.oracles.borrow();
+		let log_rows = oracles.n_vars(id);
+		let len = 1 << log_rows.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
+
+		// Over-allocate by enough underliers to move the start of the column to an aligned
+		// address. Both the alignment and the underlier size are powers of two, so such an offset
+		// always exists within the padding.
+		let padding = align.div_ceil(size_of::<U>());
+		let data = bumpalo::vec![in self.bump; U::default(); len + padding].into_bump_slice_mut();
+		let offset = data.as_ptr().align_offset(align);
+		let data = &mut data[offset..offset + len];
+
+		Ok(EntryBuilder {
+			_marker: PhantomData,
+			log_rows,
+			id,
+			data: Some(data),
+			entries: self.entries.clone(),
+		})
+	}
+
 	pub fn new_column_with_default<FS: TowerField>(
 		&self,
 		id: OracleId,
@@ -118,6 +163,13 @@ impl<'arena> Builder<'arena> {
 		})
 	}
 
+	/// Returns the tower level and raw bytes of the witness of `id`, if it has been populated.
+	pub(super) fn column_bytes(&self, id: OracleId) -> Option<(usize, &'arena [u8])> {
+		let entries = self.entries.borrow();
+		let entry = entries.get(id)?.as_ref()?;
+		Some((entry.tower_level, must_cast_slice(entry.data)))
+	}
+
 	pub fn set<FS: TowerField>(
 		&self,
 		id: OracleId,
@@ -144,6 +196,112 @@ impl<'arena> Builder<'arena> {
 		Ok(())
 	}
 
+	/// Fills the witness of `id` with the rows of `source` starting at `offset`.
+	///
+	/// The number of rows is taken from the oracle `id`. When the rows span whole packed elements,
+	/// the new entry shares the underlying data of `source`; smaller ranges are copied.
+	pub fn alias_rows(&self, id: OracleId, source: OracleId, offset: usize) -> Result<(), Error> {
+		let tower_level = self.oracles.borrow().tower_level(source);
+		match tower_level {
+			0 => self.alias_rows_typed::<BinaryField1b>(id, source, offset),
+			1 => self.alias_rows_typed::<BinaryField2b>(id, source, offset),
+			2 => self.alias_rows_typed::<BinaryField4b>(id, source, offset),
+			3 => self.alias_rows_typed::<BinaryField8b>(id, source, offset),
+			4 => self.alias_rows_typed::<BinaryField16b>(id, source, offset),
+			5 => self.alias_rows_typed::<BinaryField32b>(id, source, offset),
+			6 => self.alias_rows_typed::<BinaryField64b>(id, source, offset),
+			7 => self.alias_rows_typed::<BinaryField128b>(id, source, offset),
+			_ => bail!(anyhow!("unsupported tower level {tower_level}")),
+		}
+	}
+
+	fn alias_rows_typed<FS>(
+		&self,
+		id: OracleId,
+		source: OracleId,
+		offset: usize,
+	) -> Result<(), Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let source_entry = self.get::<FS>(source)?;
+		let log_rows = self.oracles.borrow().n_vars(id);
+		if offset + (1 << log_rows) > 1 << source_entry.log_rows {
+			bail!(anyhow!("Rows out of range of {}", self.oracles.borrow().label(source)));
+		}
+
+		let log_width = <PackedType<U, FS>>::LOG_WIDTH;
+		if log_rows >= log_width && offset % (1 << log_width) == 0 {
+			let data = &source_entry.data[offset >> log_width..][..1 << (log_rows - log_width)];
+			self.set(
+				id,
+				WitnessEntry::<FS> {
+					data,
+					log_rows,
+					_marker: PhantomData,
+				},
+			)
+		} else {
+			let mut column = self.new_column::<FS>(id);
+			let packed = column.packed();
+			for i in 0..1 << log_rows {
+				set_packed_slice(packed, i, get_packed_slice(source_entry.packed(), offset + i));
+			}
+			Ok(())
+		}
+	}
+
+	/// Fills `id` with the rows of `components` interleaved round-robin, so that row `i * k + j`
+	/// of `id` is row `i` of `components[j]`, where `k` is the number of components.
+	pub fn interleave_rows(&self, id: OracleId, components: &[OracleId]) -> Result<(), Error> {
+		let tower_level = self.oracles.borrow().tower_level(id);
+		match tower_level {
+			0 => self.interleave_rows_typed::<BinaryField1b>(id, components),
+			1 => self.interleave_rows_typed::<BinaryField2b>(id, components),
+			2 => self.interleave_rows_typed::<BinaryField4b>(id, components),
+			3 => self.interleave_rows_typed::<BinaryField8b>(id, components),
+			4 => self.interleave_rows_typed::<BinaryField16b>(id, components),
+			5 => self.interleave_rows_typed::<BinaryField32b>(id, components),
+			6 => self.interleave_rows_typed::<BinaryField64b>(id, components),
+			7 => self.interleave_rows_typed::<BinaryField128b>(id, components),
+			_ => bail!(anyhow!("unsupported tower level {tower_level}")),
+		}
+	}
+
+	fn interleave_rows_typed<FS>(&self, id: OracleId, components: &[OracleId]) -> Result<(), Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let component_entries = components
+			.iter()
+			.map(|&component| self.get::<FS>(component))
+			.collect::<Result<Vec<_>, _>>()?;
+		let log_rows = self.oracles.borrow().n_vars(id);
+		if component_entries.len() << component_entries[0].log_rows != 1 << log_rows {
+			bail!(anyhow!(
+				"Components do not fill the rows of {}",
+				self.oracles.borrow().label(id)
+			));
+		}
+
+		let mut column = self.new_column::<FS>(id);
+		let packed = column.packed();
+		for (j, entry) in component_entries.iter().enumerate() {
+			for i in 0..1 << entry.log_rows {
+				set_packed_slice(
+					packed,
+					i * component_entries.len() + j,
+					get_packed_slice(entry.packed(), i),
+				);
+			}
+		}
+		Ok(())
+	}
+
 	pub fn build(self) -> Result<MultilinearExtensionIndex<'arena, U, F>, Error> {
 		let mut result = MultilinearExtensionIndex::new();
 		let entries = Rc::into_inner(self.entries)
@@ -158,6 +316,25 @@ impl<'arena> Builder<'arena> {
 	}
 }
 
+/// Reads the scalar values of a witness column.
+///
+/// Returns `None` when the builder has no witness, ie. in verifier mode, or when the column has
+/// not been populated with `FS` values.
+pub fn read_column<FS>(builder: &mut ConstraintSystemBuilder, oracle: OracleId) -> Option<Vec<FS>>
+where
+	FS: TowerField,
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+{
+	let log_rows = builder.log_rows([oracle]).ok()?;
+	let entry = builder.witness()?.get::<FS>(oracle).ok()?;
+	Some(
+		PackedField::iter_slice(entry.packed())
+			.take(1 << log_rows)
+			.collect(),
+	)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct WitnessEntry<'arena, FS: TowerField>
 where
@@ -261,3 +438,144 @@ where
 		})
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::channel::Boundary;
+	use binius_field::{BinaryField32b, BinaryField8b, TowerField};
+
+	use crate::builder::{test_utils::test_circuit, types::F, ConstraintSystemBuilder};
+
+	fn public_input_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		claimed_values: impl IntoIterator<Item = u8>,
+	) -> Result<Vec<Boundary<F>>, anyhow::Error> {
+		let log_size = 5;
+		let public = builder.add_public_input("public", log_size, BinaryField8b::TOWER_LEVEL)?;
+		if let Some(witness) = builder.witness() {
+			let mut column = witness.new_column::<BinaryField8b>(public);
+			for (i, value) in column.as_mut_slice::<u8>().iter_mut().enumerate() {
+				*value = (3 * i) as u8;
+			}
+		}
+		builder.public_input_boundaries(
+			public,
+			claimed_values
+				.into_iter()
+				.map(|value| BinaryField8b::new(value).into()),
+		)
+	}
+
+	#[test]
+	fn test_public_input_accepts_matching_values() {
+		test_circuit(|builder| public_input_circuit(builder, (0..32).map(|i| 3 * i))).unwrap();
+	}
+
+	#[test]
+	fn test_public_input_rejects_mismatched_values() {
+		test_circuit(|builder| {
+			public_input_circuit(builder, (0..32).map(|i| if i == 7 { 0 } else { 3 * i }))
+		})
+		.expect_err("Claimed public value differs from the witness");
+
+		// The values are bound to their rows, so a permutation of the witness is rejected too.
+		test_circuit(|builder| public_input_circuit(builder, (0..32).map(|i| 3 * (i ^ 1))))
+			.expect_err("Claimed public values are permuted");
+
+		test_circuit(|builder| public_input_circuit(builder, (0..31).map(|i| 3 * i)))
+			.expect_err("Claimed public values are missing a row");
+	}
+
+	#[test]
+	fn test_new_column_aligned() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		for (i, align) in [1, 16, 64, 256].into_iter().enumerate() {
+			let id = builder.add_committed(format!("column_{i}"), 8, BinaryField32b::TOWER_LEVEL);
+			let witness = builder.witness().unwrap();
+			let mut column = witness
+				.new_column_aligned::<BinaryField32b>(id, align)
+				.unwrap();
+			let values = column.as_mut_slice::<u32>();
+			assert_eq!(values.len(), 1 << 8);
+			assert_eq!(values.as_ptr() as usize % align, 0);
+			values.fill(i as u32);
+			drop(column);
+
+			let witness = builder.witness().unwrap();
+			let values = witness.get::<BinaryField32b>(id).unwrap();
+			assert!(values
+				.as_slice::<u32>()
+				.iter()
+				.all(|&value| value == i as u32));
+		}
+
+		let witness = builder.witness().unwrap();
+		assert!(witness.new_column_aligned::<BinaryField32b>(0, 48).is_err());
+	}
+
+	#[test]
+	fn test_add_slice() {
+		test_circuit(|builder| {
+			let log_size = 8;
+			let source = builder.add_committed("source", log_size, BinaryField8b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut column = witness.new_column::<BinaryField8b>(source);
+				for (i, value) in column.as_mut_slice::<u8>().iter_mut().enumerate() {
+					*value = i as u8;
+				}
+			}
+
+			// A slice spanning whole packed elements aliases the source data, while a slice
+			// smaller than a packed element is copied.
+			for (offset, slice_log_size) in [(64, 6), (128, 7), (12, 2), (0, 0)] {
+				let slice = builder.add_slice("slice", source, offset, slice_log_size)?;
+				if let Some(witness) = builder.witness() {
+					let source = witness.get::<BinaryField8b>(source)?.as_slice::<u8>();
+					let slice = witness.get::<BinaryField8b>(slice)?.as_slice::<u8>();
+					assert_eq!(
+						&slice[..1 << slice_log_size],
+						&source[offset..offset + (1 << slice_log_size)]
+					);
+				}
+			}
+
+			assert!(builder.add_slice("misaligned", source, 32, 6).is_err());
+			assert!(builder
+				.add_slice("too_large", source, 0, log_size + 1)
+				.is_err());
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_add_interleaved() {
+		test_circuit(|builder| {
+			let log_size = 5;
+			let (interleaved, components) =
+				builder.add_interleaved("interleaved", log_size, 2, BinaryField8b::TOWER_LEVEL)?;
+			assert_eq!(components.len(), 4);
+			assert_eq!(builder.log_rows([interleaved])?, log_size + 2);
+			assert_eq!(builder.log_rows(components.iter().copied())?, log_size);
+
+			if let Some(witness) = builder.witness() {
+				for (j, &component) in components.iter().enumerate() {
+					let mut column = witness.new_column::<BinaryField8b>(component);
+					for (i, value) in column.as_mut_slice::<u8>().iter_mut().enumerate() {
+						*value = (i as u8) << 2 | j as u8;
+					}
+				}
+				witness.interleave_rows(interleaved, &components)?;
+
+				let interleaved = witness.get::<BinaryField8b>(interleaved)?.as_slice::<u8>();
+				for (row, &value) in interleaved.iter().enumerate().take(4 << log_size) {
+					assert_eq!(value, row as u8);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
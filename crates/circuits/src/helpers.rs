@@ -0,0 +1,37 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::TowerField;
+
+use crate::builder::types::F;
+
+/// Returns the basis element that places a limb at `byte_index` in a packed lookup index.
+///
+/// Lookup gadgets pack several narrow oracles into one wide index with a linear combination, where
+/// each oracle is scaled by the basis element of its position. Viewing `F` as a vector space over
+/// the tower subfield of level `level`, the element returned is the `byte_index`-th basis vector,
+/// so the limb lands in bits `byte_index << level` onwards of the index. `level` is 3 for byte
+/// limbs.
+pub fn tower_basis_for_byte_position(
+	level: usize,
+	byte_index: usize,
+) -> Result<F, binius_field::Error> {
+	<F as TowerField>::basis(level, byte_index)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{underlier::WithUnderlier, BinaryField8b};
+
+	use super::*;
+
+	#[test]
+	fn test_tower_basis_for_byte_position() {
+		for byte_index in 0..4 {
+			let basis =
+				tower_basis_for_byte_position(BinaryField8b::TOWER_LEVEL, byte_index).unwrap();
+			assert_eq!(basis, <F as TowerField>::basis(3, byte_index).unwrap());
+			assert_eq!(basis.to_underlier(), 1 << (8 * byte_index));
+		}
+		assert!(tower_basis_for_byte_position(3, 16).is_err());
+	}
+}
@@ -0,0 +1,70 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField32b, BinaryField8b, TowerField};
+use itertools::izip;
+
+use super::{batch::LookupBatch, lookups::u8_arithmetic::i8_abs as abs};
+use crate::{builder::ConstraintSystemBuilder, helpers::tower_basis_for_byte_position};
+
+type B8 = BinaryField8b;
+type B32 = BinaryField32b;
+
+/// Absolute value of a column of signed bytes, returning the `B8` absolute value column.
+///
+/// The bytes of `x` are interpreted as `i8` in two's complement. As with [`i8::wrapping_abs`], the
+/// absolute value of `-128` (`0x80`) wraps to `-128`, so the result read as an `i8` is negative
+/// for that input only. The rows are looked up in the table returned by
+/// [`i8_abs_lookup`](super::lookups::u8_arithmetic::i8_abs_lookup), which must be one of the
+/// tables of `lookup_batch`.
+pub fn i8_abs(
+	builder: &mut ConstraintSystemBuilder,
+	lookup_batch: &mut LookupBatch,
+	name: impl ToString,
+	x: OracleId,
+	n_rows: usize,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([x])?;
+	let abs_x = builder.add_committed("abs", log_rows, B8::TOWER_LEVEL);
+
+	let lookup_u = builder.add_linear_combination(
+		"lookup_u",
+		log_rows,
+		[
+			(x, tower_basis_for_byte_position(3, 2)?),
+			(abs_x, tower_basis_for_byte_position(3, 0)?),
+		],
+	)?;
+
+	let mut u_to_t_mapping = Vec::new();
+
+	if let Some(witness) = builder.witness() {
+		let mut abs_witness = witness.new_column::<B8>(abs_x);
+		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
+		let mut u_to_t_mapping_witness = vec![0; 1 << log_rows];
+
+		let x_u8 = witness.get::<B8>(x)?.as_slice::<u8>();
+
+		for (&x, abs_x, lookup_u, u_to_t) in izip!(
+			x_u8,
+			abs_witness.as_mut_slice::<u8>(),
+			lookup_u_witness.as_mut_slice::<u32>(),
+			u_to_t_mapping_witness.iter_mut()
+		) {
+			*abs_x = abs(x);
+
+			let lookup_index = x as usize;
+			*lookup_u = (lookup_index << 16 | *abs_x as usize) as u32;
+			*u_to_t = lookup_index;
+		}
+
+		u_to_t_mapping = u_to_t_mapping_witness;
+	}
+
+	lookup_batch.add([lookup_u], u_to_t_mapping, n_rows);
+
+	builder.pop_namespace();
+	Ok(abs_x)
+}
@@ -16,6 +16,13 @@ use crate::{
 	transparent,
 };
 
+/// Proves that rows of the looked up oracles appear among the rows of the table oracles.
+///
+/// The table may consist of several oracles of any tower level, so keys and values of any width
+/// can be looked up as long as they fit their columns, for example a 24-bit key in a
+/// `BinaryField32b` column next to a 48-bit value in a `BinaryField64b` column. The number of rows
+/// of the table bounds the key space. `FC` is the field of the multiplicative timestamps and must
+/// be large enough to count all lookups.
 pub fn lasso<FC>(
 	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString,
@@ -35,7 +42,7 @@ where
 		Err(anyhow::Error::msg("n_vars and lookups_u must be of the same length"))?;
 	}
 
-	if n_lookups.iter().sum::<usize>() >= 1 << FC::N_BITS {
+	if FC::N_BITS < usize::BITS as usize && n_lookups.iter().sum::<usize>() >= 1 << FC::N_BITS {
 		Err(anyhow::Error::msg("FC too small"))?;
 	}
 
@@ -109,6 +116,10 @@ where
 			for (&index, r, w) in
 				izip!(u_to_t_mapping.as_ref(), lookup_r_scalars, lookup_w_scalars).take(n_lookups)
 			{
+				ensure!(
+					index < 1 << t_log_rows,
+					"lookup index {index} is out of range for a table with {t_log_rows} log rows"
+				);
 				let ts = lookup_f_scalars[index];
 				*r = ts;
 				*w = ts * alpha;
@@ -141,3 +152,145 @@ where
 
 	Ok(())
 }
+
+/// Commits a column of `log_rows` rows holding the rows of `table` selected by `u_to_t_mapping`.
+///
+/// This populates the witness of a looked up oracle from the table it is looked up in, with row
+/// `i` set to row `u_to_t_mapping[i]` of the table. Rows past the end of the mapping are zero.
+/// The column has the tower level of `FS`, which must match the level of `table`.
+pub fn lookup_column<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	table: OracleId,
+	log_rows: usize,
+	u_to_t_mapping: &[usize],
+) -> Result<OracleId>
+where
+	FS: TowerField,
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+	PackedType<U, FS>: PackedFieldIndexable,
+{
+	ensure!(u_to_t_mapping.len() <= 1 << log_rows);
+
+	let lookup_u = builder.add_committed(name, log_rows, FS::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let table_witness = witness.get::<FS>(table)?;
+		let table_scalars = PackedType::<U, FS>::unpack_scalars(table_witness.packed());
+
+		let mut lookup_u_witness = witness.new_column::<FS>(lookup_u);
+		let lookup_u_scalars = PackedType::<U, FS>::unpack_scalars_mut(lookup_u_witness.packed());
+
+		for (&index, u) in u_to_t_mapping.iter().zip(lookup_u_scalars) {
+			*u = *table_scalars
+				.get(index)
+				.ok_or_else(|| anyhow::anyhow!("lookup index {index} is out of range"))?;
+		}
+	}
+
+	Ok(lookup_u)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		as_packed_field::{PackScalar, PackedType},
+		BinaryField32b, BinaryField64b, ExtensionField, PackedFieldIndexable, TowerField,
+	};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::lookup_column;
+	use crate::{
+		builder::{
+			test_utils::test_circuit,
+			types::{F, U},
+			ConstraintSystemBuilder,
+		},
+		lasso::batch::LookupBatch,
+	};
+
+	type B32 = BinaryField32b;
+	type B64 = BinaryField64b;
+
+	const T_LOG_SIZE: usize = 10;
+	const LOG_SIZE: usize = 8;
+
+	/// Looks up 24-bit keys in a table mapping each key to its 48-bit square.
+	///
+	/// With `mismatched` set, every looked up value is taken from the row after its key.
+	fn key_value_lookup<FC>(
+		builder: &mut ConstraintSystemBuilder,
+		mismatched: bool,
+	) -> Result<(), anyhow::Error>
+	where
+		FC: TowerField,
+		U: PackScalar<FC>,
+		F: ExtensionField<FC>,
+		PackedType<U, FC>: PackedFieldIndexable,
+	{
+		let table_keys = builder.add_committed("table_keys", T_LOG_SIZE, B32::TOWER_LEVEL);
+		let table_values = builder.add_committed("table_values", T_LOG_SIZE, B64::TOWER_LEVEL);
+
+		let mut u_to_t_mapping = Vec::new();
+		if let Some(witness) = builder.witness() {
+			let mut table_keys_witness = witness.new_column::<B32>(table_keys);
+			let mut table_values_witness = witness.new_column::<B64>(table_values);
+			for (i, (key, value)) in table_keys_witness
+				.as_mut_slice::<u32>()
+				.iter_mut()
+				.zip(table_values_witness.as_mut_slice::<u64>())
+				.enumerate()
+			{
+				*key = (i as u32) * 0x3fff;
+				assert!(*key < 1 << 24);
+				*value = (*key as u64) * (*key as u64);
+			}
+
+			let mut rng = StdRng::seed_from_u64(0);
+			u_to_t_mapping = (0..1 << LOG_SIZE)
+				.map(|_| rng.gen_range(0..(1 << T_LOG_SIZE) - 1))
+				.collect();
+		}
+
+		let lookup_keys =
+			lookup_column::<B32>(builder, "lookup_keys", table_keys, LOG_SIZE, &u_to_t_mapping)?;
+		let value_mapping = u_to_t_mapping
+			.iter()
+			.map(|&index| index + mismatched as usize)
+			.collect::<Vec<_>>();
+		let lookup_values =
+			lookup_column::<B64>(builder, "lookup_values", table_values, LOG_SIZE, &value_mapping)?;
+
+		let mut lookup_batch = LookupBatch::new([table_keys, table_values]);
+		lookup_batch.add([lookup_keys, lookup_values], u_to_t_mapping, 1 << LOG_SIZE);
+		lookup_batch.execute::<FC>(builder)
+	}
+
+	#[test]
+	fn test_lasso_wide_key_value_lookup() {
+		test_circuit(|builder| {
+			key_value_lookup::<B32>(builder, false)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_lasso_wide_key_value_lookup_with_b64_timestamps() {
+		test_circuit(|builder| {
+			key_value_lookup::<B64>(builder, false)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_lasso_wide_key_value_lookup_rejects_mismatched_value() {
+		test_circuit(|builder| {
+			key_value_lookup::<B32>(builder, true)?;
+			Ok(vec![])
+		})
+		.expect_err("Rejected value from the wrong row");
+	}
+}
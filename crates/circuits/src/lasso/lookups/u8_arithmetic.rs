@@ -10,136 +10,345 @@ type B32 = BinaryField32b;
 const T_LOG_SIZE_MUL: usize = 16;
 const T_LOG_SIZE_ADD: usize = 17;
 const T_LOG_SIZE_DCI: usize = 10;
-
+const T_LOG_SIZE_DIVREM: usize = 16;
+const T_LOG_SIZE_CLMUL: usize = 16;
+const T_LOG_SIZE_ABS: usize = 8;
+const T_LOG_SIZE_MIN_MAX: usize = 16;
+
+/// Returns the lookup table for 8-bit multiplication.
+///
+/// The table is shared by all callers on the same builder, see
+/// [`ConstraintSystemBuilder::shared_table`].
 pub fn mul_lookup(
 	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString + Clone,
 ) -> Result<OracleId, anyhow::Error> {
-	builder.push_namespace(name);
+	builder.shared_table("u8_arithmetic::mul", |builder| {
+		builder.push_namespace(name);
 
-	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_MUL, B32::TOWER_LEVEL);
+		let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_MUL, B32::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		if let Some(witness) = builder.witness() {
+			let mut lookup_t = witness.new_column::<B32>(lookup_t);
 
-		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+			let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
 
-		for (i, lookup_t) in lookup_t_u32.iter_mut().enumerate() {
-			let a_int = (i >> 8) & 0xff;
-			let b_int = i & 0xff;
-			let ab_product = a_int * b_int;
-			let lookup_index = a_int << 8 | b_int;
-			assert_eq!(lookup_index, i);
-			*lookup_t = (lookup_index << 16 | ab_product) as u32;
+			for (i, lookup_t) in lookup_t_u32.iter_mut().enumerate() {
+				let a_int = (i >> 8) & 0xff;
+				let b_int = i & 0xff;
+				let ab_product = a_int * b_int;
+				let lookup_index = a_int << 8 | b_int;
+				assert_eq!(lookup_index, i);
+				*lookup_t = (lookup_index << 16 | ab_product) as u32;
+			}
 		}
-	}
 
-	builder.pop_namespace();
-	Ok(lookup_t)
+		builder.pop_namespace();
+		Ok(lookup_t)
+	})
 }
 
+/// Returns the lookup table for 8-bit addition with carry.
+///
+/// The table is shared by all callers on the same builder, see
+/// [`ConstraintSystemBuilder::shared_table`].
 pub fn add_lookup(
 	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString + Clone,
 ) -> Result<OracleId, anyhow::Error> {
-	builder.push_namespace(name);
+	builder.shared_table("u8_arithmetic::add", |builder| {
+		builder.push_namespace(name);
 
-	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, B32::TOWER_LEVEL);
+		let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, B32::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		if let Some(witness) = builder.witness() {
+			let mut lookup_t = witness.new_column::<B32>(lookup_t);
 
-		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+			let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
 
-		for carry_in_usize in 0..(1 << 1) {
-			for x_in_usize in 0..(1 << 8) {
-				for y_in_usize in 0..(1 << 8) {
-					let lookup_index = (carry_in_usize << 16) | (x_in_usize << 8) | y_in_usize;
-					let xy_sum_with_carry_out = x_in_usize + y_in_usize + carry_in_usize;
-					let xy_sum_usize = xy_sum_with_carry_out & 0xff;
-					let carry_out_usize = xy_sum_with_carry_out >> 8;
-					let lookup_value = (carry_in_usize << 25)
-						| (carry_out_usize << 24)
-						| (x_in_usize << 16)
-						| (y_in_usize << 8)
-						| xy_sum_usize;
-					lookup_t_u32[lookup_index] = lookup_value as u32;
+			for carry_in_usize in 0..(1 << 1) {
+				for x_in_usize in 0..(1 << 8) {
+					for y_in_usize in 0..(1 << 8) {
+						let lookup_index = (carry_in_usize << 16) | (x_in_usize << 8) | y_in_usize;
+						let xy_sum_with_carry_out = x_in_usize + y_in_usize + carry_in_usize;
+						let xy_sum_usize = xy_sum_with_carry_out & 0xff;
+						let carry_out_usize = xy_sum_with_carry_out >> 8;
+						let lookup_value = (carry_in_usize << 25)
+							| (carry_out_usize << 24)
+							| (x_in_usize << 16) | (y_in_usize << 8)
+							| xy_sum_usize;
+						lookup_t_u32[lookup_index] = lookup_value as u32;
+					}
 				}
 			}
 		}
-	}
 
-	builder.pop_namespace();
-	Ok(lookup_t)
+		builder.pop_namespace();
+		Ok(lookup_t)
+	})
 }
 
+/// Returns the lookup table for 8-bit addition that rejects a carry out.
+///
+/// The table is shared by all callers on the same builder, see
+/// [`ConstraintSystemBuilder::shared_table`].
 pub fn add_carryfree_lookup(
 	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString + Clone,
 ) -> Result<OracleId, anyhow::Error> {
-	builder.push_namespace(name);
+	builder.shared_table("u8_arithmetic::add_carryfree", |builder| {
+		builder.push_namespace(name);
 
-	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, B32::TOWER_LEVEL);
+		let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, B32::TOWER_LEVEL);
 
-	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		if let Some(witness) = builder.witness() {
+			let mut lookup_t = witness.new_column::<B32>(lookup_t);
 
-		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+			let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
 
-		for carry_in_usize in 0..(1 << 1) {
-			for x_in_usize in 0..(1 << 8) {
-				for y_in_usize in 0..(1 << 8) {
-					let lookup_index = (carry_in_usize << 16) | (x_in_usize << 8) | y_in_usize;
-					let xy_sum_usize = x_in_usize + y_in_usize + carry_in_usize;
+			for carry_in_usize in 0..(1 << 1) {
+				for x_in_usize in 0..(1 << 8) {
+					for y_in_usize in 0..(1 << 8) {
+						let lookup_index = (carry_in_usize << 16) | (x_in_usize << 8) | y_in_usize;
+						let xy_sum_usize = x_in_usize + y_in_usize + carry_in_usize;
+
+						// Make it impossible to add numbers resulting in a carry
+						let lookup_value = if xy_sum_usize <= 0xff {
+							(carry_in_usize << 24)
+								| (x_in_usize << 16) | (y_in_usize << 8)
+								| xy_sum_usize
+						} else {
+							0
+						};
+						lookup_t_u32[lookup_index] = lookup_value as u32;
+					}
+				}
+			}
+		}
 
-					// Make it impossible to add numbers resulting in a carry
-					let lookup_value = if xy_sum_usize <= 0xff {
-						(carry_in_usize << 24)
-							| (x_in_usize << 16) | (y_in_usize << 8)
-							| xy_sum_usize
-					} else {
-						0
-					};
-					lookup_t_u32[lookup_index] = lookup_value as u32;
+		builder.pop_namespace();
+		Ok(lookup_t)
+	})
+}
+
+/// Returns the lookup table for 8-bit double conditional increment.
+///
+/// The table is shared by all callers on the same builder, see
+/// [`ConstraintSystemBuilder::shared_table`].
+pub fn dci_lookup(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString + Clone,
+) -> Result<OracleId, anyhow::Error> {
+	builder.shared_table("u8_arithmetic::dci", |builder| {
+		builder.push_namespace(name);
+
+		let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_DCI, B32::TOWER_LEVEL);
+
+		if let Some(witness) = builder.witness() {
+			let mut lookup_t = witness.new_column::<B32>(lookup_t);
+
+			let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+
+			for first_carry_in_usize in 0..(1 << 1) {
+				for second_carry_in_usize in 0..(1 << 1) {
+					for x_in_usize in 0..(1 << 8) {
+						let lookup_index =
+							(first_carry_in_usize << 9) | (second_carry_in_usize << 8) | x_in_usize;
+						let sum_with_carry_out =
+							x_in_usize + first_carry_in_usize + second_carry_in_usize;
+						let sum_usize = sum_with_carry_out & 0xff;
+						let carry_out_usize = sum_with_carry_out >> 8;
+						let lookup_value = (first_carry_in_usize << 18)
+							| (second_carry_in_usize << 17)
+							| (carry_out_usize << 16)
+							| (x_in_usize << 8) | sum_usize;
+						lookup_t_u32[lookup_index] = lookup_value as u32;
+					}
 				}
 			}
 		}
+
+		builder.pop_namespace();
+		Ok(lookup_t)
+	})
+}
+
+/// Returns the lookup table for 8-bit unsigned division with remainder.
+///
+/// Entries are keyed by `dividend << 8 | divisor` and hold the key in the high half and
+/// `quotient << 8 | remainder` in the low half. Division by zero follows RISC-V: the quotient is
+/// `0xFF` and the remainder is the dividend.
+///
+/// The table is shared by all callers on the same builder, see
+/// [`ConstraintSystemBuilder::shared_table`].
+pub fn divrem_lookup(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString + Clone,
+) -> Result<OracleId, anyhow::Error> {
+	builder.shared_table("u8_arithmetic::divrem", |builder| {
+		builder.push_namespace(name);
+
+		let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_DIVREM, B32::TOWER_LEVEL);
+
+		if let Some(witness) = builder.witness() {
+			let mut lookup_t = witness.new_column::<B32>(lookup_t);
+
+			let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+
+			for (lookup_index, lookup_t) in lookup_t_u32.iter_mut().enumerate() {
+				let dividend = (lookup_index >> 8) as u8;
+				let divisor = lookup_index as u8;
+				let (quotient, remainder) = u8_divrem(dividend, divisor);
+				*lookup_t =
+					(lookup_index << 16 | (quotient as usize) << 8 | remainder as usize) as u32;
+			}
+		}
+
+		builder.pop_namespace();
+		Ok(lookup_t)
+	})
+}
+
+/// 8-bit unsigned division with remainder, with the division by zero convention of
+/// [`divrem_lookup`].
+pub(crate) const fn u8_divrem(dividend: u8, divisor: u8) -> (u8, u8) {
+	match divisor {
+		0 => (0xFF, dividend),
+		_ => (dividend / divisor, dividend % divisor),
 	}
+}
 
-	builder.pop_namespace();
-	Ok(lookup_t)
+/// Returns the lookup table for carryless multiplication of two bytes.
+///
+/// Entries are keyed by `a << 8 | b` and hold the key in the high half and the 15-bit carryless
+/// product of `a` and `b` in the low half.
+///
+/// The table is shared by all callers on the same builder, see
+/// [`ConstraintSystemBuilder::shared_table`].
+pub fn clmul_lookup(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString + Clone,
+) -> Result<OracleId, anyhow::Error> {
+	builder.shared_table("u8_arithmetic::clmul", |builder| {
+		builder.push_namespace(name);
+
+		let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_CLMUL, B32::TOWER_LEVEL);
+
+		if let Some(witness) = builder.witness() {
+			let mut lookup_t = witness.new_column::<B32>(lookup_t);
+
+			let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+
+			for (lookup_index, lookup_t) in lookup_t_u32.iter_mut().enumerate() {
+				let a = (lookup_index >> 8) as u8;
+				let b = lookup_index as u8;
+				*lookup_t = (lookup_index << 16 | u8_clmul(a, b) as usize) as u32;
+			}
+		}
+
+		builder.pop_namespace();
+		Ok(lookup_t)
+	})
 }
 
-pub fn dci_lookup(
+/// Carryless product of two bytes, ie. their product as polynomials over GF(2).
+pub(crate) const fn u8_clmul(a: u8, b: u8) -> u16 {
+	let mut product = 0;
+	let mut i = 0;
+	while i < 8 {
+		if (b >> i) & 1 == 1 {
+			product ^= (a as u16) << i;
+		}
+		i += 1;
+	}
+	product
+}
+
+/// Returns the lookup table for the absolute value of a signed byte.
+///
+/// Entries are keyed by the byte `x` and hold the key in the high half and the two's complement
+/// absolute value of `x` in the low byte, see [`i8_abs`](fn@crate::lasso::i8_abs).
+///
+/// The table is shared by all callers on the same builder, see
+/// [`ConstraintSystemBuilder::shared_table`].
+pub fn i8_abs_lookup(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString + Clone,
+) -> Result<OracleId, anyhow::Error> {
+	builder.shared_table("u8_arithmetic::i8_abs", |builder| {
+		builder.push_namespace(name);
+
+		let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ABS, B32::TOWER_LEVEL);
+
+		if let Some(witness) = builder.witness() {
+			let mut lookup_t = witness.new_column::<B32>(lookup_t);
+
+			let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+
+			for (lookup_index, lookup_t) in lookup_t_u32.iter_mut().enumerate() {
+				*lookup_t = (lookup_index << 16 | i8_abs(lookup_index as u8) as usize) as u32;
+			}
+		}
+
+		builder.pop_namespace();
+		Ok(lookup_t)
+	})
+}
+
+/// Absolute value of a byte interpreted as an `i8`, in two's complement.
+///
+/// As with [`i8::wrapping_abs`], the absolute value of `-128` wraps to `-128`, ie. `0x80`.
+pub(crate) const fn i8_abs(x: u8) -> u8 {
+	(x as i8).wrapping_abs() as u8
+}
+
+/// Returns the lookup table for the minimum of two bytes.
+///
+/// Entries are keyed by `a << 8 | b` and hold the key in the high half and `min(a, b)` in the low
+/// byte.
+///
+/// The table is shared by all callers on the same builder, see
+/// [`ConstraintSystemBuilder::shared_table`].
+pub fn min_lookup(
 	builder: &mut ConstraintSystemBuilder,
 	name: impl ToString + Clone,
+) -> Result<OracleId, anyhow::Error> {
+	builder.shared_table("u8_arithmetic::min", |builder| byte_pair_table(builder, name, u8::min))
+}
+
+/// Returns the lookup table for the maximum of two bytes.
+///
+/// Entries are keyed by `a << 8 | b` and hold the key in the high half and `max(a, b)` in the low
+/// byte.
+///
+/// The table is shared by all callers on the same builder, see
+/// [`ConstraintSystemBuilder::shared_table`].
+pub fn max_lookup(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString + Clone,
+) -> Result<OracleId, anyhow::Error> {
+	builder.shared_table("u8_arithmetic::max", |builder| byte_pair_table(builder, name, u8::max))
+}
+
+/// Commits a table keyed by `a << 8 | b` with `op(a, b)` in the low byte of every entry.
+fn byte_pair_table(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	op: fn(u8, u8) -> u8,
 ) -> Result<OracleId, anyhow::Error> {
 	builder.push_namespace(name);
 
-	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_DCI, B32::TOWER_LEVEL);
+	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_MIN_MAX, B32::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
 		let mut lookup_t = witness.new_column::<B32>(lookup_t);
 
 		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
 
-		for first_carry_in_usize in 0..(1 << 1) {
-			for second_carry_in_usize in 0..(1 << 1) {
-				for x_in_usize in 0..(1 << 8) {
-					let lookup_index =
-						(first_carry_in_usize << 9) | (second_carry_in_usize << 8) | x_in_usize;
-					let sum_with_carry_out =
-						x_in_usize + first_carry_in_usize + second_carry_in_usize;
-					let sum_usize = sum_with_carry_out & 0xff;
-					let carry_out_usize = sum_with_carry_out >> 8;
-					let lookup_value = (first_carry_in_usize << 18)
-						| (second_carry_in_usize << 17)
-						| (carry_out_usize << 16)
-						| (x_in_usize << 8)
-						| sum_usize;
-					lookup_t_u32[lookup_index] = lookup_value as u32;
-				}
-			}
+		for (lookup_index, lookup_t) in lookup_t_u32.iter_mut().enumerate() {
+			let a = (lookup_index >> 8) as u8;
+			let b = lookup_index as u8;
+			*lookup_t = (lookup_index << 16 | op(a, b) as usize) as u32;
 		}
 	}
 
@@ -149,14 +358,56 @@ pub fn dci_lookup(
 
 #[cfg(test)]
 mod tests {
-	use binius_field::{BinaryField1b, BinaryField32b, BinaryField8b};
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField16b, BinaryField1b, BinaryField32b, BinaryField8b, TowerField};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
 
 	use crate::{
-		builder::test_utils::test_circuit,
+		builder::{test_utils::test_circuit, witness::read_column, ConstraintSystemBuilder},
 		lasso::{self, batch::LookupBatch},
+		transparent::assert_equals_constants,
 		unconstrained::unconstrained,
 	};
 
+	/// The canonical table of `i8` absolute values, with entries `x << 16 | |x|`.
+	fn canonical_i8_abs_table() -> Vec<BinaryField32b> {
+		(0..=u8::MAX)
+			.map(|x| BinaryField32b::new((x as u32) << 16 | (x as i8).unsigned_abs() as u32))
+			.collect()
+	}
+
+	#[test]
+	fn test_i8_abs_lookup_equals_canonical_table() {
+		test_circuit(|builder| {
+			let lookup_t = super::i8_abs_lookup(builder, "abs table")?;
+			assert_equals_constants(builder, "canonical", lookup_t, &canonical_i8_abs_table())?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_assert_equals_constants_rejects_corrupted_table() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let table = canonical_i8_abs_table();
+		let lookup_t = builder.add_committed("lookup_t", 8, BinaryField32b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut lookup_t_witness = witness.new_column::<BinaryField32b>(lookup_t);
+			let lookup_t_u32 = lookup_t_witness.as_mut_slice::<u32>();
+			for (entry, expected) in lookup_t_u32.iter_mut().zip(&table) {
+				*entry = expected.val();
+			}
+			// |-5| = 6
+			lookup_t_u32[0xfb] += 1;
+		}
+		assert_equals_constants(&mut builder, "canonical", lookup_t, &table).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+
 	#[test]
 	fn test_lasso_u8add_carryfree_rejects_carry() {
 		// TODO: Make this test 100% certain to pass instead of 2^14 bits of security from randomness
@@ -195,7 +446,7 @@ mod tests {
 
 			let mut lookup_batch = LookupBatch::new([mul_lookup_table]);
 
-			let _product = lasso::u8mul(
+			let product = lasso::u8mul(
 				builder,
 				&mut lookup_batch,
 				"lasso_u8mul",
@@ -205,6 +456,84 @@ mod tests {
 			)?;
 
 			lookup_batch.execute::<BinaryField32b>(builder)?;
+
+			if let Some(product) = read_column::<BinaryField16b>(builder, product) {
+				let a = read_column::<BinaryField8b>(builder, mult_a).unwrap();
+				let b = read_column::<BinaryField8b>(builder, mult_b).unwrap();
+				assert_eq!(product.len(), 1 << log_size);
+				for ((a, b), product) in a.into_iter().zip(b).zip(product) {
+					assert_eq!(product.val(), a.val() as u16 * b.val() as u16);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u8mul_column_tower_levels() {
+		let log_size = 10;
+		let mut builder = ConstraintSystemBuilder::new();
+		let mult_a = unconstrained::<BinaryField8b>(&mut builder, "mult_a", log_size).unwrap();
+		let mult_b = unconstrained::<BinaryField8b>(&mut builder, "mult_b", log_size).unwrap();
+		let lookup_t = super::mul_lookup(&mut builder, "mul table").unwrap();
+		let mut lookup_batch = LookupBatch::new([lookup_t]);
+		let product = lasso::u8mul(
+			&mut builder,
+			&mut lookup_batch,
+			"lasso_u8mul",
+			mult_a,
+			mult_b,
+			1 << log_size,
+		)
+		.unwrap();
+
+		let tower_levels = builder.column_tower_levels();
+		assert_eq!(tower_levels[&mult_a], BinaryField8b::TOWER_LEVEL);
+		assert_eq!(tower_levels[&product], BinaryField16b::TOWER_LEVEL);
+		assert_eq!(tower_levels[&lookup_t], BinaryField32b::TOWER_LEVEL);
+
+		let lookup_u = tower_levels
+			.keys()
+			.copied()
+			.find(|&id| {
+				builder
+					.describe_linear_combination(id)
+					.starts_with("lasso_u8mul::lasso_u8mul::lookup_u =")
+			})
+			.unwrap();
+		assert_eq!(tower_levels[&lookup_u], BinaryField32b::TOWER_LEVEL);
+	}
+
+	#[test]
+	fn test_lasso_u8mul_shares_lookup_table() {
+		test_circuit(|builder| {
+			let log_size = 10;
+
+			let mut lookup_tables = Vec::new();
+			for _ in 0..2 {
+				let mult_a = unconstrained::<BinaryField8b>(builder, "mult_a", log_size)?;
+				let mult_b = unconstrained::<BinaryField8b>(builder, "mult_b", log_size)?;
+
+				builder.push_namespace("gadget");
+				let mul_lookup_table = super::mul_lookup(builder, "mul table")?;
+				let mut lookup_batch = LookupBatch::new([mul_lookup_table]);
+				let _product = lasso::u8mul(
+					builder,
+					&mut lookup_batch,
+					"lasso_u8mul",
+					mult_a,
+					mult_b,
+					1 << log_size,
+				)?;
+				lookup_batch.execute::<BinaryField32b>(builder)?;
+				builder.pop_namespace();
+
+				lookup_tables.push(mul_lookup_table);
+			}
+
+			assert_eq!(lookup_tables[0], lookup_tables[1]);
+			assert_ne!(lookup_tables[0], super::add_lookup(builder, "add table")?);
 			Ok(vec![])
 		})
 		.unwrap();
@@ -267,4 +596,174 @@ mod tests {
 		})
 		.expect_err("Channels should be unbalanced");
 	}
+
+	#[test]
+	fn test_lasso_u8_divrem_all_divisors() {
+		test_circuit(|builder| {
+			// Every (dividend, divisor) pair appears in exactly one row, including division by zero.
+			let log_size = 16;
+			let dividend = builder.add_committed("dividend", log_size, BinaryField8b::TOWER_LEVEL);
+			let divisor = builder.add_committed("divisor", log_size, BinaryField8b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut dividend_witness = witness.new_column::<BinaryField8b>(dividend);
+				let mut divisor_witness = witness.new_column::<BinaryField8b>(divisor);
+				for (row_idx, (dividend, divisor)) in dividend_witness
+					.as_mut_slice::<u8>()
+					.iter_mut()
+					.zip(divisor_witness.as_mut_slice::<u8>())
+					.enumerate()
+				{
+					*dividend = (row_idx >> 8) as u8;
+					*divisor = row_idx as u8;
+				}
+			}
+
+			let divrem_lookup_table = super::divrem_lookup(builder, "divrem table")?;
+			let mut lookup_batch = LookupBatch::new([divrem_lookup_table]);
+			let [quotient, remainder] = lasso::u8_divrem(
+				builder,
+				&mut lookup_batch,
+				"lasso_u8_divrem",
+				dividend,
+				divisor,
+				1 << log_size,
+			)?;
+
+			if let Some(witness) = builder.witness() {
+				let quotient = witness.get::<BinaryField8b>(quotient)?.as_slice::<u8>();
+				let remainder = witness.get::<BinaryField8b>(remainder)?.as_slice::<u8>();
+				for (row_idx, (&quotient, &remainder)) in quotient.iter().zip(remainder).enumerate()
+				{
+					let dividend = (row_idx >> 8) as u8;
+					let divisor = row_idx as u8;
+					if divisor == 0 {
+						assert_eq!((quotient, remainder), (0xFF, dividend));
+					} else {
+						assert_eq!(quotient, dividend / divisor);
+						assert_eq!(remainder, dividend % divisor);
+					}
+				}
+			}
+
+			lookup_batch.execute::<BinaryField32b>(builder)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_lasso_i8_abs() {
+		test_circuit(|builder| {
+			let log_size = 8;
+			let x = builder.add_committed("x", log_size, BinaryField8b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut x_witness = witness.new_column::<BinaryField8b>(x);
+				for (row_idx, x) in x_witness.as_mut_slice::<u8>().iter_mut().enumerate() {
+					*x = row_idx as u8;
+				}
+			}
+
+			let abs_lookup_table = super::i8_abs_lookup(builder, "abs table")?;
+			let mut lookup_batch = LookupBatch::new([abs_lookup_table]);
+			let abs = lasso::i8_abs(builder, &mut lookup_batch, "lasso_i8_abs", x, 1 << log_size)?;
+			lookup_batch.execute::<BinaryField32b>(builder)?;
+
+			if let Some(abs) = read_column::<BinaryField8b>(builder, abs) {
+				for (x, abs) in abs.into_iter().enumerate() {
+					assert_eq!(abs.val(), (x as u8 as i8).wrapping_abs() as u8);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_u8_clmul_known_values() {
+		assert_eq!(super::u8_clmul(0, 0xFF), 0);
+		assert_eq!(super::u8_clmul(1, 0xA7), 0xA7);
+		assert_eq!(super::u8_clmul(0x80, 0x80), 0x4000);
+		assert_eq!(super::u8_clmul(0x03, 0x03), 0x05);
+		assert_eq!(super::u8_clmul(0xFF, 0xFF), 0x5555);
+	}
+
+	#[test]
+	fn test_lasso_u8_clmul() {
+		test_circuit(|builder| {
+			let log_size = 12;
+			let a = builder.add_committed("a", log_size, BinaryField8b::TOWER_LEVEL);
+			let b = builder.add_committed("b", log_size, BinaryField8b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut rng = StdRng::seed_from_u64(0);
+				let mut a_witness = witness.new_column::<BinaryField8b>(a);
+				let mut b_witness = witness.new_column::<BinaryField8b>(b);
+				rng.fill(a_witness.as_mut_slice::<u8>());
+				rng.fill(b_witness.as_mut_slice::<u8>());
+			}
+
+			let clmul_lookup_table = super::clmul_lookup(builder, "clmul table")?;
+			let mut lookup_batch = LookupBatch::new([clmul_lookup_table]);
+			let product =
+				lasso::u8_clmul(builder, &mut lookup_batch, "lasso_u8_clmul", a, b, 1 << log_size)?;
+			lookup_batch.execute::<BinaryField32b>(builder)?;
+
+			if let Some(product) = read_column::<BinaryField16b>(builder, product) {
+				let a = read_column::<BinaryField8b>(builder, a).unwrap();
+				let b = read_column::<BinaryField8b>(builder, b).unwrap();
+				for ((a, b), product) in a.into_iter().zip(b).zip(product) {
+					// Carryless multiplication by shifting and adding over the bits of `a`.
+					let expected = (0..8)
+						.filter(|i| (a.val() >> i) & 1 == 1)
+						.fold(0u16, |acc, i| acc ^ ((b.val() as u16) << i));
+					assert_eq!(product.val(), expected);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_lasso_u8_min_max_all_pairs() {
+		test_circuit(|builder| {
+			// Every (a, b) pair appears in exactly one row, including the equal pairs.
+			let log_size = 16;
+			let a = builder.add_committed("a", log_size, BinaryField8b::TOWER_LEVEL);
+			let b = builder.add_committed("b", log_size, BinaryField8b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut a_witness = witness.new_column::<BinaryField8b>(a);
+				let mut b_witness = witness.new_column::<BinaryField8b>(b);
+				for (row_idx, (a, b)) in a_witness
+					.as_mut_slice::<u8>()
+					.iter_mut()
+					.zip(b_witness.as_mut_slice::<u8>())
+					.enumerate()
+				{
+					*a = (row_idx >> 8) as u8;
+					*b = row_idx as u8;
+				}
+			}
+
+			let min_lookup_table = super::min_lookup(builder, "min table")?;
+			let max_lookup_table = super::max_lookup(builder, "max table")?;
+			let mut min_batch = LookupBatch::new([min_lookup_table]);
+			let mut max_batch = LookupBatch::new([max_lookup_table]);
+			let min = lasso::u8_min(builder, &mut min_batch, "lasso_u8_min", a, b, 1 << log_size)?;
+			let max = lasso::u8_max(builder, &mut max_batch, "lasso_u8_max", a, b, 1 << log_size)?;
+			min_batch.execute::<BinaryField32b>(builder)?;
+			max_batch.execute::<BinaryField32b>(builder)?;
+
+			if let Some(min) = read_column::<BinaryField8b>(builder, min) {
+				let max = read_column::<BinaryField8b>(builder, max).unwrap();
+				for (row_idx, (min, max)) in min.into_iter().zip(max).enumerate() {
+					let a = (row_idx >> 8) as u8;
+					let b = row_idx as u8;
+					assert_eq!(min.val(), a.min(b));
+					assert_eq!(max.val(), a.max(b));
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
 }
@@ -2,18 +2,30 @@
 
 pub mod batch;
 pub mod big_integer_ops;
+pub mod i8_abs;
 pub mod lasso;
 pub mod lookups;
+pub mod range_check;
+pub mod set_membership;
 pub mod sha256;
 pub mod u32add;
+pub mod u8_clmul;
+pub mod u8_divrem;
 pub mod u8_double_conditional_increment;
+pub mod u8_min_max;
 pub mod u8add;
 pub mod u8add_carryfree;
 pub mod u8mul;
 
+pub use i8_abs::i8_abs;
+pub use range_check::range_check;
+pub use set_membership::assert_in_set;
 pub use sha256::sha256;
 pub use u32add::u32add;
+pub use u8_clmul::u8_clmul;
+pub use u8_divrem::u8_divrem;
 pub use u8_double_conditional_increment::u8_double_conditional_increment;
+pub use u8_min_max::{u8_max, u8_min};
 pub use u8add::u8add;
 pub use u8add_carryfree::u8add_carryfree;
 pub use u8mul::u8mul;
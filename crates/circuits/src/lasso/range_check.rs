@@ -0,0 +1,177 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{as_packed_field::PackedType, BinaryField1b, BinaryField32b, TowerField};
+use binius_macros::arith_expr;
+
+use super::batch::LookupBatch;
+use crate::{
+	arithmetic,
+	builder::{types::U, ConstraintSystemBuilder},
+	transparent,
+};
+
+type B1 = BinaryField1b;
+type B32 = BinaryField32b;
+
+/// Largest bit width for which the range check is performed with a lookup into a table of all
+/// valid values. Wider ranges are checked via a bit decomposition.
+pub const RANGE_CHECK_LOOKUP_MAX_BITS: usize = 8;
+
+/// Asserts that every value of the `B32` column `input` is less than `2^k`.
+///
+/// For `k <= RANGE_CHECK_LOOKUP_MAX_BITS` the values are looked up in a transparent table
+/// containing `0..2^k`. For larger `k` the column is decomposed into bits and the `32 - k` high
+/// bits are constrained to be zero.
+pub fn range_check(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+	k: usize,
+) -> Result<()> {
+	ensure!(k <= 32, "range check bit width must be at most 32, got {k}");
+
+	builder.push_namespace(name);
+	if k <= RANGE_CHECK_LOOKUP_MAX_BITS {
+		range_check_lookup(builder, input, k)?;
+	} else {
+		range_check_decomposition(builder, input, k)?;
+	}
+	builder.pop_namespace();
+	Ok(())
+}
+
+fn range_check_lookup(
+	builder: &mut ConstraintSystemBuilder,
+	input: OracleId,
+	k: usize,
+) -> Result<()> {
+	let log_rows = builder.log_rows([input])?;
+
+	// The table is padded with zeros up to a full packed element, which is harmless since zero is
+	// in range.
+	let t_log_size = k.max(PackedType::<U, B32>::LOG_WIDTH);
+	let table_values = (0..1u32 << t_log_size)
+		.map(|i| B32::new(if i >> k == 0 { i } else { 0 }))
+		.collect::<Vec<_>>();
+	let lookup_t = transparent::make_transparent(builder, "lookup_t", &table_values)?;
+
+	let mut u_to_t_mapping = vec![];
+	if let Some(witness) = builder.witness() {
+		// Out-of-range values are mapped to the zero entry, which leaves the lookup unbalanced.
+		u_to_t_mapping = witness
+			.get::<B32>(input)?
+			.as_slice::<u32>()
+			.iter()
+			.map(|&value| if value >> k == 0 { value as usize } else { 0 })
+			.collect();
+	}
+
+	let mut lookup_batch = LookupBatch::new([lookup_t]);
+	lookup_batch.add([input], u_to_t_mapping, 1 << log_rows);
+	lookup_batch.execute::<B32>(builder)
+}
+
+fn range_check_decomposition(
+	builder: &mut ConstraintSystemBuilder,
+	input: OracleId,
+	k: usize,
+) -> Result<()> {
+	let log_rows = builder.log_rows([input])?;
+	let bits = builder.add_committed("bits", log_rows + B32::TOWER_LEVEL, B1::TOWER_LEVEL);
+	let bits_packed = builder.add_packed("bits_packed", bits, B32::TOWER_LEVEL)?;
+
+	if let Some(witness) = builder.witness() {
+		let input_witness = witness.get::<B32>(input)?;
+		witness
+			.new_column::<B1>(bits)
+			.as_mut_slice::<u32>()
+			.copy_from_slice(input_witness.as_slice::<u32>());
+		witness.set(bits_packed, witness.get::<B1>(bits)?.repacked::<B32>())?;
+	}
+
+	builder.assert_zero(
+		"decomposition",
+		[bits_packed, input],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+
+	if k < 32 {
+		let high_bits = arithmetic::u32::shr(builder, "high_bits", bits, k)?;
+		builder.assert_zero("high_bits_zero", [high_bits], arith_expr!([x] = x).convert_field());
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField32b;
+
+	use crate::{builder::test_utils::test_circuit, unconstrained::fixed_u32};
+
+	#[test]
+	fn test_range_check_lookup() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let input = fixed_u32::<BinaryField32b>(
+				builder,
+				"input",
+				log_size,
+				vec![(1 << 5) - 1; 1 << log_size],
+			)?;
+			super::range_check(builder, "range_check", input, 5)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_range_check_lookup_rejects() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let input = fixed_u32::<BinaryField32b>(
+				builder,
+				"input",
+				log_size,
+				vec![1 << 5; 1 << log_size],
+			)?;
+			super::range_check(builder, "range_check", input, 5)?;
+			Ok(vec![])
+		})
+		.expect_err("Value 2^k is out of range");
+	}
+
+	#[test]
+	fn test_range_check_decomposition() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let input = fixed_u32::<BinaryField32b>(
+				builder,
+				"input",
+				log_size,
+				vec![(1 << 20) - 1; 1 << log_size],
+			)?;
+			super::range_check(builder, "range_check", input, 20)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_range_check_decomposition_rejects() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let input = fixed_u32::<BinaryField32b>(
+				builder,
+				"input",
+				log_size,
+				vec![1 << 20; 1 << log_size],
+			)?;
+			super::range_check(builder, "range_check", input, 20)?;
+			Ok(vec![])
+		})
+		.expect_err("Value 2^k is out of range");
+	}
+}
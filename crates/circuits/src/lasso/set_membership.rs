@@ -0,0 +1,137 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::collections::HashMap;
+
+use anyhow::{ensure, Result};
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::WithUnderlier,
+	BinaryField32b, BinaryField64b, ExtensionField, TowerField,
+};
+
+use super::batch::LookupBatch;
+use crate::{
+	builder::{
+		types::{F, U},
+		witness::read_column,
+		ConstraintSystemBuilder,
+	},
+	transparent,
+};
+
+type B64 = BinaryField64b;
+
+/// Asserts that every value of the column `value` is an element of `set`.
+///
+/// The values are looked up in a transparent table holding exactly the elements of `set`, padded
+/// with repetitions of its first element up to a power of two. The table is a `B64` column, so
+/// `value` may be a column of any tower level up to `B64` whose values are compared as integers.
+pub fn assert_in_set<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	value: OracleId,
+	set: &[u64],
+) -> Result<()>
+where
+	FS: TowerField + WithUnderlier<Underlier: Into<u64>>,
+	U: PackScalar<FS>,
+	F: ExtensionField<FS>,
+{
+	ensure!(!set.is_empty(), "assert_in_set: set must not be empty");
+	ensure!(FS::TOWER_LEVEL <= B64::TOWER_LEVEL, "assert_in_set: value column must fit in B64");
+
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([value])?;
+
+	let t_len = set
+		.len()
+		.next_power_of_two()
+		.max(PackedType::<U, B64>::WIDTH);
+	let table_values = (0..t_len)
+		.map(|i| B64::new(set.get(i).copied().unwrap_or(set[0])))
+		.collect::<Vec<_>>();
+	let lookup_t = transparent::make_transparent::<B64>(builder, "lookup_t", &table_values)?;
+
+	let mut u_to_t_mapping = vec![];
+	if let Some(values) = read_column::<FS>(builder, value) {
+		let indices = set
+			.iter()
+			.enumerate()
+			.rev()
+			.map(|(index, &element)| (element, index))
+			.collect::<HashMap<_, _>>();
+		// Values outside of the set are mapped to the first entry, which leaves the lookup
+		// unbalanced.
+		u_to_t_mapping = values
+			.into_iter()
+			.map(|value| {
+				indices
+					.get(&value.to_underlier().into())
+					.copied()
+					.unwrap_or(0)
+			})
+			.collect();
+	}
+
+	let mut lookup_batch = LookupBatch::new([lookup_t]);
+	lookup_batch.add([value], u_to_t_mapping, 1 << log_rows);
+	lookup_batch.execute::<BinaryField32b>(builder)?;
+
+	builder.pop_namespace();
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField32b, BinaryField8b};
+
+	use super::assert_in_set;
+	use crate::{builder::test_utils::test_circuit, unconstrained::fixed_u32};
+
+	const OPCODES: [u64; 5] = [0x01, 0x02, 0x10, 0x20, 0xff];
+
+	#[test]
+	fn test_assert_in_set() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let values = (0..1 << log_size)
+				.map(|i| OPCODES[i % OPCODES.len()] as u32)
+				.collect();
+			let input = fixed_u32::<BinaryField32b>(builder, "input", log_size, values)?;
+			assert_in_set::<BinaryField32b>(builder, "in_set", input, &OPCODES)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_assert_in_set_rejects_out_of_set_value() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let mut values = vec![OPCODES[2] as u32; 1 << log_size];
+			values[17] = 0x11;
+			let input = fixed_u32::<BinaryField32b>(builder, "input", log_size, values)?;
+			assert_in_set::<BinaryField32b>(builder, "in_set", input, &OPCODES)?;
+			Ok(vec![])
+		})
+		.expect_err("0x11 is not in the set");
+	}
+
+	#[test]
+	fn test_assert_in_set_small_field() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let input = builder.add_committed("input", log_size, 3);
+			if let Some(witness) = builder.witness() {
+				let mut column = witness.new_column::<BinaryField8b>(input);
+				for (i, value) in column.as_mut_slice::<u8>().iter_mut().enumerate() {
+					*value = OPCODES[i % OPCODES.len()] as u8;
+				}
+			}
+			assert_in_set::<BinaryField8b>(builder, "in_set", input, &OPCODES)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
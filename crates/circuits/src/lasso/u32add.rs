@@ -18,6 +18,7 @@ use crate::{
 		types::{F, U},
 		ConstraintSystemBuilder,
 	},
+	helpers::tower_basis_for_byte_position,
 	pack::pack,
 };
 
@@ -132,9 +133,9 @@ impl SeveralU32add {
 			[
 				(cin, <F as TowerField>::basis(0, 25)?),
 				(cout, <F as TowerField>::basis(0, 24)?),
-				(xin_u8, <F as TowerField>::basis(3, 2)?),
-				(yin_u8, <F as TowerField>::basis(3, 1)?),
-				(sum_packed, <F as TowerField>::basis(3, 0)?),
+				(xin_u8, tower_basis_for_byte_position(3, 2)?),
+				(yin_u8, tower_basis_for_byte_position(3, 1)?),
+				(sum_packed, tower_basis_for_byte_position(3, 0)?),
 			],
 		)?;
 
@@ -0,0 +1,76 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField16b, BinaryField32b, BinaryField8b, TowerField};
+use itertools::izip;
+
+use super::{batch::LookupBatch, lookups::u8_arithmetic::u8_clmul as clmul};
+use crate::{
+	builder::{types::F, ConstraintSystemBuilder},
+	helpers::tower_basis_for_byte_position,
+};
+
+type B8 = BinaryField8b;
+type B16 = BinaryField16b;
+type B32 = BinaryField32b;
+
+/// Carryless multiplication of two bytes, returning the 15-bit product column.
+///
+/// The product is that of `a` and `b` as polynomials over GF(2), without reduction. The rows are
+/// looked up in the table returned by [`clmul_lookup`](super::lookups::u8_arithmetic::clmul_lookup),
+/// which must be one of the tables of `lookup_batch`.
+pub fn u8_clmul(
+	builder: &mut ConstraintSystemBuilder,
+	lookup_batch: &mut LookupBatch,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+	n_multiplications: usize,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([a, b])?;
+	let product = builder.add_committed("product", log_rows, B16::TOWER_LEVEL);
+
+	let lookup_u = builder.add_linear_combination(
+		"lookup_u",
+		log_rows,
+		[
+			(a, tower_basis_for_byte_position(3, 3)?),
+			(b, tower_basis_for_byte_position(3, 2)?),
+			(product, <F as TowerField>::basis(4, 0)?),
+		],
+	)?;
+
+	let mut u_to_t_mapping = Vec::new();
+
+	if let Some(witness) = builder.witness() {
+		let mut product_witness = witness.new_column::<B16>(product);
+		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
+		let mut u_to_t_mapping_witness = vec![0; 1 << log_rows];
+
+		let a_u8 = witness.get::<B8>(a)?.as_slice::<u8>();
+		let b_u8 = witness.get::<B8>(b)?.as_slice::<u8>();
+
+		for (&a, &b, product, lookup_u, u_to_t) in izip!(
+			a_u8,
+			b_u8,
+			product_witness.as_mut_slice::<u16>(),
+			lookup_u_witness.as_mut_slice::<u32>(),
+			u_to_t_mapping_witness.iter_mut()
+		) {
+			*product = clmul(a, b);
+
+			let lookup_index = (a as usize) << 8 | b as usize;
+			*lookup_u = (lookup_index << 16 | *product as usize) as u32;
+			*u_to_t = lookup_index;
+		}
+
+		u_to_t_mapping = u_to_t_mapping_witness;
+	}
+
+	lookup_batch.add([lookup_u], u_to_t_mapping, n_multiplications);
+
+	builder.pop_namespace();
+	Ok(product)
+}
@@ -0,0 +1,78 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField32b, BinaryField8b, TowerField};
+use itertools::izip;
+
+use super::{batch::LookupBatch, lookups::u8_arithmetic::u8_divrem as divrem};
+use crate::{builder::ConstraintSystemBuilder, helpers::tower_basis_for_byte_position};
+
+type B8 = BinaryField8b;
+type B32 = BinaryField32b;
+
+/// Unsigned 8-bit division, returning the quotient and remainder columns.
+///
+/// The rows are looked up in the table returned by
+/// [`divrem_lookup`](super::lookups::u8_arithmetic::divrem_lookup), which must be one of the
+/// tables of `lookup_batch`. Division by zero yields a quotient of `0xFF` and a remainder equal to
+/// the dividend, as in RISC-V.
+pub fn u8_divrem(
+	builder: &mut ConstraintSystemBuilder,
+	lookup_batch: &mut LookupBatch,
+	name: impl ToString,
+	dividend: OracleId,
+	divisor: OracleId,
+	n_divisions: usize,
+) -> Result<[OracleId; 2], anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([dividend, divisor])?;
+	let [quotient, remainder] =
+		builder.add_committed_multiple("quotient_remainder", log_rows, B8::TOWER_LEVEL);
+
+	let lookup_u = builder.add_linear_combination(
+		"lookup_u",
+		log_rows,
+		[
+			(dividend, tower_basis_for_byte_position(3, 3)?),
+			(divisor, tower_basis_for_byte_position(3, 2)?),
+			(quotient, tower_basis_for_byte_position(3, 1)?),
+			(remainder, tower_basis_for_byte_position(3, 0)?),
+		],
+	)?;
+
+	let mut u_to_t_mapping = Vec::new();
+
+	if let Some(witness) = builder.witness() {
+		let mut quotient_witness = witness.new_column::<B8>(quotient);
+		let mut remainder_witness = witness.new_column::<B8>(remainder);
+		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
+		let mut u_to_t_mapping_witness = vec![0; 1 << log_rows];
+
+		let dividend_u8 = witness.get::<B8>(dividend)?.as_slice::<u8>();
+		let divisor_u8 = witness.get::<B8>(divisor)?.as_slice::<u8>();
+
+		for (&dividend, &divisor, quotient, remainder, lookup_u, u_to_t) in izip!(
+			dividend_u8,
+			divisor_u8,
+			quotient_witness.as_mut_slice::<u8>(),
+			remainder_witness.as_mut_slice::<u8>(),
+			lookup_u_witness.as_mut_slice::<u32>(),
+			u_to_t_mapping_witness.iter_mut()
+		) {
+			(*quotient, *remainder) = divrem(dividend, divisor);
+
+			let lookup_index = (dividend as usize) << 8 | divisor as usize;
+			*lookup_u =
+				(lookup_index << 16 | (*quotient as usize) << 8 | *remainder as usize) as u32;
+			*u_to_t = lookup_index;
+		}
+
+		u_to_t_mapping = u_to_t_mapping_witness;
+	}
+
+	lookup_batch.add([lookup_u], u_to_t_mapping, n_divisions);
+
+	builder.pop_namespace();
+	Ok([quotient, remainder])
+}
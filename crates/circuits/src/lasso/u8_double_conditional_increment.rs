@@ -5,7 +5,10 @@ use binius_core::oracle::OracleId;
 use binius_field::{BinaryField1b, BinaryField32b, BinaryField8b, TowerField};
 
 use super::batch::LookupBatch;
-use crate::builder::{types::F, ConstraintSystemBuilder};
+use crate::{
+	builder::{types::F, ConstraintSystemBuilder},
+	helpers::tower_basis_for_byte_position,
+};
 
 type B1 = BinaryField1b;
 type B8 = BinaryField8b;
@@ -32,9 +35,9 @@ pub fn u8_double_conditional_increment(
 		[
 			(first_carry_in, <F as TowerField>::basis(0, 18)?),
 			(second_carry_in, <F as TowerField>::basis(0, 17)?),
-			(carry_out, <F as TowerField>::basis(3, 2)?),
-			(x_in, <F as TowerField>::basis(3, 1)?),
-			(sum, <F as TowerField>::basis(3, 0)?),
+			(carry_out, tower_basis_for_byte_position(3, 2)?),
+			(x_in, tower_basis_for_byte_position(3, 1)?),
+			(sum, tower_basis_for_byte_position(3, 0)?),
 		],
 	)?;
 
@@ -0,0 +1,102 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField32b, BinaryField8b, TowerField};
+use itertools::izip;
+
+use super::batch::LookupBatch;
+use crate::{builder::ConstraintSystemBuilder, helpers::tower_basis_for_byte_position};
+
+type B8 = BinaryField8b;
+type B32 = BinaryField32b;
+
+/// Minimum of two bytes, returning the `min(a, b)` column.
+///
+/// The rows are looked up in the table returned by
+/// [`min_lookup`](super::lookups::u8_arithmetic::min_lookup), which must be one of the tables of
+/// `lookup_batch`.
+pub fn u8_min(
+	builder: &mut ConstraintSystemBuilder,
+	lookup_batch: &mut LookupBatch,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+	n_lookups: usize,
+) -> Result<OracleId, anyhow::Error> {
+	byte_pair_op(builder, lookup_batch, name, "min", a, b, n_lookups, u8::min)
+}
+
+/// Maximum of two bytes, returning the `max(a, b)` column.
+///
+/// The rows are looked up in the table returned by
+/// [`max_lookup`](super::lookups::u8_arithmetic::max_lookup), which must be one of the tables of
+/// `lookup_batch`.
+pub fn u8_max(
+	builder: &mut ConstraintSystemBuilder,
+	lookup_batch: &mut LookupBatch,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+	n_lookups: usize,
+) -> Result<OracleId, anyhow::Error> {
+	byte_pair_op(builder, lookup_batch, name, "max", a, b, n_lookups, u8::max)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn byte_pair_op(
+	builder: &mut ConstraintSystemBuilder,
+	lookup_batch: &mut LookupBatch,
+	name: impl ToString,
+	output_name: &str,
+	a: OracleId,
+	b: OracleId,
+	n_lookups: usize,
+	op: fn(u8, u8) -> u8,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([a, b])?;
+	let output = builder.add_committed(output_name, log_rows, B8::TOWER_LEVEL);
+
+	let lookup_u = builder.add_linear_combination(
+		"lookup_u",
+		log_rows,
+		[
+			(a, tower_basis_for_byte_position(3, 3)?),
+			(b, tower_basis_for_byte_position(3, 2)?),
+			(output, tower_basis_for_byte_position(3, 0)?),
+		],
+	)?;
+
+	let mut u_to_t_mapping = Vec::new();
+
+	if let Some(witness) = builder.witness() {
+		let mut output_witness = witness.new_column::<B8>(output);
+		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
+		let mut u_to_t_mapping_witness = vec![0; 1 << log_rows];
+
+		let a_u8 = witness.get::<B8>(a)?.as_slice::<u8>();
+		let b_u8 = witness.get::<B8>(b)?.as_slice::<u8>();
+
+		for (&a, &b, output, lookup_u, u_to_t) in izip!(
+			a_u8,
+			b_u8,
+			output_witness.as_mut_slice::<u8>(),
+			lookup_u_witness.as_mut_slice::<u32>(),
+			u_to_t_mapping_witness.iter_mut()
+		) {
+			*output = op(a, b);
+
+			let lookup_index = (a as usize) << 8 | b as usize;
+			*lookup_u = (lookup_index << 16 | *output as usize) as u32;
+			*u_to_t = lookup_index;
+		}
+
+		u_to_t_mapping = u_to_t_mapping_witness;
+	}
+
+	lookup_batch.add([lookup_u], u_to_t_mapping, n_lookups);
+
+	builder.pop_namespace();
+	Ok(output)
+}
@@ -5,7 +5,7 @@ use binius_core::oracle::OracleId;
 use binius_field::{BinaryField1b, BinaryField32b, BinaryField8b, TowerField};
 
 use super::batch::LookupBatch;
-use crate::builder::{types::F, ConstraintSystemBuilder};
+use crate::{builder::ConstraintSystemBuilder, helpers::tower_basis_for_byte_position};
 
 type B1 = BinaryField1b;
 type B8 = BinaryField8b;
@@ -28,10 +28,10 @@ pub fn u8add_carryfree(
 		"lookup_u",
 		log_size,
 		[
-			(carry_in, <F as TowerField>::basis(3, 3)?),
-			(x_in, <F as TowerField>::basis(3, 2)?),
-			(y_in, <F as TowerField>::basis(3, 1)?),
-			(sum, <F as TowerField>::basis(3, 0)?),
+			(carry_in, tower_basis_for_byte_position(3, 3)?),
+			(x_in, tower_basis_for_byte_position(3, 2)?),
+			(y_in, tower_basis_for_byte_position(3, 1)?),
+			(sum, tower_basis_for_byte_position(3, 0)?),
 		],
 	)?;
 
@@ -6,7 +6,7 @@ use binius_field::{BinaryField16b, BinaryField32b, BinaryField8b, TowerField};
 use itertools::izip;
 
 use super::batch::LookupBatch;
-use crate::builder::{types::F, ConstraintSystemBuilder};
+use crate::{builder::ConstraintSystemBuilder, helpers::tower_basis_for_byte_position};
 
 type B8 = BinaryField8b;
 type B16 = BinaryField16b;
@@ -28,10 +28,10 @@ pub fn u8mul_bytesliced(
 		"lookup_u",
 		log_rows,
 		[
-			(mult_a, <F as TowerField>::basis(3, 3)?),
-			(mult_b, <F as TowerField>::basis(3, 2)?),
-			(product[1], <F as TowerField>::basis(3, 1)?),
-			(product[0], <F as TowerField>::basis(3, 0)?),
+			(mult_a, tower_basis_for_byte_position(B8::TOWER_LEVEL, 3)?),
+			(mult_b, tower_basis_for_byte_position(B8::TOWER_LEVEL, 2)?),
+			(product[1], tower_basis_for_byte_position(B8::TOWER_LEVEL, 1)?),
+			(product[0], tower_basis_for_byte_position(B8::TOWER_LEVEL, 0)?),
 		],
 	)?;
 
@@ -98,8 +98,8 @@ pub fn u8mul(
 		"bytes summed",
 		log_rows,
 		[
-			(product_bytesliced[0], <F as TowerField>::basis(3, 0)?),
-			(product_bytesliced[1], <F as TowerField>::basis(3, 1)?),
+			(product_bytesliced[0], tower_basis_for_byte_position(B8::TOWER_LEVEL, 0)?),
+			(product_bytesliced[1], tower_basis_for_byte_position(B8::TOWER_LEVEL, 1)?),
 		],
 	)?;
 
@@ -14,10 +14,17 @@ pub mod bitwise;
 pub mod blake3;
 pub mod builder;
 pub mod collatz;
+pub mod helpers;
 pub mod keccakf;
 pub mod lasso;
+pub mod narrow;
+pub mod nibbles;
 mod pack;
+pub mod permutation;
 pub mod plain_lookup;
+pub mod prefix_xor;
+pub mod replicate;
+pub mod select;
 pub mod sha256;
 pub mod transparent;
 pub mod u32fib;
@@ -26,25 +33,35 @@ pub mod vision;
 
 #[cfg(test)]
 mod tests {
+	use std::fs::File;
+
 	use binius_core::{
 		constraint_system::{
 			self,
 			channel::{Boundary, FlushDirection},
+			ConstraintSystem, Proof, ZerocheckChallenges,
 		},
 		fiat_shamir::HasherChallenger,
+		oracle::ShiftVariant,
 		tower::CanonicalTowerFamily,
+		transcript::{StreamingProverTranscript, VerifierTranscript},
+		witness::MultilinearExtensionIndex,
 	};
 	use binius_field::{
-		as_packed_field::PackedType, underlier::WithUnderlier, BinaryField8b, Field,
+		as_packed_field::PackedType, underlier::WithUnderlier, BinaryField1b, BinaryField8b, Field,
+		TowerField,
 	};
 	use binius_hal::make_portable_backend;
 	use binius_hash::compress::Groestl256ByteCompression;
 	use binius_math::DefaultEvaluationDomainFactory;
 	use groestl_crypto::Groestl256;
 
-	use crate::builder::{
-		types::{F, U},
-		ConstraintSystemBuilder,
+	use crate::{
+		arithmetic, bitwise,
+		builder::{
+			types::{F, U},
+			CompiledConstraintSystem, ConstraintSystemBuilder,
+		},
 	};
 
 	#[test]
@@ -161,4 +178,250 @@ mod tests {
 		>(&constraint_system, 1, 10, &boundaries, proof)
 		.unwrap();
 	}
+
+	fn build_u32_add(
+		allocator: &bumpalo::Bump,
+	) -> (ConstraintSystem<F>, MultilinearExtensionIndex<U, F>) {
+		let mut builder = ConstraintSystemBuilder::new_with_witness(allocator);
+
+		let log_size = 10;
+		let a = builder.add_committed("a", log_size, BinaryField1b::TOWER_LEVEL);
+		let b = builder.add_committed("b", log_size, BinaryField1b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut a = witness.new_column::<BinaryField1b>(a);
+			let mut b = witness.new_column::<BinaryField1b>(b);
+			for (i, (a, b)) in a
+				.as_mut_slice::<u32>()
+				.iter_mut()
+				.zip(b.as_mut_slice::<u32>())
+				.enumerate()
+			{
+				*a = (i as u32).wrapping_mul(0x9E3779B9);
+				*b = (i as u32).wrapping_mul(0x85EBCA6B);
+			}
+		}
+		arithmetic::u32::add(&mut builder, "u32add", a, b, arithmetic::Flags::Unchecked).unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		(builder.build().unwrap(), witness)
+	}
+
+	fn prove_u32_add(
+		zerocheck_challenges: ZerocheckChallenges<F>,
+	) -> (ConstraintSystem<F>, Proof, Vec<F>) {
+		let allocator = bumpalo::Bump::new();
+		let (constraint_system, witness) = build_u32_add(&allocator);
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let (proof, zerocheck_challenges) = constraint_system::prove_with_zerocheck_challenges::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			1,
+			10,
+			&[],
+			witness,
+			zerocheck_challenges,
+			&domain_factory,
+			&backend,
+		)
+		.unwrap();
+		(constraint_system, proof, zerocheck_challenges)
+	}
+
+	fn verify_u32_add(
+		constraint_system: &ConstraintSystem<F>,
+		proof: Proof,
+		zerocheck_challenges: ZerocheckChallenges<F>,
+	) -> Result<(), constraint_system::error::Error> {
+		constraint_system::verify_with_zerocheck_challenges::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(constraint_system, 1, 10, &[], proof, zerocheck_challenges)
+	}
+
+	#[test]
+	fn test_supplied_zerocheck_challenges() {
+		let (constraint_system, sampled_proof, sampled_challenges) =
+			prove_u32_add(ZerocheckChallenges::Sampled);
+
+		verify_u32_add(&constraint_system, sampled_proof, ZerocheckChallenges::Sampled).unwrap();
+
+		// A supplied point is used as is and verifies only against that point.
+		let mut supplied_challenges = sampled_challenges;
+		supplied_challenges[0] += F::ONE;
+		let (_, supplied_proof, used_challenges) =
+			prove_u32_add(ZerocheckChallenges::Supplied(supplied_challenges.clone()));
+		assert_eq!(used_challenges, supplied_challenges);
+		verify_u32_add(
+			&constraint_system,
+			supplied_proof.clone(),
+			ZerocheckChallenges::Supplied(supplied_challenges.clone()),
+		)
+		.unwrap();
+		assert!(verify_u32_add(
+			&constraint_system,
+			supplied_proof.clone(),
+			ZerocheckChallenges::Sampled
+		)
+		.is_err());
+
+		// The supplied point is part of the transcript, so the verifier rejects any other point.
+		let mut other_challenges = supplied_challenges;
+		other_challenges[1] += F::ONE;
+		assert!(matches!(
+			verify_u32_add(
+				&constraint_system,
+				supplied_proof,
+				ZerocheckChallenges::Supplied(other_challenges)
+			),
+			Err(constraint_system::error::Error::Verification(
+				constraint_system::error::VerificationError::IncorrectZerocheckChallenges
+			))
+		));
+	}
+
+	#[test]
+	fn test_streamed_proof_matches_in_memory() {
+		let (constraint_system, proof, _) = prove_u32_add(ZerocheckChallenges::Sampled);
+
+		let allocator = bumpalo::Bump::new();
+		let (_, witness) = build_u32_add(&allocator);
+		let path =
+			std::env::temp_dir().join(format!("binius-streamed-proof-{}", std::process::id()));
+		let mut transcript = StreamingProverTranscript::<HasherChallenger<Groestl256>>::new(
+			File::create(&path).unwrap(),
+		);
+		constraint_system::prove_with_transcript::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			1,
+			10,
+			&[],
+			witness,
+			ZerocheckChallenges::Sampled,
+			&DefaultEvaluationDomainFactory::default(),
+			&make_portable_backend(),
+			&mut transcript,
+		)
+		.unwrap();
+		transcript.finalize().unwrap();
+		assert_eq!(std::fs::read(&path).unwrap(), proof.transcript);
+
+		let result = constraint_system::verify_with_transcript::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(
+			&constraint_system,
+			1,
+			10,
+			&[],
+			ZerocheckChallenges::Sampled,
+			VerifierTranscript::from_reader(File::open(&path).unwrap()).unwrap(),
+		);
+		std::fs::remove_file(&path).unwrap();
+		result.unwrap();
+	}
+
+	#[test]
+	fn test_compiled_constraint_system_binds_multiple_witnesses() {
+		let log_size = 10;
+		let mut builder = ConstraintSystemBuilder::new();
+		let a = builder.add_committed("a", log_size, BinaryField1b::TOWER_LEVEL);
+		let b = builder.add_committed("b", log_size, BinaryField1b::TOWER_LEVEL);
+		let and = bitwise::and(&mut builder, "and", a, b).unwrap();
+		let xor = builder
+			.add_linear_combination("xor", log_size, [(a, F::ONE), (b, F::ONE)])
+			.unwrap();
+		let compiled = builder.compile().unwrap();
+
+		let prove_with = |compiled: &CompiledConstraintSystem, seed: u32| {
+			let allocator = bumpalo::Bump::new();
+			let witness = compiled.witness_builder(&allocator);
+			{
+				let mut a_col = witness.new_column::<BinaryField1b>(a);
+				let mut b_col = witness.new_column::<BinaryField1b>(b);
+				let mut and_col = witness.new_column::<BinaryField1b>(and);
+				let mut xor_col = witness.new_column::<BinaryField1b>(xor);
+				for (i, (((a, b), and), xor)) in a_col
+					.as_mut_slice::<u32>()
+					.iter_mut()
+					.zip(b_col.as_mut_slice::<u32>())
+					.zip(and_col.as_mut_slice::<u32>())
+					.zip(xor_col.as_mut_slice::<u32>())
+					.enumerate()
+				{
+					*a = (i as u32 ^ seed).wrapping_mul(0x9E3779B9);
+					*b = (i as u32 ^ seed).wrapping_mul(0x85EBCA6B);
+					*and = *a & *b;
+					*xor = *a ^ *b;
+				}
+			}
+			let bound = compiled.bind_witness(witness.build().unwrap()).unwrap();
+
+			let domain_factory = DefaultEvaluationDomainFactory::default();
+			let backend = make_portable_backend();
+			constraint_system::prove::<
+				U,
+				CanonicalTowerFamily,
+				_,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+				_,
+			>(bound.constraint_system, 1, 10, &[], bound.witness, &domain_factory, &backend)
+			.unwrap()
+		};
+
+		let proofs = [prove_with(&compiled, 0), prove_with(&compiled, 0xDEADBEEF)];
+		assert_ne!(proofs[0].transcript, proofs[1].transcript);
+		for proof in proofs {
+			constraint_system::verify::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+			>(compiled.constraint_system(), 1, 10, &[], proof)
+			.unwrap();
+		}
+
+		// A witness missing any oracle of the compiled system cannot be bound.
+		let allocator = bumpalo::Bump::new();
+		let witness = compiled.witness_builder(&allocator);
+		witness.new_column::<BinaryField1b>(a);
+		witness.new_column::<BinaryField1b>(b);
+		assert!(compiled.bind_witness(witness.build().unwrap()).is_err());
+	}
+
+	#[test]
+	fn test_compile_rejects_derived_oracles() {
+		let mut builder = ConstraintSystemBuilder::new();
+		let a = builder.add_committed("a", 10, BinaryField1b::TOWER_LEVEL);
+		builder
+			.add_shifted("a_shifted", a, 1, 5, ShiftVariant::CircularLeft)
+			.unwrap();
+		assert!(builder.compile().is_err());
+	}
 }
@@ -0,0 +1,164 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField16b, BinaryField8b, TowerField};
+use binius_macros::arith_expr;
+use itertools::izip;
+
+use crate::{builder::ConstraintSystemBuilder, helpers::tower_basis_for_byte_position};
+
+type B8 = BinaryField8b;
+type B16 = BinaryField16b;
+
+/// Splits a `B16` column into its low and high bytes as two `B8` columns.
+///
+/// The bytes are committed and constrained to recompose to the input column.
+fn split_bytes16(builder: &mut ConstraintSystemBuilder, input: OracleId) -> Result<[OracleId; 2]> {
+	let log_rows = builder.log_rows([input])?;
+	let low = builder.add_committed("low", log_rows, B8::TOWER_LEVEL);
+	let high = builder.add_committed("high", log_rows, B8::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let input_u16 = witness.get::<B16>(input)?.as_slice::<u16>();
+
+		let mut low_witness = witness.new_column::<B8>(low);
+		let mut high_witness = witness.new_column::<B8>(high);
+		for (low, high, &input) in
+			izip!(low_witness.as_mut_slice::<u8>(), high_witness.as_mut_slice::<u8>(), input_u16)
+		{
+			*low = input as u8;
+			*high = (input >> 8) as u8;
+		}
+	}
+
+	let recomposed = builder.add_linear_combination(
+		"recomposed",
+		log_rows,
+		[
+			(low, tower_basis_for_byte_position(B8::TOWER_LEVEL, 0)?),
+			(high, tower_basis_for_byte_position(B8::TOWER_LEVEL, 1)?),
+		],
+	)?;
+	if let Some(witness) = builder.witness() {
+		let low_u8 = witness.get::<B8>(low)?.as_slice::<u8>();
+		let high_u8 = witness.get::<B8>(high)?.as_slice::<u8>();
+
+		let mut recomposed_witness = witness.new_column::<B16>(recomposed);
+		for (recomposed, &low, &high) in
+			izip!(recomposed_witness.as_mut_slice::<u16>(), low_u8, high_u8)
+		{
+			*recomposed = (high as u16) << 8 | low as u16;
+		}
+	}
+	builder.assert_zero(
+		"split_bytes16",
+		[recomposed, input],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+
+	Ok([low, high])
+}
+
+/// Truncates a `B16` column to its low byte, as a `B8` column.
+///
+/// The low and high bytes are committed and constrained to recompose to the input, and the low
+/// byte is returned.
+pub fn truncate16to8(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId> {
+	builder.push_namespace(name);
+	let [low, _high] = split_bytes16(builder, input)?;
+	builder.pop_namespace();
+	Ok(low)
+}
+
+/// Narrows a `B16` column to a `B8` column, constraining the high byte of every row to be zero.
+pub fn narrow16to8_checked(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId> {
+	builder.push_namespace(name);
+	let [low, high] = split_bytes16(builder, input)?;
+	builder.assert_zero("high_byte_zero", [high], arith_expr!([x] = x).convert_field());
+	builder.pop_namespace();
+	Ok(low)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
+	use binius_field::{BinaryField16b, BinaryField8b, TowerField};
+
+	use crate::builder::{test_utils::test_circuit, ConstraintSystemBuilder};
+
+	const LOG_SIZE: usize = 8;
+
+	fn input_column(
+		builder: &mut ConstraintSystemBuilder,
+		values: impl Fn(usize) -> u16,
+	) -> OracleId {
+		let input = builder.add_committed("input", LOG_SIZE, BinaryField16b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			for (i, value) in witness
+				.new_column::<BinaryField16b>(input)
+				.as_mut_slice::<u16>()
+				.iter_mut()
+				.enumerate()
+			{
+				*value = values(i);
+			}
+		}
+		input
+	}
+
+	#[test]
+	fn test_truncate16to8() {
+		test_circuit(|builder| {
+			let input = input_column(builder, |i| (i as u16).wrapping_mul(0x9e37) ^ 0x1234);
+			let low = super::truncate16to8(builder, "truncate", input)?;
+
+			if let Some(witness) = builder.witness() {
+				let input_u16 = witness.get::<BinaryField16b>(input)?.as_slice::<u16>();
+				let low_u8 = witness.get::<BinaryField8b>(low)?.as_slice::<u8>();
+				for (&x, &low) in input_u16.iter().zip(low_u8) {
+					assert_eq!(low, x as u8);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_narrow16to8_checked() {
+		test_circuit(|builder| {
+			let input = input_column(builder, |i| i as u16);
+			let low = super::narrow16to8_checked(builder, "narrow", input)?;
+
+			if let Some(witness) = builder.witness() {
+				let low_u8 = witness.get::<BinaryField8b>(low)?.as_slice::<u8>();
+				for (i, &low) in low_u8.iter().enumerate() {
+					assert_eq!(low as usize, i);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_narrow16to8_checked_rejects_nonzero_high_byte() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let input =
+			input_column(&mut builder, |i| if i == 17 { 0x100 | i as u16 } else { i as u16 });
+		super::narrow16to8_checked(&mut builder, "narrow", input).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+}
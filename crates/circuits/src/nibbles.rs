@@ -0,0 +1,134 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField4b, BinaryField8b, TowerField};
+use binius_macros::arith_expr;
+use itertools::izip;
+
+use crate::builder::{types::F, ConstraintSystemBuilder};
+
+type B4 = BinaryField4b;
+type B8 = BinaryField8b;
+
+const fn get_nibble(nibbles_u8: &[u8], row_idx: usize) -> u8 {
+	(nibbles_u8[row_idx / 2] >> (4 * (row_idx % 2))) & 0xF
+}
+
+/// Packs two `B4` columns into a `B8` column, with `low` as the low nibble and `high` as the high
+/// nibble of each byte.
+pub fn pack_nibbles(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	[low, high]: [OracleId; 2],
+) -> Result<OracleId> {
+	let log_rows = builder.log_rows([low, high])?;
+	let packed = builder.add_linear_combination(
+		name,
+		log_rows,
+		[
+			(low, <F as TowerField>::basis(B4::TOWER_LEVEL, 0)?),
+			(high, <F as TowerField>::basis(B4::TOWER_LEVEL, 1)?),
+		],
+	)?;
+
+	if let Some(witness) = builder.witness() {
+		let low_u8 = witness.get::<B4>(low)?.as_slice::<u8>();
+		let high_u8 = witness.get::<B4>(high)?.as_slice::<u8>();
+
+		let mut packed_witness = witness.new_column::<B8>(packed);
+		for (row_idx, packed) in packed_witness.as_mut_slice::<u8>().iter_mut().enumerate() {
+			*packed = get_nibble(high_u8, row_idx) << 4 | get_nibble(low_u8, row_idx);
+		}
+	}
+
+	Ok(packed)
+}
+
+/// Splits a `B8` column into its low and high nibbles as two `B4` columns.
+///
+/// The nibbles are committed and constrained to recompose to the input column.
+pub fn unpack_nibbles(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	packed: OracleId,
+) -> Result<[OracleId; 2]> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([packed])?;
+	let low = builder.add_committed("low", log_rows, B4::TOWER_LEVEL);
+	let high = builder.add_committed("high", log_rows, B4::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let packed_u8 = witness.get::<B8>(packed)?.as_slice::<u8>();
+
+		let mut low_witness = witness.new_column::<B4>(low);
+		let mut high_witness = witness.new_column::<B4>(high);
+		for (low, high, packed) in izip!(
+			low_witness.as_mut_slice::<u8>(),
+			high_witness.as_mut_slice::<u8>(),
+			packed_u8.chunks(2)
+		) {
+			*low = (packed[1] & 0xF) << 4 | (packed[0] & 0xF);
+			*high = (packed[1] >> 4) << 4 | (packed[0] >> 4);
+		}
+	}
+
+	let recomposed = pack_nibbles(builder, "recomposed", [low, high])?;
+	builder.assert_zero(
+		"unpack_nibbles",
+		[recomposed, packed],
+		arith_expr!([x, y] = x - y).convert_field(),
+	);
+
+	builder.pop_namespace();
+	Ok([low, high])
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField4b, BinaryField8b, TowerField};
+
+	use crate::builder::test_utils::test_circuit;
+
+	#[test]
+	fn test_pack_unpack_nibbles_roundtrip() {
+		test_circuit(|builder| {
+			// Every pair of nibbles appears in exactly one row.
+			let log_size = 8;
+			let low = builder.add_committed("low", log_size, BinaryField4b::TOWER_LEVEL);
+			let high = builder.add_committed("high", log_size, BinaryField4b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut low_witness = witness.new_column::<BinaryField4b>(low);
+				let mut high_witness = witness.new_column::<BinaryField4b>(high);
+				for (i, (low, high)) in low_witness
+					.as_mut_slice::<u8>()
+					.iter_mut()
+					.zip(high_witness.as_mut_slice::<u8>())
+					.enumerate()
+				{
+					let rows = [2 * i, 2 * i + 1].map(|row| row as u8);
+					*low = (rows[1] & 0xF) << 4 | (rows[0] & 0xF);
+					*high = (rows[1] >> 4) << 4 | (rows[0] >> 4);
+				}
+			}
+
+			let packed = super::pack_nibbles(builder, "packed", [low, high])?;
+			let [unpacked_low, unpacked_high] = super::unpack_nibbles(builder, "unpacked", packed)?;
+
+			if let Some(witness) = builder.witness() {
+				let packed_u8 = witness.get::<BinaryField8b>(packed)?.as_slice::<u8>();
+				for (row_idx, &packed) in packed_u8.iter().enumerate().take(1 << log_size) {
+					assert_eq!(packed as usize, row_idx);
+				}
+				for (original, unpacked) in [(low, unpacked_low), (high, unpacked_high)] {
+					assert_eq!(
+						witness.get::<BinaryField4b>(original)?.as_slice::<u8>(),
+						witness.get::<BinaryField4b>(unpacked)?.as_slice::<u8>()
+					);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
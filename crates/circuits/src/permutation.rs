@@ -0,0 +1,82 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::OracleId;
+
+use crate::builder::ConstraintSystemBuilder;
+
+/// Constrains column `b` to be a permutation of column `a`.
+///
+/// Both columns must have the same number of rows. A fresh channel is created on which all values
+/// of `a` are pushed and all values of `b` are pulled, so that the channel balances if and only if
+/// the two columns hold the same multiset of values.
+pub fn assert_permutation(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	a: OracleId,
+	b: OracleId,
+) -> Result<(), anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([a, b])?;
+	let channel = builder.add_channel();
+	builder.send(channel, 1 << log_rows, [a])?;
+	builder.receive(channel, 1 << log_rows, [b])?;
+	builder.pop_namespace();
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_core::constraint_system::validate::validate_witness;
+	use binius_field::{BinaryField32b, TowerField};
+
+	use super::*;
+	use crate::builder::test_utils::test_circuit;
+
+	const LOG_SIZE: usize = 6;
+
+	fn permutation_circuit(
+		builder: &mut ConstraintSystemBuilder,
+		tamper: bool,
+	) -> Result<(), anyhow::Error> {
+		let a = builder.add_committed("a", LOG_SIZE, BinaryField32b::TOWER_LEVEL);
+		let b = builder.add_committed("b", LOG_SIZE, BinaryField32b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let mut a_col = witness.new_column::<BinaryField32b>(a);
+			let mut b_col = witness.new_column::<BinaryField32b>(b);
+			let a_values = a_col.as_mut_slice::<u32>();
+			let b_values = b_col.as_mut_slice::<u32>();
+			for (i, (a, b)) in a_values
+				.iter_mut()
+				.zip(b_values.iter_mut().rev())
+				.enumerate()
+			{
+				// Repeated values make both columns genuine multisets.
+				*a = (i as u32 % 7) * 0x01010101;
+				*b = *a;
+			}
+			if tamper {
+				b_values[3] ^= 1;
+			}
+		}
+		assert_permutation(builder, "permutation", a, b)
+	}
+
+	#[test]
+	fn test_assert_permutation() {
+		test_circuit(|builder| {
+			permutation_circuit(builder, false)?;
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_assert_permutation_rejects_differing_element() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		permutation_circuit(&mut builder, true).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		assert!(validate_witness(&constraint_system, &[], &witness).is_err());
+	}
+}
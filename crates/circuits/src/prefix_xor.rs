@@ -0,0 +1,107 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_field::{as_packed_field::PackScalar, ExtensionField, TowerField};
+use binius_macros::arith_expr;
+use bytemuck::Pod;
+use itertools::izip;
+
+use crate::builder::{
+	types::{F, U},
+	ConstraintSystemBuilder,
+};
+
+/// Computes the running XOR of a column, `out[i] = in[0] ^ ... ^ in[i]`.
+///
+/// XOR is addition in the binary tower, so this is a prefix sum. The output is committed and
+/// constrained against its own shift by one row, `out[i] = out[i - 1] + in[i]`. The shifted
+/// column is zero in the first row, which pins `out[0] = in[0]` with the same constraint.
+pub fn prefix_xor<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	input: OracleId,
+) -> Result<OracleId, anyhow::Error>
+where
+	U: PackScalar<FS> + Pod,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField + Pod,
+{
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([input])?;
+	let out = builder.add_committed("out", log_rows, FS::TOWER_LEVEL);
+	let prev = builder.add_shifted("prev", out, 1, log_rows, ShiftVariant::LogicalLeft)?;
+
+	if let Some(witness) = builder.witness() {
+		let input = &witness.get::<FS>(input)?.as_slice::<FS>()[..1 << log_rows];
+
+		let mut out_witness = witness.new_column::<FS>(out);
+		let mut prev_witness = witness.new_column::<FS>(prev);
+		let out = &mut out_witness.as_mut_slice::<FS>()[..1 << log_rows];
+		let prev = &mut prev_witness.as_mut_slice::<FS>()[..1 << log_rows];
+
+		let mut acc = FS::ZERO;
+		for (out, prev, &input) in izip!(out, prev, input) {
+			*prev = acc;
+			acc += input;
+			*out = acc;
+		}
+	}
+
+	builder.assert_zero(
+		"prefix_xor",
+		[input, prev, out],
+		arith_expr!([input, prev, out] = prev + input - out).convert_field(),
+	);
+
+	builder.pop_namespace();
+	Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField32b, BinaryField8b};
+
+	use crate::{builder::test_utils::test_circuit, unconstrained::unconstrained};
+
+	#[test]
+	fn test_prefix_xor_b8() {
+		test_circuit(|builder| {
+			let log_size = 7;
+			let input = unconstrained::<BinaryField8b>(builder, "input", log_size)?;
+			let out = super::prefix_xor::<BinaryField8b>(builder, "prefix_xor", input)?;
+
+			if let Some(witness) = builder.witness() {
+				let input = witness.get::<BinaryField8b>(input)?.as_slice::<u8>();
+				let out = witness.get::<BinaryField8b>(out)?.as_slice::<u8>();
+				let mut expected = 0;
+				for (&input, &out) in input.iter().zip(out).take(1 << log_size) {
+					expected ^= input;
+					assert_eq!(out, expected);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_prefix_xor_b32() {
+		test_circuit(|builder| {
+			let log_size = 5;
+			let input = unconstrained::<BinaryField32b>(builder, "input", log_size)?;
+			let out = super::prefix_xor::<BinaryField32b>(builder, "prefix_xor", input)?;
+
+			if let Some(witness) = builder.witness() {
+				let input = witness.get::<BinaryField32b>(input)?.as_slice::<u32>();
+				let out = witness.get::<BinaryField32b>(out)?.as_slice::<u32>();
+				let mut expected = 0;
+				for (&input, &out) in input.iter().zip(out).take(1 << log_size) {
+					expected ^= input;
+					assert_eq!(out, expected);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
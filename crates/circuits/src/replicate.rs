@@ -0,0 +1,93 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::{OracleId, ShiftVariant};
+use binius_macros::arith_expr;
+
+use crate::builder::{types::F, ConstraintSystemBuilder};
+
+/// Replicates every row of `source` `2^log_factor` times.
+///
+/// Row `i * 2^log_factor + j` of the returned oracle is row `i` of `source` for every
+/// `j < 2^log_factor`, so the result has `log_factor` more variables than `source`. Only the
+/// replicated oracle is committed. It is constrained to equal its rotation within each block of
+/// `2^log_factor` rows, so all copies agree, and its first copy is constrained to equal `source`.
+/// With `log_factor == 0` the source itself is returned.
+pub fn replicate(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	source: OracleId,
+	log_factor: usize,
+) -> Result<OracleId, anyhow::Error> {
+	if log_factor == 0 {
+		return Ok(source);
+	}
+
+	let log_rows = builder.log_rows([source])?;
+	let tower_level = builder.tower_level(source);
+
+	builder.push_namespace(name);
+	let (replicated, copies) =
+		builder.add_interleaved("replicated", log_rows, log_factor, tower_level)?;
+	let rotated =
+		builder.add_shifted("rotated", replicated, 1, log_factor, ShiftVariant::CircularLeft)?;
+
+	if let Some(witness) = builder.witness() {
+		witness.interleave_rows(replicated, &vec![source; copies.len()])?;
+		witness.alias_rows(rotated, replicated, 0)?;
+		for &copy in &copies {
+			witness.alias_rows(copy, source, 0)?;
+		}
+	}
+
+	builder.assert_zero("copies", [replicated, rotated], arith_expr!(F[x, y] = x - y));
+	builder.assert_zero("source", [copies[0], source], arith_expr!(F[x, y] = x - y));
+	builder.pop_namespace();
+
+	Ok(replicated)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField8b;
+
+	use super::*;
+	use crate::{
+		builder::{test_utils::test_circuit, witness::read_column},
+		unconstrained::unconstrained,
+	};
+
+	#[test]
+	fn test_replicate() {
+		test_circuit(|builder| {
+			let log_size = 5;
+			let log_factor = 2;
+			let source = unconstrained::<BinaryField8b>(builder, "source", log_size)?;
+			let replicated = replicate(builder, "replicated", source, log_factor)?;
+			assert_eq!(builder.log_rows([replicated])?, log_size + log_factor);
+
+			if let (Some(source_values), Some(replicated_values)) = (
+				read_column::<BinaryField8b>(builder, source),
+				read_column::<BinaryField8b>(builder, replicated),
+			) {
+				let expected = source_values
+					.iter()
+					.flat_map(|&value| std::iter::repeat(value).take(1 << log_factor))
+					.collect::<Vec<_>>();
+				assert_eq!(replicated_values, expected);
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn test_replicate_by_one() {
+		test_circuit(|builder| {
+			let source = unconstrained::<BinaryField8b>(builder, "source", 5)?;
+			let replicated = replicate(builder, "replicated", source, 0)?;
+			assert_eq!(builder.log_rows([replicated])?, 5);
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
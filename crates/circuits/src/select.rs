@@ -0,0 +1,87 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::OracleId;
+use binius_field::{BinaryField1b, BinaryField32b, TowerField};
+use binius_macros::arith_expr;
+use itertools::izip;
+
+use crate::builder::ConstraintSystemBuilder;
+
+type B1 = BinaryField1b;
+type B32 = BinaryField32b;
+
+/// Selects between two `B32` columns row by row, returning `out = if sel { a } else { b }`.
+///
+/// The selector is a `B1` column, so its values are restricted to 0 and 1 by the tower level of
+/// the oracle and no separate boolean check is required.
+pub fn select(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	sel: OracleId,
+	a: OracleId,
+	b: OracleId,
+) -> Result<OracleId, anyhow::Error> {
+	builder.push_namespace(name);
+	let log_rows = builder.log_rows([sel, a, b])?;
+	let out = builder.add_committed("out", log_rows, B32::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let sel_u8_packed = witness.get::<B1>(sel)?.as_slice::<u8>();
+		let a_u32 = witness.get::<B32>(a)?.as_slice::<u32>();
+		let b_u32 = witness.get::<B32>(b)?.as_slice::<u32>();
+
+		let mut out_witness = witness.new_column::<B32>(out);
+		let out_u32 = out_witness.as_mut_slice::<u32>();
+
+		for (row_idx, (out, &a, &b)) in izip!(out_u32, a_u32, b_u32).enumerate() {
+			let sel_bit = (sel_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1;
+			*out = if sel_bit == 1 { a } else { b };
+		}
+	}
+
+	builder.assert_zero(
+		"select",
+		[sel, a, b, out],
+		arith_expr!([sel, a, b, out] = sel * (a - b) + b - out).convert_field(),
+	);
+
+	builder.pop_namespace();
+	Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField1b, BinaryField32b, TowerField};
+	use itertools::izip;
+
+	use crate::{builder::test_utils::test_circuit, unconstrained::unconstrained};
+
+	#[test]
+	fn test_select() {
+		test_circuit(|builder| {
+			let log_size = 6;
+			let sel = builder.add_committed("sel", log_size, BinaryField1b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				witness
+					.new_column::<BinaryField1b>(sel)
+					.as_mut_slice::<u8>()
+					.fill(0b01010101);
+			}
+			let a = unconstrained::<BinaryField32b>(builder, "a", log_size)?;
+			let b = unconstrained::<BinaryField32b>(builder, "b", log_size)?;
+			let out = super::select(builder, "select", sel, a, b)?;
+
+			if let Some(witness) = builder.witness() {
+				let a = witness.get::<BinaryField32b>(a)?.as_slice::<u32>();
+				let b = witness.get::<BinaryField32b>(b)?.as_slice::<u32>();
+				let out = witness.get::<BinaryField32b>(out)?.as_slice::<u32>();
+				for (row_idx, (&a, &b, &out)) in izip!(a, b, out).enumerate() {
+					let expected = if row_idx % 2 == 0 { a } else { b };
+					assert_eq!(out, expected);
+				}
+			}
+			Ok(vec![])
+		})
+		.unwrap();
+	}
+}
@@ -1,10 +1,12 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use anyhow::ensure;
 use binius_core::{oracle::OracleId, transparent};
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
 	BinaryField1b, ExtensionField, PackedField, TowerField,
 };
+use binius_macros::arith_expr;
 
 use crate::builder::{
 	types::{F, U},
@@ -91,3 +93,42 @@ where
 
 	Ok(oracle)
 }
+
+/// Constrains every row of `oracle` to equal the corresponding value of `expected`.
+///
+/// The expected values are added as a transparent oracle with [`make_transparent`], so they are
+/// fixed by the constraint system rather than supplied by the witness. This pins down committed
+/// columns such as fixed lookup tables. Note that
+/// [`validate_witness`](binius_core::constraint_system::validate::validate_witness) evaluates the
+/// transparent oracle separately at every row, which is quadratic in the number of rows.
+pub fn assert_equals_constants<FS>(
+	builder: &mut ConstraintSystemBuilder,
+	name: impl ToString,
+	oracle: OracleId,
+	expected: &[FS],
+) -> Result<(), anyhow::Error>
+where
+	U: PackScalar<FS>,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	let log_rows = builder.log_rows([oracle])?;
+	ensure!(
+		expected.len() == 1 << log_rows,
+		"expected {} constants, got {}",
+		1 << log_rows,
+		expected.len()
+	);
+
+	builder.push_namespace(name);
+	let constants = make_transparent(builder, "constants", expected);
+	if let Ok(constants) = constants {
+		builder.assert_zero(
+			"equals_constants",
+			[oracle, constants],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+	}
+	builder.pop_namespace();
+	constants.map(|_| ())
+}
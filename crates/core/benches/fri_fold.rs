@@ -0,0 +1,47 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::{protocols::fri::fold_full_round, reed_solomon::reed_solomon::ReedSolomonCode};
+use binius_field::{BinaryField128b, BinaryField32b, Field};
+use binius_ntt::NTTOptions;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rand::{thread_rng, Rng};
+
+fn generate_scalar<F: Field>(mut rng: impl Rng, n: usize) -> Vec<F> {
+	std::iter::repeat_with(|| F::random(&mut rng))
+		.take(n)
+		.collect()
+}
+
+fn bench_fold_rounds(c: &mut Criterion) {
+	let mut group = c.benchmark_group("fri_fold");
+	let mut rng = thread_rng();
+	let log_inv_rate = 1;
+	for log_len in [16, 20] {
+		let rs_code = ReedSolomonCode::<BinaryField32b>::new(
+			log_len - log_inv_rate,
+			log_inv_rate,
+			&NTTOptions::default(),
+		)
+		.unwrap();
+		let codeword = generate_scalar::<BinaryField128b>(&mut rng, 1 << log_len);
+		let challenges = generate_scalar::<BinaryField128b>(&mut rng, rs_code.log_dim());
+
+		group.throughput(Throughput::Elements(1 << log_len));
+		for arity in [1, 2] {
+			group.bench_function(format!("128b/log_len={log_len}/arity={arity}"), |bench| {
+				bench.iter(|| {
+					challenges.chunks_exact(arity).enumerate().fold(
+						codeword.clone(),
+						|codeword, (i, challenges)| {
+							fold_full_round(&rs_code, i * arity, arity, &codeword, challenges)
+						},
+					)
+				});
+			});
+		}
+	}
+	group.finish()
+}
+
+criterion_group!(fri_fold, bench_fold_rounds);
+criterion_main!(fri_fold);
@@ -43,6 +43,9 @@ pub enum Error {
 	#[error("Non-zero oracles contain zeros")]
 	Zeros,
 
+	#[error("expected {expected} supplied zerocheck challenges, got {got}")]
+	IncorrectZerocheckChallengesLength { expected: usize, got: usize },
+
 	#[error("False eq evaluation claim")]
 	FalseEqEvaluationClaim,
 
@@ -105,4 +108,6 @@ pub enum VerificationError {
 		"Channel with id={id} is not balanced. Pushes and pulls do not contain the same elements"
 	)]
 	ChannelUnbalanced { id: ChannelId },
+	#[error("the supplied zerocheck challenges do not match the transcript")]
+	IncorrectZerocheckChallenges,
 }
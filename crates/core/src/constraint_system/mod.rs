@@ -7,14 +7,19 @@ mod prove;
 pub mod validate;
 mod verify;
 
-use binius_field::{BinaryField128b, TowerField};
-use binius_macros::SerializeBytes;
+use binius_field::{BinaryField128b, BinaryField32b, ExtensionField, TowerField};
+use binius_macros::{DeserializeBytes, SerializeBytes};
 use binius_utils::{DeserializeBytes, SerializationError, SerializationMode};
-use channel::{ChannelId, Flush};
-pub use prove::prove;
-pub use verify::verify;
+use channel::{Boundary, ChannelId, Flush, FlushDirection};
+use error::{Error, VerificationError};
+pub use prove::{prove, prove_with_transcript, prove_with_zerocheck_challenges};
+pub use verify::{verify, verify_with_transcript, verify_with_zerocheck_challenges};
 
-use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
+use crate::{
+	fiat_shamir::Challenger,
+	oracle::{ConstraintSet, MultilinearOracleSet, OracleId},
+	transcript::{ProverTranscript, VerifierTranscript},
+};
 
 /// Contains the 3 things that place constraints on witness data in Binius
 /// - virtual oracles
@@ -30,6 +35,7 @@ pub struct ConstraintSystem<F: TowerField> {
 	pub non_zero_oracle_ids: Vec<OracleId>,
 	pub flushes: Vec<Flush>,
 	pub max_channel_id: ChannelId,
+	pub public_inputs: Vec<PublicInput>,
 }
 
 impl DeserializeBytes for ConstraintSystem<BinaryField128b> {
@@ -46,6 +52,7 @@ impl DeserializeBytes for ConstraintSystem<BinaryField128b> {
 			non_zero_oracle_ids: DeserializeBytes::deserialize(&mut read_buf, mode)?,
 			flushes: DeserializeBytes::deserialize(&mut read_buf, mode)?,
 			max_channel_id: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			public_inputs: DeserializeBytes::deserialize(&mut read_buf, mode)?,
 		})
 	}
 }
@@ -56,6 +63,100 @@ impl<F: TowerField> ConstraintSystem<F> {
 	}
 }
 
+/// An oracle whose values are part of the public statement.
+///
+/// Every row of the oracle is pulled from `channel_id` together with its row index, so the channel
+/// only balances if the verifier pushes the same `(index, value)` pairs as boundaries. These are
+/// built by [`Self::boundaries`] from the claimed public values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+pub struct PublicInput {
+	pub oracle_id: OracleId,
+	pub channel_id: ChannelId,
+}
+
+impl PublicInput {
+	/// Returns the boundaries asserting that the public input column equals `values`.
+	///
+	/// Row indices are encoded as `BinaryField32b` elements, so `values` may have at most
+	/// `2^32` entries.
+	pub fn boundaries<F>(&self, values: impl IntoIterator<Item = F>) -> Vec<Boundary<F>>
+	where
+		F: TowerField + ExtensionField<BinaryField32b>,
+	{
+		values
+			.into_iter()
+			.enumerate()
+			.map(|(index, value)| Boundary {
+				values: vec![BinaryField32b::new(index as u32).into(), value],
+				channel_id: self.channel_id,
+				direction: FlushDirection::Push,
+				multiplicity: 1,
+			})
+			.collect()
+	}
+}
+
+/// The source of the zerocheck challenges of a constraint system proof.
+///
+/// The challenges are normally sampled from the transcript. Folding and aggregation schemes may
+/// instead need the zerocheck to run at a point they fix themselves, in which case the point is
+/// supplied by the caller. A supplied point is written to the transcript before the witness is
+/// committed, and the verifier rejects a proof whose point differs from the one it was given. The
+/// transcript still draws the sample later on, so that prover and verifier stay in lockstep with
+/// the standard protocol, but the supplied values replace it.
+///
+/// The zerocheck is only sound if the supplied point is independent of the witness, for example
+/// because it was fixed by an outer protocol before the witness existed. Points that are read from
+/// the committed witness itself are not supported, since nothing opens them against the
+/// commitment.
+#[derive(Debug, Clone, Default)]
+pub enum ZerocheckChallenges<F> {
+	#[default]
+	Sampled,
+	Supplied(Vec<F>),
+}
+
+impl<F: TowerField> ZerocheckChallenges<F> {
+	/// Writes a supplied point to the transcript.
+	fn write<Challenger_: Challenger>(&self, transcript: &mut ProverTranscript<Challenger_>) {
+		if let Self::Supplied(supplied) = self {
+			transcript.message().write_scalar_slice(supplied);
+		}
+	}
+
+	/// Reads a supplied point from the transcript and checks it against the expected one.
+	fn read<Challenger_: Challenger>(
+		&self,
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(), Error> {
+		if let Self::Supplied(supplied) = self {
+			let observed = transcript
+				.message()
+				.read_scalar_slice::<F>(supplied.len())?;
+			if observed != *supplied {
+				return Err(VerificationError::IncorrectZerocheckChallenges.into());
+			}
+		}
+		Ok(())
+	}
+
+	/// Returns the challenges to use in place of the `sampled` ones.
+	fn resolve(self, sampled: Vec<F>) -> Result<Vec<F>, Error> {
+		match self {
+			Self::Sampled => Ok(sampled),
+			Self::Supplied(supplied) => {
+				if supplied.len() != sampled.len() {
+					return Err(Error::IncorrectZerocheckChallengesLength {
+						expected: sampled.len(),
+						got: supplied.len(),
+					});
+				}
+				Ok(supplied)
+			}
+		}
+	}
+}
+
 /// Constraint system proof that has been serialized into bytes
 #[derive(Debug, Clone)]
 pub struct Proof {
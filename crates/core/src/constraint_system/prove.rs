@@ -29,7 +29,7 @@ use super::{
 		get_post_flush_sumcheck_eval_claims_without_eq, make_flush_oracles,
 		max_n_vars_and_skip_rounds, reorder_for_flushing_by_n_vars,
 	},
-	ConstraintSystem, Proof,
+	ConstraintSystem, Proof, ZerocheckChallenges,
 };
 use crate::{
 	constraint_system::{
@@ -60,16 +60,146 @@ use crate::{
 };
 
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
-#[instrument("constraint_system::prove", skip_all, level = "debug")]
 pub fn prove<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
 	log_inv_rate: usize,
 	security_bits: usize,
 	boundaries: &[Boundary<FExt<Tower>>],
-	mut witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
 	domain_factory: DomainFactory,
 	backend: &Backend,
 ) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable,
+	PackedType<U, Tower::B16>: PackedFieldIndexable,
+	PackedType<U, Tower::B32>: PackedFieldIndexable,
+	PackedType<U, Tower::B64>: PackedFieldIndexable,
+{
+	let (proof, _) = prove_with_zerocheck_challenges::<
+		U,
+		Tower,
+		DomainFactory,
+		Hash,
+		Compress,
+		Challenger_,
+		Backend,
+	>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		witness,
+		ZerocheckChallenges::Sampled,
+		domain_factory,
+		backend,
+	)?;
+	Ok(proof)
+}
+
+/// Generates a proof like [`prove`], taking the zerocheck challenges from `zerocheck_challenges`.
+///
+/// Returns the proof together with the zerocheck challenges it was generated with. See
+/// [`ZerocheckChallenges`] for the semantics of supplied challenges.
+#[allow(clippy::too_many_arguments)]
+pub fn prove_with_zerocheck_challenges<
+	U,
+	Tower,
+	DomainFactory,
+	Hash,
+	Compress,
+	Challenger_,
+	Backend,
+>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	zerocheck_challenges: ZerocheckChallenges<FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+) -> Result<(Proof, Vec<FExt<Tower>>), Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable,
+	PackedType<U, Tower::B16>: PackedFieldIndexable,
+	PackedType<U, Tower::B32>: PackedFieldIndexable,
+	PackedType<U, Tower::B64>: PackedFieldIndexable,
+{
+	let mut transcript = ProverTranscript::<Challenger_>::new();
+	let zerocheck_challenges =
+		prove_with_transcript::<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+			constraint_system,
+			log_inv_rate,
+			security_bits,
+			boundaries,
+			witness,
+			zerocheck_challenges,
+			domain_factory,
+			backend,
+			&mut transcript,
+		)?;
+	let proof = Proof {
+		transcript: transcript.finalize(),
+	};
+	Ok((proof, zerocheck_challenges))
+}
+
+/// Generates a proof like [`prove_with_zerocheck_challenges`], writing it to `transcript`.
+///
+/// The transcript is not finalized, so the caller may choose how the proof is stored, for example
+/// by passing a [`StreamingProverTranscript`](crate::transcript::StreamingProverTranscript) to
+/// write it through to a file. Returns the zerocheck challenges the proof was generated with.
+#[instrument("constraint_system::prove", skip_all, level = "debug")]
+#[allow(clippy::too_many_arguments)]
+pub fn prove_with_transcript<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	mut witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	zerocheck_challenges: ZerocheckChallenges<FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<Vec<FExt<Tower>>, Error>
 where
 	U: ProverTowerUnderlier<Tower>,
 	Tower: ProverTowerFamily,
@@ -103,8 +233,8 @@ where
 
 	let fast_domain_factory = IsomorphicEvaluationDomainFactory::<FFastExt<Tower>>::default();
 
-	let mut transcript = ProverTranscript::<Challenger_>::new();
 	transcript.observe().write_slice(boundaries);
+	zerocheck_challenges.write(transcript);
 
 	let ConstraintSystem {
 		mut oracles,
@@ -112,6 +242,7 @@ where
 		mut flushes,
 		non_zero_oracle_ids,
 		max_channel_id,
+		..
 	} = constraint_system.clone();
 
 	// Stable sort constraint sets in descending order by number of variables.
@@ -215,7 +346,7 @@ where
 			all_gpa_witnesses,
 			&all_gpa_claims,
 			&fast_domain_factory,
-			&mut transcript,
+			transcript,
 			backend,
 		)?;
 
@@ -255,7 +386,7 @@ where
 		backend,
 	)?;
 
-	let flush_sumcheck_output = sumcheck::prove::batch_prove(provers, &mut transcript)?;
+	let flush_sumcheck_output = sumcheck::prove::batch_prove(provers, transcript)?;
 
 	let flush_eval_claims = get_post_flush_sumcheck_eval_claims_without_eq(
 		&oracles,
@@ -276,7 +407,8 @@ where
 	let (max_n_vars, skip_rounds) =
 		max_n_vars_and_skip_rounds(&zerocheck_claims, FDomain::<Tower>::N_BITS);
 
-	let zerocheck_challenges = transcript.sample_vec(max_n_vars - skip_rounds);
+	let zerocheck_challenges =
+		zerocheck_challenges.resolve(transcript.sample_vec(max_n_vars - skip_rounds))?;
 
 	let switchover_fn = standard_switchover_heuristic(-2);
 
@@ -337,7 +469,7 @@ where
 	let univariate_output = sumcheck::prove::batch_prove_zerocheck_univariate_round(
 		univariate_provers,
 		skip_rounds,
-		&mut transcript,
+		transcript,
 	)?;
 
 	let univariate_challenge = univariate_output.univariate_challenge;
@@ -345,7 +477,7 @@ where
 	let sumcheck_output = sumcheck::prove::batch_prove_with_start(
 		univariate_output.batch_prove_start,
 		tail_regular_zerocheck_provers,
-		&mut transcript,
+		transcript,
 	)?;
 
 	let zerocheck_output = zerocheck::verify_sumcheck_outputs(
@@ -388,7 +520,7 @@ where
 		reduction_provers.push(reduction_prover);
 	}
 
-	let univariatizing_output = sumcheck::prove::batch_prove(reduction_provers, &mut transcript)?;
+	let univariatizing_output = sumcheck::prove::batch_prove(reduction_provers, transcript)?;
 
 	let multilinear_zerocheck_output = sumcheck::univariate::verify_sumcheck_outputs(
 		&reduction_claims,
@@ -409,7 +541,7 @@ where
 			.into_iter()
 			.chain(zerocheck_eval_claims),
 		switchover_fn,
-		&mut transcript,
+		transcript,
 		&domain_factory,
 		backend,
 	)?;
@@ -428,7 +560,7 @@ where
 	} = ring_switch::prove::<_, _, _, Tower, _, _>(
 		&system,
 		&committed_multilins,
-		&mut transcript,
+		transcript,
 		backend,
 	)?;
 
@@ -443,13 +575,11 @@ where
 		&committed_multilins,
 		&transparent_multilins,
 		&piop_sumcheck_claims,
-		&mut transcript,
+		transcript,
 		&backend,
 	)?;
 
-	Ok(Proof {
-		transcript: transcript.finalize(),
-	})
+	Ok(zerocheck_challenges.clone())
 }
 
 type TypeErasedUnivariateZerocheck<'a, F> = Box<dyn UnivariateZerocheckProver<'a, F> + 'a>;
@@ -13,7 +13,7 @@ use tracing::instrument;
 use super::{
 	channel::Boundary,
 	error::{Error, VerificationError},
-	ConstraintSystem, Proof,
+	ConstraintSystem, Proof, ZerocheckChallenges,
 };
 use crate::{
 	composition::IndexComposition,
@@ -44,7 +44,6 @@ use crate::{
 };
 
 /// Verifies a proof against a constraint system.
-#[instrument("constraint_system::verify", skip_all, level = "debug")]
 pub fn verify<U, Tower, Hash, Compress, Challenger_>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
 	log_inv_rate: usize,
@@ -52,6 +51,71 @@ pub fn verify<U, Tower, Hash, Compress, Challenger_>(
 	boundaries: &[Boundary<FExt<Tower>>],
 	proof: Proof,
 ) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	verify_with_zerocheck_challenges::<U, Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		proof,
+		ZerocheckChallenges::Sampled,
+	)
+}
+
+/// Verifies a proof generated with
+/// [`prove_with_zerocheck_challenges`](super::prove_with_zerocheck_challenges).
+///
+/// `zerocheck_challenges` must be the same source the prover used. See [`ZerocheckChallenges`]
+/// for the semantics of supplied challenges.
+pub fn verify_with_zerocheck_challenges<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+	zerocheck_challenges: ZerocheckChallenges<FExt<Tower>>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	let Proof { transcript } = proof;
+	verify_with_transcript::<U, Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		zerocheck_challenges,
+		VerifierTranscript::new(transcript),
+	)
+}
+
+/// Verifies a proof read from `transcript`, as written by
+/// [`prove_with_transcript`](super::prove_with_transcript).
+///
+/// This allows reading the proof lazily, for example from a transcript created with
+/// [`VerifierTranscript::from_reader`]. The transcript is finalized, so the proof must be read
+/// out completely.
+#[instrument("constraint_system::verify", skip_all, level = "debug")]
+pub fn verify_with_transcript<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	zerocheck_challenges: ZerocheckChallenges<FExt<Tower>>,
+	mut transcript: VerifierTranscript<Challenger_>,
+) -> Result<(), Error>
 where
 	U: TowerUnderlier<Tower>,
 	Tower: TowerFamily,
@@ -72,10 +136,8 @@ where
 	// Stable sort constraint sets in descending order by number of variables.
 	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
 
-	let Proof { transcript } = proof;
-
-	let mut transcript = VerifierTranscript::<Challenger_>::new(transcript);
 	transcript.observe().write_slice(boundaries);
+	zerocheck_challenges.read(&mut transcript)?;
 
 	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
 	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
@@ -209,7 +271,8 @@ where
 	let (max_n_vars, skip_rounds) =
 		max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
 
-	let zerocheck_challenges = transcript.sample_vec(max_n_vars - skip_rounds);
+	let zerocheck_challenges =
+		zerocheck_challenges.resolve(transcript.sample_vec(max_n_vars - skip_rounds))?;
 
 	let univariate_cnt = zerocheck_claims
 		.partition_point(|zerocheck_claim| zerocheck_claim.n_vars() > max_n_vars - skip_rounds);
@@ -2,6 +2,7 @@
 
 use std::{cmp::min, mem};
 
+use binius_utils::{DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
 use bytes::{buf::UninitSlice, Buf, BufMut};
 use digest::{
 	core_api::{Block, BlockSizeUser},
@@ -16,6 +17,7 @@ pub struct HasherSampler<H: Digest> {
 	index: usize,
 	buffer: Output<H>,
 	hasher: H,
+	absorbed: Option<Vec<u8>>,
 }
 
 /// Challenger type which implements `[BufMut]` that has similar functionality as `[CanObserve]`
@@ -24,11 +26,18 @@ pub struct HasherObserver<H: Digest + BlockSizeUser> {
 	index: usize,
 	buffer: Block<H>,
 	hasher: H,
+	absorbed: Option<Vec<u8>>,
 }
 
 /// Challenger interface over hashes that implement `[Digest]` trait,
 ///
-/// This challenger works over bytes instead of Field elements
+/// This challenger works over bytes instead of Field elements.
+///
+/// A challenger created with [`Self::with_state_tracking`] keeps a copy of the bytes absorbed by
+/// the hasher since its last reset, which happens every time a new block of randomness is
+/// squeezed. Together with the buffered bytes, they determine the challenger state, which can be
+/// saved with [`Self::serialize_state`] and resumed with [`Self::restore_state`]. The default
+/// challenger does not keep this copy.
 #[derive(Debug)]
 pub enum HasherChallenger<H: Digest + BlockSizeUser> {
 	Observer(HasherObserver<H>),
@@ -44,6 +53,7 @@ where
 			hasher: H::new(),
 			index: 0,
 			buffer: Block::<H>::default(),
+			absorbed: None,
 		})
 	}
 }
@@ -76,6 +86,119 @@ impl<H: Digest + BlockSizeUser + FixedOutputReset + Default> Challenger for Hash
 			}
 		}
 	}
+
+	/// Samples are squeezed into a digest-sized buffer, so the only allocation made while sampling
+	/// is the record of absorbed bytes of a challenger that tracks its state, which holds one
+	/// fed-forward digest and the sample count absorbed when switching back to observing.
+	fn reserve_output(&mut self, n_bytes: usize) {
+		if n_bytes == 0 {
+			return;
+		}
+		let absorbed = match self {
+			Self::Observer(observer) => &mut observer.absorbed,
+			Self::Sampler(sampler) => &mut sampler.absorbed,
+		};
+		let Some(absorbed) = absorbed else {
+			return;
+		};
+		let capacity = <H as Digest>::output_size() + size_of::<usize>();
+		absorbed.reserve(capacity.saturating_sub(absorbed.len()));
+	}
+}
+
+impl<H> HasherChallenger<H>
+where
+	H: Digest + BlockSizeUser + Default,
+{
+	/// Creates a challenger that keeps a copy of the bytes absorbed since the last hasher reset, so
+	/// that its state can be saved with [`Self::serialize_state`].
+	///
+	/// The copy grows with the observed data between two samples, so state tracking is opt-in.
+	pub fn with_state_tracking() -> Self {
+		Self::Observer(HasherObserver {
+			hasher: H::new(),
+			index: 0,
+			buffer: Block::<H>::default(),
+			absorbed: Some(Vec::new()),
+		})
+	}
+
+	/// Serializes the challenger state, so that it can later be resumed with
+	/// [`Self::restore_state`].
+	///
+	/// Returns `None` if the challenger was not created with [`Self::with_state_tracking`] or
+	/// restored from a state.
+	pub fn serialize_state(&self) -> Option<Vec<u8>> {
+		let (variant, absorbed, index, buffer) = match self {
+			Self::Observer(observer) => {
+				(0u8, &observer.absorbed, observer.index, observer.buffer.as_slice())
+			}
+			Self::Sampler(sampler) => {
+				(1u8, &sampler.absorbed, sampler.index, sampler.buffer.as_slice())
+			}
+		};
+
+		let absorbed = absorbed.as_ref()?;
+
+		let mode = SerializationMode::Native;
+		let mut bytes = Vec::new();
+		variant
+			.serialize(&mut bytes, mode)
+			.and_then(|_| absorbed.serialize(&mut bytes, mode))
+			.and_then(|_| index.serialize(&mut bytes, mode))
+			.expect("Vec<u8> write buffer is unbounded");
+		bytes.put_slice(buffer);
+		Some(bytes)
+	}
+
+	/// Restores a challenger from a state produced by [`Self::serialize_state`].
+	///
+	/// The restored challenger produces the same samples as the original one would have, given the
+	/// same subsequent observations, and keeps tracking its state.
+	pub fn restore_state(mut bytes: &[u8]) -> Result<Self, SerializationError> {
+		let mode = SerializationMode::Native;
+		let variant = u8::deserialize(&mut bytes, mode)?;
+		let absorbed = Vec::<u8>::deserialize(&mut bytes, mode)?;
+		let index = usize::deserialize(&mut bytes, mode)?;
+
+		let mut hasher = H::default();
+		Digest::update(&mut hasher, &absorbed);
+
+		match variant {
+			0 => {
+				let buffer = Block::<H>::deserialize(&mut bytes, mode)?;
+				if index >= buffer.len() {
+					return Err(SerializationError::InvalidConstruction {
+						name: "HasherObserver::index",
+					});
+				}
+				Ok(Self::Observer(HasherObserver {
+					index,
+					buffer,
+					hasher,
+					absorbed: Some(absorbed),
+				}))
+			}
+			1 => {
+				let buffer = Output::<H>::deserialize(&mut bytes, mode)?;
+				if index > buffer.len() {
+					return Err(SerializationError::InvalidConstruction {
+						name: "HasherSampler::index",
+					});
+				}
+				Ok(Self::Sampler(HasherSampler {
+					index,
+					buffer,
+					hasher,
+					absorbed: Some(absorbed),
+				}))
+			}
+			index => Err(SerializationError::UnknownEnumVariant {
+				name: "HasherChallenger",
+				index,
+			}),
+		}
+	}
 }
 
 impl<H> HasherSampler<H>
@@ -83,12 +206,13 @@ where
 	H: Digest + Default + BlockSizeUser,
 {
 	fn into_observer(mut self) -> HasherObserver<H> {
-		Digest::update(&mut self.hasher, self.index.to_le_bytes());
+		absorb(&mut self.hasher, &mut self.absorbed, &self.index.to_le_bytes());
 
 		HasherObserver {
 			hasher: self.hasher,
 			index: 0,
 			buffer: Block::<H>::default(),
+			absorbed: self.absorbed,
 		}
 	}
 }
@@ -99,9 +223,12 @@ where
 {
 	fn fill_buffer(&mut self) {
 		let digest = self.hasher.finalize_reset();
+		if let Some(absorbed) = &mut self.absorbed {
+			absorbed.clear();
+		}
 
 		// feed forward to the empty state
-		Digest::update(&mut self.hasher, &digest);
+		absorb(&mut self.hasher, &mut self.absorbed, &digest);
 
 		self.buffer = digest;
 		self.index = 0
@@ -118,6 +245,7 @@ where
 			hasher: self.hasher,
 			index: <H as Digest>::output_size(),
 			buffer: Output::<H>::default(),
+			absorbed: self.absorbed,
 		}
 	}
 }
@@ -127,7 +255,7 @@ where
 	H: Digest + BlockSizeUser,
 {
 	fn flush(&mut self) {
-		self.hasher.update(&self.buffer[..self.index]);
+		absorb(&mut self.hasher, &mut self.absorbed, &self.buffer[..self.index]);
 		self.index = 0
 	}
 }
@@ -187,6 +315,15 @@ where
 	}
 }
 
+/// Updates the hasher with `data`, keeping a copy of the bytes absorbed since its last reset if
+/// the challenger tracks its state.
+fn absorb<H: Digest>(hasher: &mut H, absorbed: &mut Option<Vec<u8>>, data: &[u8]) {
+	Digest::update(hasher, data);
+	if let Some(absorbed) = absorbed {
+		absorbed.extend_from_slice(data);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use groestl_crypto::Groestl256;
@@ -267,4 +404,90 @@ mod tests {
 		let final_hasher_out = hasher.finalize_reset();
 		assert_eq!(final_hasher_out[..7], out_again);
 	}
+
+	#[test]
+	fn test_restore_state_resumes_challenges() {
+		let mut observable = [0u8; 300];
+		thread_rng().fill_bytes(&mut observable);
+
+		// Runs the same sequence of operations and returns all sampled bytes, taking a snapshot
+		// and resuming from it after `checkpoint` operations.
+		let run = |checkpoint: Option<usize>| {
+			let mut challenger = HasherChallenger::<Groestl256>::with_state_tracking();
+			let mut samples = Vec::new();
+			for step in 0..8 {
+				if checkpoint == Some(step) {
+					let state = challenger.serialize_state().unwrap();
+					challenger = HasherChallenger::restore_state(&state).unwrap();
+				}
+				if step % 3 == 2 {
+					challenger
+						.observer()
+						.put_slice(&observable[step * 30..step * 30 + 70]);
+				} else {
+					let mut out = vec![0u8; 5 + step * 7];
+					challenger.sampler().copy_to_slice(&mut out);
+					samples.extend(out);
+				}
+			}
+			samples
+		};
+
+		let uninterrupted = run(None);
+		for checkpoint in 0..8 {
+			assert_eq!(run(Some(checkpoint)), uninterrupted);
+		}
+	}
+
+	#[test]
+	fn test_reserve_output_does_not_change_samples() {
+		let mut observable = [0u8; 100];
+		thread_rng().fill_bytes(&mut observable);
+
+		let run = |reserve: bool| {
+			let mut challenger = HasherChallenger::<Groestl256>::default();
+			challenger.observer().put_slice(&observable);
+			if reserve {
+				challenger.reserve_output(200);
+			}
+			let mut out = vec![0u8; 200];
+			challenger.sampler().copy_to_slice(&mut out);
+			challenger.observer().put_slice(&observable[..10]);
+			if reserve {
+				challenger.reserve_output(40);
+			}
+			let mut out_after_observe = vec![0u8; 40];
+			challenger.sampler().copy_to_slice(&mut out_after_observe);
+			out.extend(out_after_observe);
+			out
+		};
+
+		assert_eq!(run(true), run(false));
+	}
+
+	#[test]
+	fn test_restore_state_rejects_invalid_variant() {
+		let mut state = HasherChallenger::<Groestl256>::with_state_tracking()
+			.serialize_state()
+			.unwrap();
+		state[0] = 2;
+		assert!(HasherChallenger::<Groestl256>::restore_state(&state).is_err());
+	}
+
+	#[test]
+	fn test_default_challenger_does_not_track_state() {
+		let mut challenger = HasherChallenger::<Groestl256>::default();
+		challenger.observer().put_slice(&[0u8; 1000]);
+		assert!(challenger.serialize_state().is_none());
+
+		// State tracking does not change the samples.
+		let mut tracking = HasherChallenger::<Groestl256>::with_state_tracking();
+		tracking.observer().put_slice(&[0u8; 1000]);
+		let mut out = [0u8; 64];
+		let mut tracking_out = [0u8; 64];
+		challenger.sampler().copy_to_slice(&mut out);
+		tracking.sampler().copy_to_slice(&mut tracking_out);
+		assert_eq!(out, tracking_out);
+		assert!(tracking.serialize_state().is_some());
+	}
 }
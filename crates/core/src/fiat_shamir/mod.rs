@@ -14,4 +14,10 @@ pub trait Challenger {
 
 	/// Returns and infinite buffer for writing data that the challenger observes.
 	fn observer(&mut self) -> &mut impl BufMut;
+
+	/// Hints that about `n_bytes` of randomness are about to be sampled.
+	///
+	/// Implementations may use this to preallocate their buffers. It never changes the sampled
+	/// bytes, and the default implementation does nothing.
+	fn reserve_output(&mut self, _n_bytes: usize) {}
 }
@@ -9,6 +9,8 @@
 
 use std::array;
 
+use binius_field::PackedField;
+
 #[auto_impl::auto_impl(&mut)]
 pub trait CanSample<T> {
 	fn sample(&mut self) -> T;
@@ -20,9 +22,21 @@ pub trait CanSample<T> {
 	fn sample_vec(&mut self, n: usize) -> Vec<T> {
 		(0..n).map(|_| self.sample()).collect()
 	}
+
+	/// Samples a packed field element, squeezing one scalar per lane in lane order.
+	fn sample_packed<P>(&mut self) -> P
+	where
+		P: PackedField<Scalar = T>,
+	{
+		P::from_scalars((0..P::WIDTH).map(|_| self.sample()))
+	}
 }
 
 #[auto_impl::auto_impl(&mut)]
 pub trait CanSampleBits<T> {
+	/// Samples a uniformly random value in `[0, 2^bits)`.
+	///
+	/// The transcripts squeeze `bits.div_ceil(8)` bytes from the challenger and mask the result
+	/// to `bits` bits, so sampling indices into power-of-two ranges has no modulo bias.
 	fn sample_bits(&mut self, bits: usize) -> T;
 }
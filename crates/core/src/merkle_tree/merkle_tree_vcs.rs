@@ -25,6 +25,9 @@ pub trait MerkleTreeScheme<T>: Sync {
 	/// Returns the optimal layer that the verifier should verify only once.
 	fn optimal_verify_layer(&self, n_queries: usize, tree_depth: usize) -> usize;
 
+	/// Returns the number of bytes of a digest as serialized in a proof.
+	fn digest_size(&self) -> usize;
+
 	/// Returns the total byte-size of a proof for multiple opening queries.
 	///
 	/// ## Arguments
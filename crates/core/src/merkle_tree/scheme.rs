@@ -50,6 +50,10 @@ where
 		log2_ceil_usize(n_queries).min(tree_depth)
 	}
 
+	fn digest_size(&self) -> usize {
+		<H as Digest>::output_size()
+	}
+
 	fn proof_size(&self, len: usize, n_queries: usize, layer_depth: usize) -> Result<usize, Error> {
 		if !len.is_power_of_two() {
 			bail!(Error::PowerOfTwoLengthRequired)
@@ -61,8 +65,7 @@ where
 			bail!(Error::IncorrectLayerDepth)
 		}
 
-		Ok(((log_len - layer_depth - 1) * n_queries + (1 << layer_depth))
-			* <H as Digest>::output_size())
+		Ok(((log_len - layer_depth - 1) * n_queries + (1 << layer_depth)) * self.digest_size())
 	}
 
 	fn verify_vector(
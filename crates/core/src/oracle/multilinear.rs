@@ -412,6 +412,39 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().zero_padded(id, n_vars)
 	}
 
+	/// Replaces the committed oracle `id` with an alias of the committed oracle `target`.
+	///
+	/// The alias is a linear combination with the single term `target`. It keeps the id, name and
+	/// tower level of the replaced oracle, so that derived oracles and constraints referring to
+	/// `id` remain valid while `id` is no longer committed. `target` must precede `id` and have
+	/// the same number of variables and tower level.
+	pub fn alias_committed(&mut self, id: OracleId, target: OracleId) -> Result<(), Error> {
+		if id >= self.oracles.len() {
+			return Err(Error::InvalidOracleId(id));
+		}
+		// Checked before indexing, as `target < id` also bounds `target` by the number of oracles.
+		if target >= id {
+			return Err(Error::InvalidCommittedAlias { id, target });
+		}
+		let is_committed = |oracle: &MultilinearPolyOracle<F>| {
+			matches!(oracle.variant, MultilinearPolyVariant::Committed)
+		};
+		let (oracle, target_oracle) = (&self.oracles[id], &self.oracles[target]);
+		if !is_committed(oracle)
+			|| !is_committed(target_oracle)
+			|| oracle.n_vars != target_oracle.n_vars
+			|| oracle.tower_level != target_oracle.tower_level
+		{
+			return Err(Error::InvalidCommittedAlias { id, target });
+		}
+
+		let n_vars = oracle.n_vars;
+		let linear_combination =
+			LinearCombination::new(n_vars, F::ZERO, [(target_oracle.clone(), F::ONE)])?;
+		self.oracles[id].variant = MultilinearPolyVariant::LinearCombination(linear_combination);
+		Ok(())
+	}
+
 	pub fn oracle(&self, id: OracleId) -> MultilinearPolyOracle<F> {
 		self.oracles[id].clone()
 	}
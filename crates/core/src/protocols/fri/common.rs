@@ -1,11 +1,13 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::marker::PhantomData;
+use std::{iter, marker::PhantomData, ops::Range};
 
-use binius_field::{util::inner_product_unchecked, BinaryField, ExtensionField, PackedField};
+use binius_field::{
+	util::inner_product_unchecked, BinaryField, ExtensionField, PackedField, TowerField,
+};
 use binius_math::extrapolate_line_scalar;
-use binius_ntt::AdditiveNTT;
-use binius_utils::bail;
+use binius_utils::{bail, SerializationMode, SerializeBytes};
+use digest::{Digest, Output};
 use getset::{CopyGetters, Getters};
 
 use crate::{
@@ -31,7 +33,7 @@ where
 	FS: BinaryField,
 {
 	// Perform inverse additive NTT butterfly
-	let t = rs_code.get_ntt().get_subspace_eval(round, index);
+	let t = rs_code.get_subspace_eval(round, index);
 	let (mut u, mut v) = values;
 	v += u;
 	u += v * t;
@@ -71,6 +73,11 @@ where
 	debug_assert_eq!(values.len(), 1 << folding_challenges.len());
 	debug_assert!(scratch_buffer.len() >= values.len());
 
+	// Folding by a single round is the common case and needs no scratch space
+	if let [r] = *folding_challenges {
+		return fold_pair(rs_code, start_round, chunk_index, (values[0], values[1]), r);
+	}
+
 	// Fold the chunk with the folding challenges one by one
 	for n_challenges_processed in 0..folding_challenges.len() {
 		let n_remaining_challenges = folding_challenges.len() - n_challenges_processed;
@@ -101,6 +108,108 @@ where
 	scratch_buffer[0]
 }
 
+/// Folds an entire codeword by `arity` rounds, starting at fold round `start_round`.
+///
+/// This is a serial reference implementation that applies [`fold_chunk`] to every chunk of
+/// `2^arity` consecutive values in order. It is intended as the ground truth when testing the
+/// optimized folding paths, not as the fast path itself; provers should use
+/// [`fold_codeword`](super::fold_codeword).
+///
+/// ## Preconditions
+///
+/// * `arity` must be nonzero and equal to `challenges.len()`.
+/// * `codeword.len()` must be a multiple of `2^arity`.
+/// * `start_round + arity` must be at most `rs_code.log_dim()`.
+pub fn fold_full_round<F, FS>(
+	rs_code: &ReedSolomonCode<FS>,
+	start_round: usize,
+	arity: usize,
+	codeword: &[F],
+	challenges: &[F],
+) -> Vec<F>
+where
+	F: BinaryField + ExtensionField<FS>,
+	FS: BinaryField,
+{
+	assert!(arity > 0);
+	assert_eq!(challenges.len(), arity);
+	assert_eq!(codeword.len() % (1 << arity), 0);
+	assert!(start_round + arity <= rs_code.log_dim());
+
+	let mut scratch_buffer = vec![F::default(); 1 << arity];
+	codeword
+		.chunks(1 << arity)
+		.enumerate()
+		.map(|(chunk_index, chunk)| {
+			fold_chunk(rs_code, start_round, chunk_index, chunk, challenges, &mut scratch_buffer)
+		})
+		.collect()
+}
+
+/// Returns the chunk that a query opens in an oracle, along with the range of positions the chunk
+/// occupies within the oracle.
+///
+/// `query_index` is the query position in the codeword before folding and `round` is the number
+/// of folding rounds applied to the oracle being opened, so the query falls at position
+/// `query_index >> round` of that oracle. Folding the oracle by `arity` rounds groups its
+/// positions into chunks of `2^arity` consecutive values; the returned chunk index is the one
+/// passed to [`fold_chunk`] and also the query position in the next oracle.
+pub const fn coset_layout(query_index: usize, round: usize, arity: usize) -> (usize, Range<usize>) {
+	let chunk_index = query_index >> round >> arity;
+	let coset_start = chunk_index << arity;
+	(chunk_index, coset_start..coset_start + (1 << arity))
+}
+
+/// Folds a single query down its path through the FRI oracles, returning the folded value after
+/// each oracle is folded.
+///
+/// `openings[i]` is the coset of the `i`-th oracle that contains the query, as returned by
+/// [`coset_layout`], and `arities[i]` is the number of rounds that oracle is folded by. The
+/// returned vector has one value per oracle; its last element is the value of the fully folded
+/// codeword at the query's final position. This is a serial reference for the per-query folding
+/// the verifier performs and does not check the folded values against the next oracle's opening.
+///
+/// ## Preconditions
+///
+/// * `openings.len()` must equal `arities.len()` and `openings[i].len()` must be `2^arities[i]`.
+/// * `challenges.len()` must equal the sum of `arities`, which must be at most
+///   `rs_code.log_dim()`.
+pub fn fold_query_path<F, FS>(
+	rs_code: &ReedSolomonCode<FS>,
+	query_index: usize,
+	arities: &[usize],
+	openings: &[Vec<F>],
+	challenges: &[F],
+) -> Vec<F>
+where
+	F: BinaryField + ExtensionField<FS>,
+	FS: BinaryField,
+{
+	assert_eq!(openings.len(), arities.len());
+	assert_eq!(challenges.len(), arities.iter().sum::<usize>());
+	assert!(challenges.len() <= rs_code.log_dim());
+
+	let max_arity = arities.iter().copied().max().unwrap_or_default();
+	let mut scratch_buffer = vec![F::default(); 1 << max_arity];
+	let mut round = 0;
+	iter::zip(arities, openings)
+		.map(|(&arity, values)| {
+			assert_eq!(values.len(), 1 << arity);
+			let (chunk_index, _) = coset_layout(query_index, round, arity);
+			let folded = fold_chunk(
+				rs_code,
+				round,
+				chunk_index,
+				values,
+				&challenges[round..round + arity],
+				&mut scratch_buffer,
+			);
+			round += arity;
+			folded
+		})
+		.collect()
+}
+
 /// Calculate the fold of an interleaved chunk of values with random folding challenges.
 ///
 /// The elements in the `values` vector are the interleaved cosets of a batch of codewords at the
@@ -179,9 +288,27 @@ where
 	/// The number oracle consistency queries required during the query phase.
 	#[getset(get_copy = "pub")]
 	n_test_queries: usize,
+	/// How the prover conveys the final message to the verifier.
+	#[getset(get_copy = "pub")]
+	final_message_mode: FinalMessageMode,
 	_marker: PhantomData<F>,
 }
 
+/// How the final message of the FRI protocol is conveyed to the verifier.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FinalMessageMode {
+	/// The terminate codeword is written to the transcript in the clear after the fold rounds.
+	#[default]
+	Plaintext,
+	/// The terminate codeword is only available through the commitment of the last oracle.
+	///
+	/// The last oracle is committed in cosets of `2^n_final_challenges` values, so that each coset
+	/// folds to the final message. The prover opens the first coset to convey the final message,
+	/// and every test query opens the coset it reaches in the last oracle, checking that it also
+	/// folds to the final message. This requires at least one oracle to be sent in the fold rounds.
+	Committed,
+}
+
 impl<F, FA> FRIParams<F, FA>
 where
 	F: BinaryField + ExtensionField<FA>,
@@ -193,19 +320,82 @@ where
 		fold_arities: Vec<usize>,
 		n_test_queries: usize,
 	) -> Result<Self, Error> {
-		if fold_arities.iter().sum::<usize>() >= rs_code.log_dim() + log_batch_size {
-			bail!(Error::InvalidFoldAritySequence)
-		}
+		validate_fold_schedule(rs_code.log_dim() + log_batch_size, &fold_arities)?;
 
 		Ok(Self {
 			rs_code,
 			log_batch_size,
 			fold_arities,
 			n_test_queries,
+			final_message_mode: FinalMessageMode::default(),
 			_marker: PhantomData,
 		})
 	}
 
+	/// Sets how the final message is conveyed to the verifier.
+	///
+	/// [`FinalMessageMode::Committed`] is rejected when no oracles are sent in the fold rounds, as
+	/// then the last oracle is the original interleaved codeword.
+	pub fn with_final_message_mode(mut self, mode: FinalMessageMode) -> Result<Self, Error> {
+		if mode == FinalMessageMode::Committed && self.n_oracles() == 0 {
+			bail!(Error::InvalidArgs(
+				"a committed final message requires at least one oracle in the fold rounds"
+					.to_string()
+			));
+		}
+		self.final_message_mode = mode;
+		Ok(self)
+	}
+
+	/// Constructs the parameters from the lengths of the committed oracles.
+	///
+	/// `oracle_lens` contains the length of the initially committed codeword followed by the
+	/// lengths of the oracles committed in the fold rounds, and the fold arities are derived from
+	/// the ratios of consecutive lengths. The oracles must imply fewer fold rounds than the
+	/// interleaved code has, leaving at least one round for the final codeword, which is checked
+	/// up front so that mismatched oracles are rejected before any folding takes place.
+	pub fn from_oracle_lens(
+		rs_code: ReedSolomonCode<FA>,
+		log_batch_size: usize,
+		oracle_lens: &[usize],
+		n_test_queries: usize,
+	) -> Result<Self, Error> {
+		let log_len = rs_code.log_len() + log_batch_size;
+		if oracle_lens.first() != Some(&(1 << log_len)) {
+			bail!(Error::InvalidArgs(format!(
+				"initial oracle length must be the interleaved codeword length {}",
+				1usize << log_len,
+			)));
+		}
+
+		if !oracle_lens.iter().all(|len| len.is_power_of_two()) {
+			bail!(Error::RoundVCSLengthsNotPowerOfTwo);
+		}
+
+		let log_oracle_lens = oracle_lens
+			.iter()
+			.map(|len| len.trailing_zeros() as usize)
+			.collect::<Vec<_>>();
+		if log_oracle_lens.windows(2).any(|lens| lens[1] >= lens[0]) {
+			bail!(Error::RoundVCSLengthsNotDescending);
+		}
+
+		let n_commit_rounds = log_len - log_oracle_lens.last().expect("checked non-empty above");
+		let n_fold_rounds = rs_code.log_dim() + log_batch_size;
+		if n_commit_rounds >= n_fold_rounds {
+			bail!(Error::TooManyFoldRounds {
+				n_commit_rounds,
+				n_fold_rounds,
+			});
+		}
+
+		let fold_arities = log_oracle_lens
+			.windows(2)
+			.map(|lens| lens[0] - lens[1])
+			.collect();
+		Self::new(rs_code, log_batch_size, fold_arities, n_test_queries)
+	}
+
 	pub const fn n_fold_rounds(&self) -> usize {
 		self.rs_code.log_dim() + self.log_batch_size
 	}
@@ -224,6 +414,15 @@ where
 			.unwrap_or(0)
 	}
 
+	/// Number of distinct cosets of the initial oracle that can be queried in the first fold round.
+	///
+	/// This is the size of the query index domain, `2^(log_len - first_arity)`, and so bounds the
+	/// number of distinct test queries. Without fold rounds there is a single coset, the whole
+	/// codeword.
+	pub fn n_distinct_cosets(&self) -> usize {
+		1 << self.index_bits()
+	}
+
 	/// Number of folding challenges the verifier sends after receiving the last oracle.
 	pub fn n_final_challenges(&self) -> usize {
 		self.n_fold_rounds() - self.fold_arities.iter().sum::<usize>()
@@ -234,10 +433,72 @@ where
 		&self.fold_arities
 	}
 
+	/// The fold rounds after which the prover commits an oracle.
+	///
+	/// The oracle following the `i`-th fold arity is committed in the round that completes the
+	/// first `i + 1` arities, so the commit rounds are the prefix sums of the arities minus one.
+	pub fn fold_commit_rounds(&self) -> Vec<usize> {
+		self.fold_arities
+			.iter()
+			.scan(0, |n_rounds, &arity| {
+				*n_rounds += arity;
+				Some(*n_rounds - 1)
+			})
+			.collect()
+	}
+
+	/// The variable of a blinded message that holds its random padding, see
+	/// [`super::commit_interleaved_with_blinding`].
+	///
+	/// This is the first variable folded after the first fold arity, so that every coset opened
+	/// in the first fold round mixes message and padding symbols. As the first fold arity is at
+	/// least the log batch size, it is a variable of the Reed–Solomon code. Returns `None` if
+	/// there is no fold round with a commitment or if the first fold arity covers every variable.
+	pub fn blinding_padding_var(&self) -> Option<usize> {
+		let &first_arity = self.fold_arities.first()?;
+		(first_arity < self.n_fold_rounds()).then_some(first_arity)
+	}
+
 	/// The binary logarithm of the length of the initial oracle.
 	pub fn log_len(&self) -> usize {
 		self.rs_code().log_len() + self.log_batch_size()
 	}
+
+	/// The length of the terminate codeword the prover sends in the clear after the fold rounds.
+	pub fn terminate_codeword_len(&self) -> usize {
+		1 << (self.n_final_challenges() + self.rs_code().log_inv_rate())
+	}
+
+	/// The base-2 logarithm of the number of values in each leaf of the last oracle commitment.
+	pub(super) fn log_terminate_coset_size(&self) -> usize {
+		match self.final_message_mode {
+			FinalMessageMode::Plaintext => self.rs_code().log_inv_rate(),
+			FinalMessageMode::Committed => self.n_final_challenges(),
+		}
+	}
+}
+
+/// The Merkle layer depth at which the last oracle is decommitted, if the final message is
+/// committed.
+///
+/// Besides every test query, the opening of the final message also authenticates against this
+/// layer.
+pub fn vcs_terminate_layer_depth<F, FA, VCS>(
+	fri_params: &FRIParams<F, FA>,
+	vcs: &VCS,
+) -> Option<usize>
+where
+	VCS: MerkleTreeScheme<F>,
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	match fri_params.final_message_mode() {
+		FinalMessageMode::Plaintext => None,
+		FinalMessageMode::Committed => Some(vcs.optimal_verify_layer(
+			fri_params.n_test_queries() + 1,
+			fri_params.rs_code().log_inv_rate(),
+		)),
+	}
 }
 
 /// This layer allows minimizing the proof size.
@@ -259,9 +520,300 @@ where
 		})
 }
 
+/// Returns the length of the codeword at each oracle round of the FRI protocol.
+///
+/// The first entry is the length of the initially committed codeword, followed by the length of
+/// the oracle committed after each fold arity. The last entry is therefore the length of the
+/// terminate codeword sent in the clear after the fold rounds.
+pub fn round_codeword_lengths<F, FA>(fri_params: &FRIParams<F, FA>) -> Vec<usize>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	iter::once(0)
+		.chain(
+			fri_params
+				.fold_arities()
+				.iter()
+				.scan(0, |cumulative_arity, &arity| {
+					*cumulative_arity += arity;
+					Some(*cumulative_arity)
+				}),
+		)
+		.map(|cumulative_arity| 1 << (fri_params.log_len() - cumulative_arity))
+		.collect()
+}
+
+/// Estimates the number of bytes a FRI prover holds in memory for the given parameters.
+///
+/// The estimate sums the codewords of every oracle round, as given by [`round_codeword_lengths`],
+/// the scratch space of `2^max_arity` field elements used for folding, and the Merkle tree digests
+/// committing to each oracle. The maximum arity also counts the final challenges, which are folded
+/// in one step. Each oracle is committed in cosets of the next fold arity, or of the terminate
+/// coset size for the last oracle, so its tree has `2 * n_cosets - 1` digests of type `Digest`.
+/// The estimate ignores allocator overhead and transient buffers of the NTT and hashers.
+pub fn estimate_prover_memory<F, FA, Digest>(fri_params: &FRIParams<F, FA>) -> usize
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	let codeword_lengths = round_codeword_lengths(fri_params);
+	let codewords_size = codeword_lengths.iter().sum::<usize>() * size_of::<F>();
+
+	let max_arity = fri_params
+		.fold_arities()
+		.iter()
+		.copied()
+		.chain(iter::once(fri_params.n_final_challenges()))
+		.max()
+		.unwrap_or_default();
+	let scratch_size = (1 << max_arity) * size_of::<F>();
+
+	let log_coset_sizes = fri_params
+		.fold_arities()
+		.iter()
+		.copied()
+		.chain(iter::once(fri_params.log_terminate_coset_size()));
+	let n_digests = iter::zip(&codeword_lengths, log_coset_sizes)
+		.map(|(&len, log_coset_size)| 2 * (len >> log_coset_size) - 1)
+		.sum::<usize>();
+	let commitments_size = n_digests * size_of::<Digest>();
+
+	codewords_size + scratch_size + commitments_size
+}
+
+/// Returns the maximum number of bytes a valid FRI proof can occupy for the given parameters.
+///
+/// The proof consists of the terminate codeword, the coset values opened by each test query in
+/// every oracle, and the vector commitment data authenticating them, which takes
+/// `vcs_proof_size` bytes. With a committed final message, the terminate codeword is replaced by
+/// the opening of the final message and one more coset per test query in the last oracle. All of
+/// these have sizes fixed by the parameters, so a verifier can reject a longer proof before reading
+/// any of it, rather than trusting lengths claimed by the prover.
+pub fn max_valid_proof_size<F, FA>(fri_params: &FRIParams<F, FA>, vcs_proof_size: usize) -> usize
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	n_proof_values(fri_params) * size_of::<F>() + vcs_proof_size
+}
+
+/// Estimates the number of constraints a recursive circuit needs to verify a FRI proof.
+///
+/// Each field value of the proof, as counted by [`max_valid_proof_size`], is folded or compared
+/// once, costing one constraint. Each coset opening is authenticated by a Merkle path of
+/// `merkle_depth` compressions plus the hash of the leaf, costing one constraint each. The test
+/// queries open one coset per oracle, and with a committed final message the last oracle is
+/// opened once more for the final message. The estimate ignores sampling the query indices and the
+/// one-time check of the decommitted Merkle layers.
+pub fn recursion_constraint_estimate<F, FA>(
+	fri_params: &FRIParams<F, FA>,
+	merkle_depth: usize,
+) -> usize
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	let n_test_queries = fri_params.n_test_queries();
+	let n_openings = match fri_params.final_message_mode() {
+		FinalMessageMode::Plaintext => n_test_queries * fri_params.n_oracles(),
+		FinalMessageMode::Committed => n_test_queries * (fri_params.n_oracles() + 1) + 1,
+	};
+	n_proof_values(fri_params) + n_openings * (merkle_depth + 1)
+}
+
+/// The number of field values in a valid FRI proof, excluding vector commitment data.
+fn n_proof_values<F, FA>(fri_params: &FRIParams<F, FA>) -> usize
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	let n_coset_values = fri_params
+		.fold_arities()
+		.iter()
+		.map(|arity| 1 << arity)
+		.sum::<usize>();
+	let n_terminate_values = match fri_params.final_message_mode() {
+		FinalMessageMode::Plaintext => fri_params.terminate_codeword_len(),
+		FinalMessageMode::Committed => {
+			(fri_params.n_test_queries() + 1) << fri_params.n_final_challenges()
+		}
+	};
+	n_terminate_values + fri_params.n_test_queries() * n_coset_values
+}
+
+/// Checks that a fold schedule covers exactly `total_fold_rounds` fold rounds.
+///
+/// `fold_arities` holds the number of rounds folded between consecutive oracle commitments. Each
+/// arity must be nonzero, and the rounds folded before the last commitment must leave at least one
+/// round for the final challenges, so that the committed rounds plus the final challenges add up
+/// to exactly `total_fold_rounds`.
+pub fn validate_fold_schedule(
+	total_fold_rounds: usize,
+	fold_arities: &[usize],
+) -> Result<(), Error> {
+	if let Some(index) = fold_arities.iter().position(|&arity| arity == 0) {
+		bail!(Error::FoldArityIsZero { index });
+	}
+
+	let last_commit_round = fold_arities
+		.iter()
+		.try_fold(0usize, |round, &arity| round.checked_add(arity))
+		.ok_or(Error::InvalidFoldAritySequence)?;
+	if last_commit_round >= total_fold_rounds {
+		bail!(Error::InvalidFoldAritySequence);
+	}
+
+	Ok(())
+}
+
+/// Returns a fold schedule of constant `arity` that ends with a shorter fold round when needed.
+///
+/// A constant-arity schedule with `(total_fold_rounds - 1) / arity` oracles leaves the remainder
+/// of the division to the final challenges, so the terminate codeword may grow by up to a factor
+/// of `2^(arity - 1)`. This schedule instead commits one more oracle after a final fold round of
+/// arity `(total_fold_rounds - 1) % arity`, leaving exactly one round for the final challenges.
+/// The result passes [`validate_fold_schedule`] for any nonzero `total_fold_rounds`.
+///
+/// ## Preconditions
+///
+/// * `arity` must be nonzero.
+pub fn fold_arities_with_partial_round(total_fold_rounds: usize, arity: usize) -> Vec<usize> {
+	assert!(arity > 0);
+
+	let n_committed_rounds = total_fold_rounds.saturating_sub(1);
+	let partial_round = n_committed_rounds % arity;
+	iter::repeat(arity)
+		.take(n_committed_rounds / arity)
+		.chain((partial_round > 0).then_some(partial_round))
+		.collect()
+}
+
+/// Checks that fold commit rounds are strictly increasing and within `[0, total_fold_rounds)`.
+///
+/// `fold_commit_rounds` holds the fold round after which each oracle is committed, as returned by
+/// [`FRIParams::fold_commit_rounds`]. Commit rounds constructed by hand may be unordered, which
+/// would make the fold chunks derived from them meaningless.
+pub fn validate_commit_rounds(
+	total_fold_rounds: usize,
+	fold_commit_rounds: &[usize],
+) -> Result<(), Error> {
+	if let Some(index) = fold_commit_rounds
+		.windows(2)
+		.position(|rounds| rounds[1] <= rounds[0])
+	{
+		bail!(Error::CommitRoundsNotIncreasing { index: index + 1 });
+	}
+	if let Some(index) = fold_commit_rounds
+		.iter()
+		.position(|&round| round >= total_fold_rounds)
+	{
+		bail!(Error::CommitRoundOutOfRange {
+			index,
+			n_fold_rounds: total_fold_rounds,
+		});
+	}
+	Ok(())
+}
+
+/// Returns the fold round at which each fold chunk starts, given the fold commit rounds.
+///
+/// The first chunk starts at round 0 and every commitment starts a new chunk in the round after
+/// it, so the result has one more entry than `fold_commit_rounds`. The last entry is the round at
+/// which the final challenges begin. The commit rounds are checked with
+/// [`validate_commit_rounds`].
+pub fn calculate_fold_chunk_start_rounds(
+	total_fold_rounds: usize,
+	fold_commit_rounds: &[usize],
+) -> Result<Vec<usize>, Error> {
+	validate_commit_rounds(total_fold_rounds, fold_commit_rounds)?;
+	Ok(iter::once(0)
+		.chain(fold_commit_rounds.iter().map(|&round| round + 1))
+		.collect())
+}
+
+/// Checks that the lengths of the committed FRI oracles are consistent with the fold arities.
+///
+/// `oracle_lens` contains the length of the initially committed codeword followed by the lengths
+/// of the oracles committed in the fold rounds, so it must be one element longer than
+/// `fold_arities`. Each oracle must be shorter than the previous one by a factor of exactly
+/// `2^arity`, where `arity` is the fold arity between the two.
+///
+/// This is a standalone helper for callers that build the oracle lengths by hand alongside a
+/// separately chosen arity schedule. [`FRIVerifier`](super::FRIVerifier) does not call it: the
+/// verifier derives every oracle shape from its [`FRIParams`], and
+/// [`FRIParams::from_oracle_lens`] derives the arities from the lengths, so neither can disagree.
+pub fn validate_vcss_against_arities(
+	oracle_lens: &[usize],
+	fold_arities: &[usize],
+) -> Result<(), Error> {
+	if oracle_lens.len() != fold_arities.len() + 1 {
+		bail!(Error::InvalidArgs(format!(
+			"got {} oracle lengths, expected {}",
+			oracle_lens.len(),
+			fold_arities.len() + 1,
+		)));
+	}
+
+	if !oracle_lens.iter().all(|len| len.is_power_of_two()) {
+		bail!(Error::RoundVCSLengthsNotPowerOfTwo);
+	}
+
+	for (index, (lens, &arity)) in oracle_lens.windows(2).zip(fold_arities).enumerate() {
+		if lens[1] >= lens[0] {
+			bail!(Error::RoundVCSLengthsNotDescending);
+		}
+		if lens[0] >> arity != lens[1] {
+			bail!(Error::RoundVCSLengthsArityMismatch { index });
+		}
+	}
+
+	Ok(())
+}
+
 /// The type of the termination round codeword in the FRI protocol.
 pub type TerminateCodeword<F> = Vec<F>;
 
+/// The coset values opened by a single test query, one coset per oracle round.
+pub type QueryProof<F> = Vec<Vec<F>>;
+
+/// Computes a digest binding all values of a FRI proof.
+///
+/// The commitments, the final message and the query proofs are absorbed into `hasher` in that
+/// order, using the canonical tower [`SerializeBytes`] encoding with every sequence prefixed by its
+/// length, so that the digest only depends on the proof values and on how they are grouped.
+pub fn fri_proof_digest<F, VCSDigest, H>(
+	query_proofs: &[QueryProof<F>],
+	final_message: &[F],
+	commitments: &[VCSDigest],
+	mut hasher: H,
+) -> Output<H>
+where
+	F: TowerField,
+	VCSDigest: SerializeBytes,
+	H: Digest,
+{
+	fn serialize_slice<T: SerializeBytes>(buf: &mut Vec<u8>, values: &[T]) {
+		let mode = SerializationMode::CanonicalTower;
+		values
+			.len()
+			.serialize(&mut *buf, mode)
+			.expect("serializing into a Vec does not fail");
+		for value in values {
+			value
+				.serialize(&mut *buf, mode)
+				.expect("serializing into a Vec does not fail");
+		}
+	}
+
+	let mut buf = Vec::new();
+	serialize_slice(&mut buf, commitments);
+	serialize_slice(&mut buf, final_message);
+	serialize_slice(&mut buf, query_proofs);
+	hasher.update(&buf);
+	hasher.finalize()
+}
+
 /// Calculates the number of test queries required to achieve a target security level.
 ///
 /// Throws [`Error::ParameterError`] if the security level is unattainable given the code
@@ -274,10 +826,88 @@ where
 	F: BinaryField + ExtensionField<PS::Scalar>,
 	PS: PackedField<Scalar: BinaryField>,
 {
-	let per_query_err = 0.5 * (1f64 + 2.0f64.powi(-(code.log_inv_rate() as i32)));
-	let mut n_queries = (-(security_bits as f64) / per_query_err.log2()).ceil() as usize;
+	n_test_queries_for::<F>(security_bits, code.log_dim(), code.log_inv_rate())
+}
+
+/// Calculates the number of test queries required to achieve a target security level with a
+/// given fold schedule.
+///
+/// `log_batch_size` and `fold_arities` are the interleaving and fold arities that would be passed
+/// to [`FRIParams::new`]. Unlike [`calculate_n_test_queries`], which assumes that every oracle is
+/// folded by a single round, the folding error term accounts for the number of rounds each oracle
+/// is folded by, see [`folding_error`]. For a schedule of arity one with no interleaving the two
+/// agree.
+///
+/// Throws [`Error::InvalidFoldAritySequence`] if the arities exceed the number of fold rounds and
+/// [`Error::ParameterError`] if the security level is unattainable.
+pub fn calculate_n_test_queries_for_schedule<F, PS>(
+	security_bits: usize,
+	code: &ReedSolomonCode<PS>,
+	log_batch_size: usize,
+	fold_arities: &[usize],
+) -> Result<usize, Error>
+where
+	F: BinaryField + ExtensionField<PS::Scalar>,
+	PS: PackedField<Scalar: BinaryField>,
+{
+	let oracle_folds =
+		oracle_folds(code.log_dim(), code.log_inv_rate(), log_batch_size, fold_arities)?;
+	n_test_queries_with_folds::<F>(
+		security_bits,
+		code.log_dim(),
+		code.log_inv_rate(),
+		&oracle_folds,
+	)
+}
+
+/// Returns the largest code dimension, as a binary logarithm, for which
+/// [`calculate_n_test_queries`] finds a query count achieving `security_bits` of security.
+///
+/// The sumcheck and folding error terms grow with the code dimension relative to the size of `F`
+/// and do not shrink with more queries, so beyond this dimension a larger field is required.
+/// Returns zero if the target is unattainable even for a code of dimension one.
+pub fn max_secure_log_dim<F: BinaryField>(security_bits: usize, log_inv_rate: usize) -> usize {
+	// Both field-dependent terms exceed one once the code length reaches the field size.
+	(0..F::N_BITS)
+		.take_while(|&log_dim| {
+			n_test_queries_for::<F>(security_bits, log_dim, log_inv_rate).is_ok()
+		})
+		.last()
+		.unwrap_or(0)
+}
+
+/// Returns the soundness error of a single FRI test query, `(1 + 2^-log_inv_rate) / 2`.
+///
+/// The query term of the FRI soundness error is this value raised to the number of queries.
+pub fn per_query_error(log_inv_rate: usize) -> f64 {
+	0.5 * (1.0 + 2.0f64.powi(-(log_inv_rate as i32)))
+}
+
+fn n_test_queries_for<F: BinaryField>(
+	security_bits: usize,
+	log_dim: usize,
+	log_inv_rate: usize,
+) -> Result<usize, Error> {
+	n_test_queries_with_folds::<F>(
+		security_bits,
+		log_dim,
+		log_inv_rate,
+		&uniform_oracle_folds(log_dim, log_inv_rate),
+	)
+}
+
+fn n_test_queries_with_folds<F: BinaryField>(
+	security_bits: usize,
+	log_dim: usize,
+	log_inv_rate: usize,
+	oracle_folds: &[(usize, usize)],
+) -> Result<usize, Error> {
+	let mut n_queries =
+		(-(security_bits as f64) / per_query_error(log_inv_rate).log2()).ceil() as usize;
 	for _ in 0..10 {
-		if calculate_error_bound::<F, _>(code, n_queries) >= security_bits {
+		if calculate_error_bound::<F>(log_dim, log_inv_rate, oracle_folds, n_queries)
+			>= security_bits
+		{
 			return Ok(n_queries);
 		}
 		n_queries += 1;
@@ -285,20 +915,133 @@ where
 	Err(Error::ParameterError)
 }
 
-fn calculate_error_bound<F, PS>(code: &ReedSolomonCode<PS>, n_queries: usize) -> usize
+fn calculate_error_bound<F: BinaryField>(
+	log_dim: usize,
+	log_inv_rate: usize,
+	oracle_folds: &[(usize, usize)],
+	n_queries: usize,
+) -> usize {
+	let total_err = error_terms::<F>(log_dim, log_inv_rate, oracle_folds, n_queries)
+		.iter()
+		.sum::<f64>();
+	-total_err.log2() as usize
+}
+
+/// Returns the sumcheck, folding and query soundness error terms, in that order.
+fn error_terms<F: BinaryField>(
+	log_dim: usize,
+	log_inv_rate: usize,
+	oracle_folds: &[(usize, usize)],
+	n_queries: usize,
+) -> [f64; 3] {
+	let field_size = 2.0_f64.powi(F::N_BITS as i32);
+	// ℓ' / |T_{τ}|
+	let sumcheck_err = log_dim as f64 / field_size;
+	let folding_err = folding_error::<F>(oracle_folds);
+	let query_err = per_query_error(log_inv_rate).powi(n_queries as i32);
+	[sumcheck_err, folding_err, query_err]
+}
+
+/// Returns the folding soundness error term for a fold schedule.
+///
+/// `oracle_folds` holds, for every oracle in order, the binary logarithm of its code length and
+/// the number of fold rounds applied to it. Folding an oracle of length `2^n` by `ϑ` rounds
+/// contributes `ϑ · 2^n / |T_{τ}|`, and the largest contribution is returned. When every oracle is
+/// folded by a single round the largest contribution is the first one, `2^{ℓ' + R} / |T_{τ}|`.
+fn folding_error<F: BinaryField>(oracle_folds: &[(usize, usize)]) -> f64 {
+	let field_size = 2.0_f64.powi(F::N_BITS as i32);
+	oracle_folds
+		.iter()
+		.map(|&(log_len, n_rounds)| n_rounds as f64 * 2.0_f64.powi(log_len as i32) / field_size)
+		.fold(0.0, f64::max)
+}
+
+/// Returns the code length and number of fold rounds of every oracle in a fold schedule, as
+/// consumed by [`folding_error`].
+///
+/// The first oracle has the length of the code and the rounds after the last committed oracle fold
+/// the terminate codeword. Interleaving rounds fold the first oracle without shrinking its code.
+fn oracle_folds(
+	log_dim: usize,
+	log_inv_rate: usize,
+	log_batch_size: usize,
+	fold_arities: &[usize],
+) -> Result<Vec<(usize, usize)>, Error> {
+	let n_fold_rounds = log_dim + log_batch_size;
+	let n_committed_rounds = fold_arities.iter().sum::<usize>();
+	if n_committed_rounds > n_fold_rounds {
+		bail!(Error::InvalidFoldAritySequence);
+	}
+
+	let mut log_len = log_dim + log_inv_rate + log_batch_size;
+	let oracle_folds = fold_arities
+		.iter()
+		.copied()
+		.chain(iter::once(n_fold_rounds - n_committed_rounds))
+		.map(|n_rounds| {
+			let fold = (log_len.min(log_dim + log_inv_rate), n_rounds);
+			log_len -= n_rounds;
+			fold
+		})
+		.filter(|&(_, n_rounds)| n_rounds > 0)
+		.collect();
+	Ok(oracle_folds)
+}
+
+/// The oracle folds of a schedule that folds every oracle by a single round.
+fn uniform_oracle_folds(log_dim: usize, log_inv_rate: usize) -> Vec<(usize, usize)> {
+	(0..log_dim)
+		.map(|round| (log_dim + log_inv_rate - round, 1))
+		.collect()
+}
+
+/// One of the terms summed into the FRI soundness error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorTerm {
+	/// The error from the sumcheck challenges, which shrinks with the field size.
+	Sumcheck,
+	/// The error from the folding challenges, which shrinks with the field size.
+	Folding,
+	/// The error from the proximity test queries, which shrinks with the number of queries.
+	Query,
+}
+
+/// The largest error term for a set of FRI parameters, see [`dominant_error_term`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DominantErrorTerm {
+	/// The term contributing the most to the soundness error.
+	pub term: ErrorTerm,
+	/// The number of bits by which the dominant term exceeds the second largest one.
+	pub bits_gap: f64,
+}
+
+/// Returns the term that dominates the soundness error bound for the given code and number of
+/// test queries.
+///
+/// This indicates where security budget is best spent: when the query term dominates, adding
+/// queries improves security, whereas when a field-size dependent term dominates, only a larger
+/// extension field helps.
+pub fn dominant_error_term<F, PS>(code: &ReedSolomonCode<PS>, n_queries: usize) -> DominantErrorTerm
 where
 	F: BinaryField + ExtensionField<PS::Scalar>,
 	PS: PackedField<Scalar: BinaryField>,
 {
-	let field_size = 2.0_f64.powi(F::N_BITS as i32);
-	// ℓ' / |T_{τ}|
-	let sumcheck_err = code.log_dim() as f64 / field_size;
-	// 2^{ℓ' + R} / |T_{τ}|
-	let folding_err = code.len() as f64 / field_size;
-	let per_query_err = 0.5 * (1.0 + 2.0f64.powi(-(code.log_inv_rate() as i32)));
-	let query_err = per_query_err.powi(n_queries as i32);
-	let total_err = sumcheck_err + folding_err + query_err;
-	-total_err.log2() as usize
+	let mut terms = iter::zip(
+		[ErrorTerm::Sumcheck, ErrorTerm::Folding, ErrorTerm::Query],
+		error_terms::<F>(
+			code.log_dim(),
+			code.log_inv_rate(),
+			&uniform_oracle_folds(code.log_dim(), code.log_inv_rate()),
+			n_queries,
+		),
+	)
+	.collect::<Vec<_>>();
+	terms.sort_by(|(_, lhs), (_, rhs)| rhs.total_cmp(lhs));
+
+	DominantErrorTerm {
+		term: terms[0].0,
+		bits_gap: terms[0].1.log2() - terms[1].1.log2(),
+	}
 }
 
 /// Heuristic for estimating the optimal FRI folding arity that minimizes proof size.
@@ -335,11 +1078,50 @@ pub fn estimate_optimal_arity(
 #[cfg(test)]
 mod tests {
 	use assert_matches::assert_matches;
-	use binius_field::{BinaryField128b, BinaryField32b};
+	use binius_field::{BinaryField128b, BinaryField16b, BinaryField32b};
 	use binius_ntt::NTTOptions;
 
 	use super::*;
 
+	#[test]
+	fn test_coset_layout() {
+		let query_index = 0b1011_0110;
+
+		// First oracle, folded with arity 3: positions 0b1011_0000..0b1011_1000.
+		assert_eq!(coset_layout(query_index, 0, 3), (0b10110, 0b1011_0000..0b1011_1000));
+		// After 3 rounds the query is at position 0b10110; folding with arity 2 opens 0b10100..0b11000.
+		assert_eq!(coset_layout(query_index, 3, 2), (0b101, 0b10100..0b11000));
+		// After 5 rounds the query is at position 0b101; folding with arity 1 opens 0b100..0b110.
+		assert_eq!(coset_layout(query_index, 5, 1), (0b10, 0b100..0b110));
+	}
+
+	#[test]
+	fn test_n_distinct_cosets() {
+		let new_rs_code =
+			|| ReedSolomonCode::<BinaryField16b>::new(10, 2, &NTTOptions::default()).unwrap();
+
+		let params = FRIParams::<BinaryField128b, _>::new(new_rs_code(), 2, vec![3, 2], 8).unwrap();
+		// The initial oracle has 2^(10 + 2 + 2) symbols, opened in cosets of 2^3.
+		assert_eq!(params.n_distinct_cosets(), 1 << 11);
+
+		let params = FRIParams::<BinaryField128b, _>::new(new_rs_code(), 0, vec![], 8).unwrap();
+		assert_eq!(params.n_distinct_cosets(), 1);
+	}
+
+	#[test]
+	fn test_dominant_error_term() {
+		let rs_code =
+			ReedSolomonCode::<BinaryField16b>::new(10, 2, &NTTOptions::default()).unwrap();
+
+		let dominant = dominant_error_term::<BinaryField128b, _>(&rs_code, 10);
+		assert_eq!(dominant.term, ErrorTerm::Query);
+		assert!(dominant.bits_gap > 0.0);
+
+		let dominant = dominant_error_term::<BinaryField32b, _>(&rs_code, 100);
+		assert_eq!(dominant.term, ErrorTerm::Folding);
+		assert!(dominant.bits_gap > 0.0);
+	}
+
 	#[test]
 	fn test_calculate_n_test_queries() {
 		let security_bits = 96;
@@ -356,6 +1138,44 @@ mod tests {
 		assert_eq!(n_test_queries, 143);
 	}
 
+	#[test]
+	fn test_calculate_n_test_queries_for_schedule() {
+		let security_bits = 96;
+		let rs_code = ReedSolomonCode::new(28, 1, &NTTOptions::default()).unwrap();
+
+		// A schedule folding by one round at a time matches the uniform model.
+		let n_test_queries =
+			calculate_n_test_queries_for_schedule::<BinaryField128b, BinaryField32b>(
+				security_bits,
+				&rs_code,
+				0,
+				&[1; 27],
+			)
+			.unwrap();
+		assert_eq!(
+			n_test_queries,
+			calculate_n_test_queries::<BinaryField128b, BinaryField32b>(security_bits, &rs_code)
+				.unwrap()
+		);
+		assert_eq!(oracle_folds(28, 1, 0, &[1; 27]).unwrap(), uniform_oracle_folds(28, 1));
+
+		// Higher arities fold the large oracles by more rounds, which increases the folding term.
+		let uniform_err = folding_error::<BinaryField128b>(&uniform_oracle_folds(28, 1));
+		let schedule_folds = oracle_folds(28, 1, 2, &[4, 4, 4]).unwrap();
+		assert_eq!(schedule_folds, vec![(29, 4), (27, 4), (23, 4), (19, 18)]);
+		assert_eq!(folding_error::<BinaryField128b>(&schedule_folds), 4.0 * uniform_err);
+
+		assert_matches!(
+			calculate_n_test_queries_for_schedule::<BinaryField128b, BinaryField32b>(
+				security_bits,
+				&rs_code,
+				0,
+				&[16, 16],
+			),
+			Err(Error::InvalidFoldAritySequence)
+		);
+	}
+
 	#[test]
 	fn test_calculate_n_test_queries_unsatisfiable() {
 		let security_bits = 128;
@@ -366,6 +1186,307 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_per_query_error() {
+		assert_eq!(per_query_error(0), 1.0);
+		assert_eq!(per_query_error(1), 0.75);
+		assert_eq!(per_query_error(2), 0.625);
+	}
+
+	#[test]
+	fn test_max_secure_log_dim() {
+		let security_bits = 96;
+		let log_inv_rate = 1;
+		let max_log_dim = max_secure_log_dim::<BinaryField128b>(security_bits, log_inv_rate);
+		assert_eq!(max_log_dim, 30);
+
+		let rs_code = ReedSolomonCode::<BinaryField32b>::new(
+			max_log_dim,
+			log_inv_rate,
+			&NTTOptions::default(),
+		)
+		.unwrap();
+		assert!(calculate_n_test_queries::<BinaryField128b, _>(security_bits, &rs_code).is_ok());
+
+		let rs_code = ReedSolomonCode::<BinaryField32b>::new(
+			max_log_dim + 1,
+			log_inv_rate,
+			&NTTOptions::default(),
+		)
+		.unwrap();
+		assert_matches!(
+			calculate_n_test_queries::<BinaryField128b, _>(security_bits, &rs_code),
+			Err(Error::ParameterError)
+		);
+	}
+
+	#[test]
+	fn test_round_codeword_lengths() {
+		let rs_code =
+			ReedSolomonCode::<BinaryField16b>::new(10, 2, &NTTOptions::default()).unwrap();
+		let fri_params =
+			FRIParams::<BinaryField128b, _>::new(rs_code, 2, vec![3, 4, 2], 32).unwrap();
+
+		let lengths = round_codeword_lengths(&fri_params);
+		assert_eq!(lengths, vec![1 << 14, 1 << 11, 1 << 7, 1 << 5]);
+		assert!(lengths.windows(2).all(|pair| pair[0] > pair[1]));
+		assert_eq!(*lengths.last().unwrap(), fri_params.terminate_codeword_len());
+	}
+
+	#[test]
+	fn test_estimate_prover_memory() {
+		let rs_code =
+			ReedSolomonCode::<BinaryField16b>::new(10, 2, &NTTOptions::default()).unwrap();
+		let fri_params =
+			FRIParams::<BinaryField128b, _>::new(rs_code, 2, vec![3, 4, 2], 32).unwrap();
+
+		// Oracles of 2^14, 2^11, 2^7 and 2^5 symbols, committed in cosets of 2^3, 2^4, 2^2 and 2^2.
+		let codewords =
+			((1 << 14) + (1 << 11) + (1 << 7) + (1 << 5)) * size_of::<BinaryField128b>();
+		let scratch = (1 << 4) * size_of::<BinaryField128b>();
+		let digests =
+			(2 * (1 << 11) - 1) + (2 * (1 << 7) - 1) + (2 * (1 << 5) - 1) + (2 * (1 << 3) - 1);
+		assert_eq!(
+			estimate_prover_memory::<_, _, [u8; 32]>(&fri_params),
+			codewords + scratch + digests * 32
+		);
+
+		// With a committed final message, the last oracle is committed in cosets of the 2^3 values
+		// folded by the final challenges.
+		let committed_params = fri_params
+			.with_final_message_mode(FinalMessageMode::Committed)
+			.unwrap();
+		let committed_digests = digests - (2 * (1 << 3) - 1) + (2 * (1 << 2) - 1);
+		assert_eq!(
+			estimate_prover_memory::<_, _, [u8; 32]>(&committed_params),
+			codewords + scratch + committed_digests * 32
+		);
+
+		// The final challenges dominate the scratch space when they outnumber every fold arity.
+		let rs_code =
+			ReedSolomonCode::<BinaryField16b>::new(10, 2, &NTTOptions::default()).unwrap();
+		let fri_params = FRIParams::<BinaryField128b, _>::new(rs_code, 0, vec![1, 1], 32).unwrap();
+		assert_eq!(fri_params.n_final_challenges(), 8);
+		let codewords = ((1 << 12) + (1 << 11) + (1 << 10)) * size_of::<BinaryField128b>();
+		let scratch = (1 << 8) * size_of::<BinaryField128b>();
+		let digests = (2 * (1 << 11) - 1) + (2 * (1 << 10) - 1) + (2 * (1 << 8) - 1);
+		assert_eq!(
+			estimate_prover_memory::<_, _, [u8; 32]>(&fri_params),
+			codewords + scratch + digests * 32
+		);
+	}
+
+	#[test]
+	fn test_recursion_constraint_estimate() {
+		let rs_code =
+			ReedSolomonCode::<BinaryField16b>::new(10, 2, &NTTOptions::default()).unwrap();
+		let fri_params =
+			FRIParams::<BinaryField128b, _>::new(rs_code, 2, vec![3, 4, 2], 32).unwrap();
+
+		// Each of the 32 queries opens cosets of 2^3, 2^4 and 2^2 values, and the terminate
+		// codeword has 2^5 values. Every opening hashes a leaf and compresses 20 path nodes.
+		let n_values = 32 * ((1 << 3) + (1 << 4) + (1 << 2)) + (1 << 5);
+		let n_merkle_gates = 32 * 3 * 21;
+		assert_eq!(recursion_constraint_estimate(&fri_params, 20), n_values + n_merkle_gates);
+
+		// A deeper tree only adds Merkle compressions.
+		assert_eq!(
+			recursion_constraint_estimate(&fri_params, 21)
+				- recursion_constraint_estimate(&fri_params, 20),
+			32 * 3
+		);
+	}
+
+	#[test]
+	fn test_fri_proof_digest() {
+		use binius_field::Field;
+		use groestl_crypto::Groestl256;
+		use rand::{rngs::StdRng, SeedableRng};
+
+		type F = BinaryField128b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut random_values = |n| {
+			iter::repeat_with(|| <F as Field>::random(&mut rng))
+				.take(n)
+				.collect::<Vec<_>>()
+		};
+		let query_proofs = (0..3)
+			.map(|_| vec![random_values(8), random_values(4)])
+			.collect::<Vec<_>>();
+		let final_message = random_values(4);
+		let commitments = (0..3u8)
+			.map(|i| Output::<Groestl256>::from([i; 32]))
+			.collect::<Vec<_>>();
+
+		let digest = |query_proofs: &[QueryProof<F>], final_message: &[F], commitments: &[_]| {
+			fri_proof_digest(query_proofs, final_message, commitments, Groestl256::new())
+		};
+		let expected = digest(&query_proofs, &final_message, &commitments);
+		assert_eq!(digest(&query_proofs, &final_message, &commitments), expected);
+
+		let mut changed_query_proofs = query_proofs.clone();
+		changed_query_proofs[2][1][3] += F::ONE;
+		assert_ne!(digest(&changed_query_proofs, &final_message, &commitments), expected);
+
+		let mut changed_final_message = final_message.clone();
+		changed_final_message[0] += F::ONE;
+		assert_ne!(digest(&query_proofs, &changed_final_message, &commitments), expected);
+
+		let mut changed_commitments = commitments.clone();
+		changed_commitments[1][31] ^= 1;
+		assert_ne!(digest(&query_proofs, &final_message, &changed_commitments), expected);
+
+		// Moving a value across a coset boundary changes the digest too.
+		let mut regrouped_query_proofs = query_proofs;
+		let value = regrouped_query_proofs[0][0].pop().unwrap();
+		regrouped_query_proofs[0][1].insert(0, value);
+		assert_ne!(digest(&regrouped_query_proofs, &final_message, &commitments), expected);
+	}
+
+	#[test]
+	fn test_validate_fold_schedule() {
+		assert!(validate_fold_schedule(10, &[]).is_ok());
+		assert!(validate_fold_schedule(10, &[4, 4, 1]).is_ok());
+		assert_matches!(
+			validate_fold_schedule(10, &[4, 4, 2]),
+			Err(Error::InvalidFoldAritySequence)
+		);
+		assert_matches!(
+			validate_fold_schedule(10, &[4, 4, 4]),
+			Err(Error::InvalidFoldAritySequence)
+		);
+		assert_matches!(
+			validate_fold_schedule(10, &[4, 0, 1]),
+			Err(Error::FoldArityIsZero { index: 1 })
+		);
+	}
+
+	#[test]
+	fn test_fold_arities_with_partial_round() {
+		assert_eq!(fold_arities_with_partial_round(9, 4), vec![4, 4]);
+		assert_eq!(fold_arities_with_partial_round(10, 4), vec![4, 4, 1]);
+		assert_eq!(fold_arities_with_partial_round(12, 4), vec![4, 4, 3]);
+		assert_eq!(fold_arities_with_partial_round(3, 4), vec![2]);
+		assert_eq!(fold_arities_with_partial_round(1, 4), Vec::<usize>::new());
+
+		for total_fold_rounds in 1..20 {
+			for arity in 1..6 {
+				let fold_arities = fold_arities_with_partial_round(total_fold_rounds, arity);
+				assert!(validate_fold_schedule(total_fold_rounds, &fold_arities).is_ok());
+				assert_eq!(fold_arities.iter().sum::<usize>(), total_fold_rounds - 1);
+			}
+		}
+	}
+
+	#[test]
+	fn test_validate_commit_rounds() {
+		assert!(validate_commit_rounds(10, &[]).is_ok());
+		assert!(validate_commit_rounds(10, &[3, 7, 8]).is_ok());
+		assert_matches!(
+			validate_commit_rounds(10, &[3, 3, 8]),
+			Err(Error::CommitRoundsNotIncreasing { index: 1 })
+		);
+		assert_matches!(
+			validate_commit_rounds(10, &[3, 7, 5]),
+			Err(Error::CommitRoundsNotIncreasing { index: 2 })
+		);
+		assert_matches!(
+			validate_commit_rounds(10, &[3, 10]),
+			Err(Error::CommitRoundOutOfRange {
+				index: 1,
+				n_fold_rounds: 10
+			})
+		);
+	}
+
+	#[test]
+	fn test_calculate_fold_chunk_start_rounds() {
+		let rs_code = ReedSolomonCode::<BinaryField32b>::new(8, 2, &Default::default()).unwrap();
+		let params =
+			FRIParams::<BinaryField128b, BinaryField32b>::new(rs_code, 2, vec![4, 3, 2], 1)
+				.unwrap();
+		assert_eq!(params.fold_commit_rounds(), vec![3, 6, 8]);
+		assert_eq!(
+			calculate_fold_chunk_start_rounds(params.n_fold_rounds(), &params.fold_commit_rounds())
+				.unwrap(),
+			vec![0, 4, 7, 9]
+		);
+		assert_matches!(
+			calculate_fold_chunk_start_rounds(10, &[6, 3]),
+			Err(Error::CommitRoundsNotIncreasing { index: 1 })
+		);
+	}
+
+	#[test]
+	fn test_validate_vcss_against_arities() {
+		validate_vcss_against_arities(&[1 << 10, 1 << 7, 1 << 5, 1 << 4], &[3, 2, 1]).unwrap();
+		validate_vcss_against_arities(&[1 << 10], &[]).unwrap();
+
+		assert_matches!(
+			validate_vcss_against_arities(&[1 << 10, 1 << 7, 1 << 4, 1 << 3], &[3, 2, 1]),
+			Err(Error::RoundVCSLengthsArityMismatch { index: 1 })
+		);
+		assert_matches!(
+			validate_vcss_against_arities(&[1 << 10, 1 << 7, 1 << 5], &[3, 2, 1]),
+			Err(Error::InvalidArgs(_))
+		);
+		assert_matches!(
+			validate_vcss_against_arities(&[1 << 10, 1 << 7, 96], &[3, 2]),
+			Err(Error::RoundVCSLengthsNotPowerOfTwo)
+		);
+		assert_matches!(
+			validate_vcss_against_arities(&[1 << 10, 1 << 10], &[0]),
+			Err(Error::RoundVCSLengthsNotDescending)
+		);
+	}
+
+	#[test]
+	fn test_from_oracle_lens() {
+		let rs_code =
+			|| ReedSolomonCode::<BinaryField16b>::new(6, 2, &NTTOptions::default()).unwrap();
+
+		let fri_params = FRIParams::<BinaryField128b, _>::from_oracle_lens(
+			rs_code(),
+			0,
+			&[1 << 8, 1 << 5, 1 << 3],
+			32,
+		)
+		.unwrap();
+		assert_eq!(fri_params.fold_arities(), &[3, 2]);
+
+		// Folding from 2^8 down to 2^1 takes 7 rounds, but the code only has 6.
+		assert_matches!(
+			FRIParams::<BinaryField128b, _>::from_oracle_lens(
+				rs_code(),
+				0,
+				&[1 << 8, 1 << 4, 1 << 1],
+				32
+			),
+			Err(Error::TooManyFoldRounds {
+				n_commit_rounds: 7,
+				n_fold_rounds: 6
+			})
+		);
+		// Folding from 2^8 down to 2^2 uses all 6 rounds, leaving none for the final codeword.
+		assert_matches!(
+			FRIParams::<BinaryField128b, _>::from_oracle_lens(
+				rs_code(),
+				0,
+				&[1 << 8, 1 << 5, 1 << 2],
+				32
+			),
+			Err(Error::TooManyFoldRounds {
+				n_commit_rounds: 6,
+				n_fold_rounds: 6
+			})
+		);
+		assert_matches!(
+			FRIParams::<BinaryField128b, _>::from_oracle_lens(rs_code(), 0, &[1 << 9, 1 << 5], 32),
+			Err(Error::InvalidArgs(_))
+		);
+	}
+
 	#[test]
 	fn test_estimate_optimal_arity() {
 		let field_size = 128;
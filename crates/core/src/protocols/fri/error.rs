@@ -14,6 +14,18 @@ pub enum Error {
 	MessageDimensionIsTooSmall,
 	#[error("fold arities total exceeds the number of fold rounds")]
 	InvalidFoldAritySequence,
+	#[error(
+		"oracle lengths imply {n_commit_rounds} fold rounds, but the code has only {n_fold_rounds}, \
+		 one of which must remain for the final codeword"
+	)]
+	TooManyFoldRounds {
+		n_commit_rounds: usize,
+		n_fold_rounds: usize,
+	},
+	#[error("fold commit round at index {index} does not exceed the previous commit round")]
+	CommitRoundsNotIncreasing { index: usize },
+	#[error("fold commit round at index {index} is not less than the {n_fold_rounds} fold rounds")]
+	CommitRoundOutOfRange { index: usize, n_fold_rounds: usize },
 	#[error("fold arity at index {index} in sequence is zero")]
 	FoldArityIsZero { index: usize },
 	#[error("the fold arity for the first fold be be at least the log batch size")]
@@ -28,10 +40,25 @@ pub enum Error {
 	RoundVCSLengthsOutOfRange,
 	#[error("round VCS vector_length must be a power of two")]
 	RoundVCSLengthsNotPowerOfTwo,
+	#[error("round VCS vector_length at index {index} does not match the fold arity")]
+	RoundVCSLengthsArityMismatch { index: usize },
 	#[error("Reed-Solomon encoding error: {0}")]
 	EncodeError(#[from] NttError),
 	#[error("vector commit error: {0}")]
 	VectorCommit(#[source] Box<dyn std::error::Error + Send + Sync>),
+	#[error("query {query} failed at oracle round {round}: {source}")]
+	QueryFailed {
+		query: usize,
+		round: usize,
+		#[source]
+		source: Box<Error>,
+	},
+	#[error("proof {proof} in the batch failed verification: {source}")]
+	BatchProofFailed {
+		proof: usize,
+		#[source]
+		source: Box<Error>,
+	},
 	#[error("verification error: {0}")]
 	Verification(#[from] VerificationError),
 	#[error("transcript error: {0}")]
@@ -46,6 +73,12 @@ pub enum VerificationError {
 	IncorrectQueryProofLength { expected: usize },
 	#[error("the number of values in round {round} of the query proof is incorrect, expected {coset_size}")]
 	IncorrectQueryProofValuesLength { round: usize, coset_size: usize },
+	#[error("the terminate codeword length is incorrect, expected {expected}")]
+	IncorrectTerminateCodewordLength { expected: usize },
 	#[error("The dimension-1 codeword must contain the same values")]
 	IncorrectDegree,
+	#[error("the proof has {size} bytes, more than the maximum of {max_size} for the parameters")]
+	ProofTooLarge { size: usize, max_size: usize },
+	#[error("the codewords of the two codes fold to different final values")]
+	DualCodeFinalValueMismatch,
 }
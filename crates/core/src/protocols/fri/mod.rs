@@ -18,7 +18,8 @@
 //! interpolation. Then in each subsequent round, the prover receives a challenge and folds the
 //! codeword in half using the FRI folding procedure and may or may not send a new oracle to the
 //! verifier. The last oracle the prover sends, they send entirely in the clear to the verifier,
-//! rather than sending with oracle access.
+//! rather than sending with oracle access, unless [`FinalMessageMode::Committed`] is selected, in
+//! which case the last oracle is only opened at the positions reached by the test queries.
 //!
 //! [BBHR17]: <https://eccc.weizmann.ac.il/report/2017/134/>
 //! [DP24]: <https://eprint.iacr.org/2024/504>
@@ -30,7 +31,15 @@ mod prove;
 mod tests;
 mod verify;
 
-pub use common::{calculate_n_test_queries, estimate_optimal_arity, FRIParams, TerminateCodeword};
+pub use common::{
+	calculate_fold_chunk_start_rounds, calculate_n_test_queries,
+	calculate_n_test_queries_for_schedule, coset_layout, dominant_error_term,
+	estimate_optimal_arity, estimate_prover_memory, fold_arities_with_partial_round,
+	fold_full_round, fold_query_path, fri_proof_digest, max_secure_log_dim, max_valid_proof_size,
+	per_query_error, recursion_constraint_estimate, round_codeword_lengths, validate_commit_rounds,
+	validate_fold_schedule, validate_vcss_against_arities, DominantErrorTerm, ErrorTerm, FRIParams,
+	FinalMessageMode, QueryProof, TerminateCodeword,
+};
 pub use error::*;
 pub use prove::*;
 pub use verify::*;
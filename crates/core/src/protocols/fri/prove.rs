@@ -1,6 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::{BinaryField, ExtensionField, PackedExtension, PackedField, TowerField};
+use binius_field::{
+	packed::{get_packed_slice, len_packed_slice},
+	BinaryField, ExtensionField, Field, PackedExtension, PackedField, TowerField,
+};
 use binius_hal::{make_portable_backend, ComputationBackend};
 use binius_maybe_rayon::prelude::*;
 use binius_utils::{bail, SerializeBytes};
@@ -10,14 +13,16 @@ use itertools::izip;
 use tracing::instrument;
 
 use super::{
-	common::{vcs_optimal_layers_depths_iter, FRIParams},
+	common::{
+		vcs_optimal_layers_depths_iter, vcs_terminate_layer_depth, FRIParams, FinalMessageMode,
+	},
 	error::Error,
 	TerminateCodeword,
 };
 use crate::{
 	fiat_shamir::{CanSampleBits, Challenger},
 	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
-	protocols::fri::common::{fold_chunk, fold_interleaved_chunk},
+	protocols::fri::common::{fold_chunk, fold_full_round, fold_interleaved_chunk},
 	reed_solomon::reed_solomon::ReedSolomonCode,
 	transcript::{ProverTranscript, TranscriptWriter},
 };
@@ -257,6 +262,150 @@ where
 	})
 }
 
+/// The output of [`commit_interleaved_with_blinding`].
+#[derive(Debug)]
+pub struct BlindedCommitOutput<P, VCSCommitment, VCSCommitted> {
+	/// The commitment to the codeword of the message with its random padding.
+	pub padded: CommitOutput<P, VCSCommitment, VCSCommitted>,
+	/// The commitment to the codeword of the mask.
+	pub mask: CommitOutput<P, VCSCommitment, VCSCommitted>,
+}
+
+/// Encodes and commits the input message with random padding, along with an independent mask.
+///
+/// The message fills the half of the committed message whose
+/// [padding variable](FRIParams::blinding_padding_var) is zero, and the random `padding` fills
+/// the other half. The padding variable is the first one outside the cosets opened in the first
+/// fold round, so each opened coset is a linear function of the message plus a function of the
+/// padding that vanishes only at the coset at index zero, which contains the zero of the
+/// evaluation domain. Provided that the padding has at least as many symbols as the test queries
+/// open, the opened symbols of every other coset are uniformly distributed for any message.
+///
+/// The mask is committed as an oracle of its own. After both commitments are observed, the
+/// verifier samples a batching coefficient and the padded codeword plus that coefficient times
+/// the mask is folded, see [`batch_blinded_codeword`] and [`FRIFolder::new_blinded`], so that
+/// every later oracle is masked as well. The mask is proven with a plain FRI proof folded with the
+/// same challenges, whose final value corrects the batched final value, see
+/// [`super::FRIVerifier::verify_blinded`]. The padding variable must be folded with a zero
+/// challenge, which drops the padding from the final values.
+///
+/// The terminate codewords of both proofs are sent in the clear, so their difference reveals the
+/// message partially evaluated at the challenges before the last oracle. Parameters for blinding
+/// should therefore leave few final challenges.
+///
+/// ## Arguments
+///
+/// * `rs_code` - the Reed-Solomon code to use for encoding
+/// * `params` - common FRI protocol parameters.
+/// * `merkle_prover` - the Merkle tree prover to use for committing
+/// * `message` - the interleaved message to encode and commit, of half the code message length
+/// * `padding` - the random padding, of the same length as the message
+/// * `mask` - the random interleaved mask, of the code message length
+#[instrument(skip_all, level = "debug")]
+pub fn commit_interleaved_with_blinding<F, FA, P, PA, MerkleProver, VCS>(
+	rs_code: &ReedSolomonCode<PA>,
+	params: &FRIParams<F, FA>,
+	merkle_prover: &MerkleProver,
+	message: &[P],
+	padding: &[P],
+	mask: &[P],
+) -> Result<BlindedCommitOutput<P, VCS::Digest, MerkleProver::Committed>, Error>
+where
+	F: BinaryField,
+	FA: BinaryField,
+	P: PackedField<Scalar = F> + PackedExtension<FA, PackedSubfield = PA>,
+	PA: PackedField<Scalar = FA>,
+	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
+	VCS: MerkleTreeScheme<F>,
+{
+	let Some(padding_var) = params.blinding_padding_var() else {
+		bail!(Error::InvalidArgs(
+			"the parameters have no variable to hold the blinding padding".to_string()
+		));
+	};
+	let n_message_values = 1 << (params.n_fold_rounds() - 1);
+	if len_packed_slice(message) != n_message_values
+		|| len_packed_slice(padding) != n_message_values
+	{
+		bail!(Error::InvalidArgs(
+			"message and padding must each be half the code message length".to_string()
+		));
+	}
+	if mask.len() != 2 * message.len() {
+		bail!(Error::InvalidArgs("mask length must be the code message length".to_string()));
+	}
+
+	let mask_output = commit_interleaved(rs_code, params, merkle_prover, mask)?;
+	let padded = commit_interleaved_with(rs_code, params, merkle_prover, |buffer| {
+		let low_mask = (1 << padding_var) - 1;
+		for (i, dst) in buffer.iter_mut().enumerate() {
+			*dst = P::from_fn(|j| {
+				let index = (i << P::LOG_WIDTH) | j;
+				let src_index = (index >> (padding_var + 1) << padding_var) | (index & low_mask);
+				if (index >> padding_var) & 1 == 0 {
+					get_packed_slice(message, src_index)
+				} else {
+					get_packed_slice(padding, src_index)
+				}
+			});
+		}
+	})?;
+
+	Ok(BlindedCommitOutput {
+		padded,
+		mask: mask_output,
+	})
+}
+
+/// Encodes and commits the input message under two Reed–Solomon codes.
+///
+/// The codes must have the same dimension and the parameters the same batch size, but may differ
+/// in rate, evaluation domain, and fold schedule. Each codeword is then folded by its own
+/// [`FRIFolder`] with the same challenges, and since both encode the same message, the fully-folded
+/// values agree; see [`super::FRIVerifier::verify_dual_code`].
+///
+/// ## Arguments
+///
+/// * `rs_codes` - the Reed-Solomon codes to use for encoding
+/// * `params` - the FRI protocol parameters of each code.
+/// * `merkle_prover` - the Merkle tree prover to use for committing
+/// * `message` - the interleaved message to encode and commit
+#[allow(clippy::type_complexity)]
+#[instrument(skip_all, level = "debug")]
+pub fn commit_interleaved_dual_code<F, FA, P, PA, MerkleProver, VCS>(
+	rs_codes: [&ReedSolomonCode<PA>; 2],
+	params: [&FRIParams<F, FA>; 2],
+	merkle_prover: &MerkleProver,
+	message: &[P],
+) -> Result<[CommitOutput<P, VCS::Digest, MerkleProver::Committed>; 2], Error>
+where
+	F: BinaryField,
+	FA: BinaryField,
+	P: PackedField<Scalar = F> + PackedExtension<FA, PackedSubfield = PA>,
+	PA: PackedField<Scalar = FA>,
+	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
+	VCS: MerkleTreeScheme<F>,
+{
+	let [rs_code_0, rs_code_1] = rs_codes;
+	let [params_0, params_1] = params;
+	Ok([
+		commit_interleaved(rs_code_0, params_0, merkle_prover, message)?,
+		commit_interleaved(rs_code_1, params_1, merkle_prover, message)?,
+	])
+}
+
+/// Returns the codeword folded in a blinded FRI proof, `padded + batch_coeff * mask`.
+///
+/// `padded` and `mask` are the codewords committed by [`commit_interleaved_with_blinding`], and
+/// `batch_coeff` must be sampled after both commitments have been observed.
+pub fn batch_blinded_codeword<F: Field>(padded: &[F], mask: &[F], batch_coeff: F) -> Vec<F> {
+	padded
+		.par_iter()
+		.zip(mask.par_iter())
+		.map(|(&padded, &mask)| padded + batch_coeff * mask)
+		.collect()
+}
+
 pub enum FoldRoundOutput<VCSCommitment> {
 	NoCommitment,
 	Commitment(VCSCommitment),
@@ -273,11 +422,18 @@ where
 	params: &'a FRIParams<F, FA>,
 	merkle_prover: &'a MerkleProver,
 	codeword: &'a [F],
+	/// The committed codeword opened by the test queries, which differs from the folded codeword
+	/// only for a blinded codeword, whose padded codeword is opened.
+	committed_codeword: &'a [F],
 	codeword_committed: &'a MerkleProver::Committed,
+	/// The mask of a blinded codeword, opened alongside it in the first fold round.
+	mask: Option<(&'a [F], &'a MerkleProver::Committed)>,
 	round_committed: Vec<(Vec<F>, MerkleProver::Committed)>,
 	curr_round: usize,
 	next_commit_round: Option<usize>,
 	unprocessed_challenges: Vec<F>,
+	/// The codeword after every fold round, collected only when debugging is enabled.
+	round_codewords: Option<Vec<Vec<F>>>,
 }
 
 impl<'a, F, FA, MerkleProver, VCS> FRIFolder<'a, F, FA, MerkleProver, VCS>
@@ -305,14 +461,72 @@ where
 			params,
 			merkle_prover,
 			codeword: committed_codeword,
+			committed_codeword,
 			codeword_committed: committed,
+			mask: None,
 			round_committed: Vec::with_capacity(params.n_oracles()),
 			curr_round: 0,
 			next_commit_round,
 			unprocessed_challenges: Vec::with_capacity(params.rs_code().log_dim()),
+			round_codewords: None,
+		})
+	}
+
+	/// Constructs a folder for a codeword committed by [`commit_interleaved_with_blinding`].
+	///
+	/// `batched_codeword` is the padded codeword plus the batching coefficient times the mask, as
+	/// returned by [`batch_blinded_codeword`], and is folded in place of the padded codeword. The
+	/// test queries open the padded codeword and the mask at the same positions, so that the
+	/// verifier can recompute the batched values. The
+	/// [padding variable](FRIParams::blinding_padding_var) must be folded with a zero challenge.
+	///
+	/// The mask itself is proven by a separate folder created with [`Self::new`] and folded with
+	/// the same challenges, whose proof follows this one.
+	pub fn new_blinded(
+		params: &'a FRIParams<F, FA>,
+		merkle_prover: &'a MerkleProver,
+		batched_codeword: &'a [F],
+		padded: (&'a [F], &'a MerkleProver::Committed),
+		mask: (&'a [F], &'a MerkleProver::Committed),
+	) -> Result<Self, Error> {
+		if params.blinding_padding_var().is_none() {
+			bail!(Error::InvalidArgs(
+				"the parameters have no variable to hold the blinding padding".to_string()
+			));
+		}
+		if padded.0.len() != batched_codeword.len() || mask.0.len() != batched_codeword.len() {
+			bail!(Error::InvalidArgs(
+				"the padded, mask and batched codewords must have the same length".to_string()
+			));
+		}
+
+		let (committed_codeword, committed) = padded;
+		let folder = Self::new(params, merkle_prover, batched_codeword, committed)?;
+		Ok(Self {
+			committed_codeword,
+			mask: Some(mask),
+			..folder
 		})
 	}
 
+	/// Enables collection of the full folded codeword after every fold round, for debugging.
+	///
+	/// The folder normally skips the codewords of rounds without a commitment. With collection
+	/// enabled, every fold round additionally folds the previous round's codeword by its
+	/// challenge with the serial [`fold_full_round`], so that the intermediate codewords can be
+	/// compared against an independent computation. In the interleaving rounds, adjacent
+	/// codewords of the batch are linearly interpolated instead. This doubles the folding work and
+	/// keeps every codeword in memory, so it should not be enabled in production.
+	pub fn with_round_codewords(mut self) -> Self {
+		self.round_codewords = Some(Vec::with_capacity(self.n_rounds()));
+		self
+	}
+
+	/// The codewords collected after each fold round so far, if collection is enabled.
+	pub fn round_codewords(&self) -> Option<&[Vec<F>]> {
+		self.round_codewords.as_deref()
+	}
+
 	/// Number of fold rounds, including the final fold.
 	pub const fn n_rounds(&self) -> usize {
 		self.params.n_fold_rounds()
@@ -332,13 +546,27 @@ where
 	///
 	/// As a memory efficient optimization, this method may not actually do the folding, but instead accumulate the
 	/// folding challenge for processing at a later time. This saves us from storing intermediate folded codewords.
+	///
+	/// The fold of a commitment round cannot be pipelined with the Merkle commitment of the
+	/// previous one: the folding challenges for the next round are sampled only after the previous
+	/// round commitment is observed by the transcript. Parallelism is therefore exploited within a
+	/// round, by the parallel fold and the parallel Merkle tree construction.
 	#[instrument(skip_all, name = "fri::FRIFolder::execute_fold_round", level = "debug")]
 	pub fn execute_fold_round(
 		&mut self,
 		challenge: F,
 	) -> Result<FoldRoundOutput<VCS::Digest>, Error> {
+		if self.mask.is_some()
+			&& self.params.blinding_padding_var() == Some(self.curr_round)
+			&& challenge != F::ZERO
+		{
+			bail!(Error::InvalidArgs(
+				"the padding variable of a blinded codeword must be folded with zero".to_string()
+			));
+		}
 		self.unprocessed_challenges.push(challenge);
 		self.curr_round += 1;
+		self.collect_round_codeword(challenge);
 
 		if !self.is_commitment_round() {
 			return Ok(FoldRoundOutput::NoCommitment);
@@ -370,13 +598,13 @@ where
 		};
 		self.unprocessed_challenges.clear();
 
-		// take the first arity as coset_log_len, or use inv_rate if arities are empty
+		// take the next arity as coset_log_len, or the terminate coset size for the last oracle
 		let coset_size = self
 			.params
 			.fold_arities()
 			.get(self.round_committed.len() + 1)
 			.map(|log| 1 << log)
-			.unwrap_or_else(|| self.params.rs_code().inv_rate());
+			.unwrap_or_else(|| 1 << self.params.log_terminate_coset_size());
 
 		let (commitment, committed) = self
 			.merkle_prover
@@ -392,6 +620,73 @@ where
 		Ok(FoldRoundOutput::Commitment(commitment.root))
 	}
 
+	/// Executes all remaining fold rounds with the given challenges instead of sampling them.
+	///
+	/// This supports protocol variants, such as accumulation schemes, where the folding challenges
+	/// are fixed by a separate commitment rather than sampled from the transcript. The round
+	/// commitments are written to the transcript as usual. The verifier must check the challenges
+	/// against their commitment, see [`super::FRIVerifier::new_with_committed_challenges`].
+	///
+	/// ## Soundness
+	///
+	/// FRI is only sound if each folding challenge is chosen after the oracle it folds has been
+	/// committed. Nothing here binds the challenges to the round commitments, and the prover knows
+	/// all of them before committing any round oracle. This is **unsound** unless the challenges
+	/// are derived from an outer transcript that has already absorbed the codeword commitment and
+	/// is bound to the round commitments by the surrounding protocol.
+	pub fn execute_fold_rounds_with_challenges<Challenger_>(
+		&mut self,
+		challenges: &[F],
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<(), Error>
+	where
+		Challenger_: Challenger,
+	{
+		let n_remaining_rounds = self.params.n_fold_rounds() - self.curr_round;
+		if challenges.len() != n_remaining_rounds {
+			bail!(Error::InvalidArgs(format!(
+				"got {} folding challenges, expected {n_remaining_rounds}",
+				challenges.len(),
+			)));
+		}
+
+		for &challenge in challenges {
+			if let FoldRoundOutput::Commitment(round_commitment) =
+				self.execute_fold_round(challenge)?
+			{
+				transcript.message().write(&round_commitment);
+			}
+		}
+		Ok(())
+	}
+
+	fn collect_round_codeword(&mut self, challenge: F) {
+		let Some(round_codewords) = &mut self.round_codewords else {
+			return;
+		};
+
+		let prev_codeword = round_codewords
+			.last()
+			.map(Vec::as_slice)
+			.unwrap_or(self.codeword);
+		let log_batch_size = self.params.log_batch_size();
+		let codeword = if self.curr_round <= log_batch_size {
+			prev_codeword
+				.chunks_exact(2)
+				.map(|pair| pair[0] + challenge * (pair[1] - pair[0]))
+				.collect()
+		} else {
+			fold_full_round(
+				self.params.rs_code(),
+				self.curr_round - log_batch_size - 1,
+				1,
+				prev_codeword,
+				&[challenge],
+			)
+		};
+		round_codewords.push(codeword);
+	}
+
 	/// Finalizes the FRI folding process.
 	///
 	/// This step will process any unprocessed folding challenges to produce the
@@ -418,8 +713,9 @@ where
 
 		let Self {
 			params,
-			codeword,
+			committed_codeword,
 			codeword_committed,
+			mask,
 			round_committed,
 			merkle_prover,
 			..
@@ -427,8 +723,9 @@ where
 
 		let query_prover = FRIQueryProver {
 			params,
-			codeword,
+			codeword: committed_codeword,
 			codeword_committed,
+			mask,
 			round_committed,
 			merkle_prover,
 		};
@@ -444,15 +741,24 @@ where
 	{
 		let (terminate_codeword, query_prover) = self.finalize()?;
 		let mut advice = transcript.decommitment();
-		advice.write_scalar_slice(&terminate_codeword);
+		if query_prover.params.final_message_mode() == FinalMessageMode::Plaintext {
+			advice.write_scalar_slice(&terminate_codeword);
+		}
 
 		let layers = query_prover.vcs_optimal_layers()?;
 		for layer in layers {
 			advice.write_slice(&layer);
 		}
 
+		// With a committed final message, decommit the last oracle and open the final message.
+		if let Some(terminate_layer) = query_prover.vcs_terminate_layer()? {
+			advice.write_slice(&terminate_layer);
+			query_prover.prove_terminate_opening(0, &mut advice)?;
+		}
+
 		let params = query_prover.params;
 
+		transcript.reserve_output(params.n_test_queries() * params.index_bits().div_ceil(8));
 		for _ in 0..params.n_test_queries() {
 			let index = transcript.sample_bits(params.index_bits());
 			query_prover.prove_query(index, transcript.decommitment())?;
@@ -460,6 +766,20 @@ where
 
 		Ok(())
 	}
+
+	/// Same as [`Self::finish_proof`], but also returns the codewords collected after each fold
+	/// round if collection was enabled with [`Self::with_round_codewords`].
+	pub fn finish_proof_with_round_codewords<Challenger_>(
+		mut self,
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<Option<Vec<Vec<F>>>, Error>
+	where
+		Challenger_: Challenger,
+	{
+		let round_codewords = self.round_codewords.take();
+		self.finish_proof(transcript)?;
+		Ok(round_codewords)
+	}
 }
 
 /// A prover for the FRI query phase.
@@ -473,6 +793,7 @@ where
 	params: &'a FRIParams<F, FA>,
 	codeword: &'a [F],
 	codeword_committed: &'a MerkleProver::Committed,
+	mask: Option<(&'a [F], &'a MerkleProver::Committed)>,
 	round_committed: Vec<(Vec<F>, MerkleProver::Committed)>,
 	merkle_prover: &'a MerkleProver,
 }
@@ -528,6 +849,17 @@ where
 			first_optimal_layer_depth,
 			&mut advice,
 		)?;
+		if let Some((mask_codeword, mask_committed)) = self.mask {
+			prove_coset_opening(
+				self.merkle_prover,
+				mask_codeword,
+				mask_committed,
+				index,
+				first_fold_arity,
+				first_optimal_layer_depth,
+				&mut advice,
+			)?;
+		}
 
 		for ((codeword, committed), (arity, optimal_layer_depth)) in
 			izip!(self.round_committed.iter(), arities_and_optimal_layers_depths)
@@ -544,15 +876,57 @@ where
 			)?;
 		}
 
+		if self.params.final_message_mode() == FinalMessageMode::Committed {
+			self.prove_terminate_opening(index >> self.params.n_final_challenges(), &mut advice)?;
+		}
+
 		Ok(())
 	}
 
+	/// Opens a coset of the last oracle when the final message is committed.
+	///
+	/// Each coset of the last oracle folds to the final message with the final challenges.
+	fn prove_terminate_opening<B>(
+		&self,
+		coset_index: usize,
+		advice: &mut TranscriptWriter<B>,
+	) -> Result<(), Error>
+	where
+		B: BufMut,
+	{
+		let (codeword, committed) = self
+			.round_committed
+			.last()
+			.expect("a committed final message requires at least one oracle");
+		let layer_depth = vcs_terminate_layer_depth(self.params, self.merkle_prover.scheme())
+			.expect("the final message is committed");
+		prove_coset_opening(
+			self.merkle_prover,
+			codeword,
+			committed,
+			coset_index,
+			self.params.n_final_challenges(),
+			layer_depth,
+			advice,
+		)
+	}
+
+	/// The Merkle layers to decommit for each oracle, in the order in which they are opened.
+	///
+	/// The layer of the mask of a blinded codeword follows the layer of the codeword.
 	pub fn vcs_optimal_layers(&self) -> Result<Vec<Vec<VCS::Digest>>, Error> {
 		let committed_iter = std::iter::once(self.codeword_committed)
+			.chain(self.mask.map(|(_, committed)| committed))
 			.chain(self.round_committed.iter().map(|(_, committed)| committed));
+		let mut layer_depths =
+			vcs_optimal_layers_depths_iter(self.params, self.merkle_prover.scheme())
+				.collect::<Vec<_>>();
+		if self.mask.is_some() {
+			layer_depths.insert(1, layer_depths[0]);
+		}
 
 		committed_iter
-			.zip(vcs_optimal_layers_depths_iter(self.params, self.merkle_prover.scheme()))
+			.zip(layer_depths)
 			.map(|(committed, optimal_layer_depth)| {
 				self.merkle_prover
 					.layer(committed, optimal_layer_depth)
@@ -561,6 +935,22 @@ where
 			})
 			.collect::<Result<Vec<_>, _>>()
 	}
+
+	/// The Merkle layer of the last oracle to decommit when the final message is committed.
+	pub fn vcs_terminate_layer(&self) -> Result<Option<Vec<VCS::Digest>>, Error> {
+		let Some(layer_depth) = vcs_terminate_layer_depth(self.params, self.merkle_prover.scheme())
+		else {
+			return Ok(None);
+		};
+		let (_, committed) = self
+			.round_committed
+			.last()
+			.expect("a committed final message requires at least one oracle");
+		self.merkle_prover
+			.layer(committed, layer_depth)
+			.map(|layer| Some(layer.to_vec()))
+			.map_err(|err| Error::VectorCommit(Box::new(err)))
+	}
 }
 
 fn prove_coset_opening<F, MTProver, B>(
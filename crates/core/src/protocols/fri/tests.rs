@@ -1,12 +1,17 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{iter::repeat_with, vec};
+use std::{
+	array, io,
+	iter::{self, repeat_with},
+	vec,
+};
 
+use assert_matches::assert_matches;
 use binius_field::{
 	arch::{packed_64::PackedBinaryField4x16b, OptimalUnderlier128b},
 	as_packed_field::{PackScalar, PackedType},
 	underlier::UnderlierType,
-	BinaryField, BinaryField128b, BinaryField16b, BinaryField32b, ExtensionField,
+	BinaryField, BinaryField128b, BinaryField16b, BinaryField32b, ExtensionField, Field,
 	PackedBinaryField16x16b, PackedField, PackedFieldIndexable, TowerField,
 };
 use binius_hal::{make_portable_backend, ComputationBackendExt};
@@ -14,19 +19,24 @@ use binius_hash::compress::Groestl256ByteCompression;
 use binius_math::MultilinearExtension;
 use binius_maybe_rayon::prelude::ParallelIterator;
 use binius_ntt::NTTOptions;
+use bytes::Buf;
 use groestl_crypto::Groestl256;
+use itertools::izip;
 use rand::prelude::*;
 
-use super::to_par_scalar_big_chunks;
+use super::{common::vcs_optimal_layers_depths_iter, to_par_scalar_big_chunks};
 use crate::{
-	fiat_shamir::{CanSample, HasherChallenger},
-	merkle_tree::BinaryMerkleTreeProver,
+	fiat_shamir::{CanSample, CanSampleBits, HasherChallenger},
+	merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver},
 	protocols::fri::{
-		self, to_par_scalar_small_chunks, CommitOutput, FRIFolder, FRIParams, FRIVerifier,
-		FoldRoundOutput,
+		self, to_par_scalar_small_chunks, BatchFriVerifier, BlindedCommitOutput, CommitOutput,
+		Error, FRIBatchClaim, FRIFolder, FRIParams, FRIVerifier, FinalMessageMode, FoldRoundOutput,
+		TerminateCodeword, VerificationError,
 	},
 	reed_solomon::reed_solomon::ReedSolomonCode,
-	transcript::ProverTranscript,
+	transcript::{
+		test_utils::assert_transcript_deterministic, ProverTranscript, VerifierTranscript,
+	},
 };
 
 fn test_commit_prove_verify_success<U, F, FA>(
@@ -40,6 +50,30 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	FA: BinaryField,
 	PackedType<U, F>: PackedFieldIndexable,
 	PackedType<U, FA>: PackedFieldIndexable,
+{
+	commit_prove_verify::<U, F, FA>(
+		log_dimension,
+		log_inv_rate,
+		log_batch_size,
+		arities,
+		FinalMessageMode::Plaintext,
+	);
+}
+
+/// Runs the FRI protocol end to end, returning the terminate codeword and the proof.
+fn commit_prove_verify<U, F, FA>(
+	log_dimension: usize,
+	log_inv_rate: usize,
+	log_batch_size: usize,
+	arities: &[usize],
+	final_message_mode: FinalMessageMode,
+) -> (TerminateCodeword<F>, Vec<u8>)
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<FA>,
+	F: TowerField + ExtensionField<FA> + PackedField<Scalar = F>,
+	FA: BinaryField,
+	PackedType<U, F>: PackedFieldIndexable,
+	PackedType<U, FA>: PackedFieldIndexable,
 {
 	let mut rng = StdRng::seed_from_u64(0);
 
@@ -58,6 +92,8 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	let n_test_queries = 3;
 	let params =
 		FRIParams::new(committed_rs_code, log_batch_size, arities.to_vec(), n_test_queries)
+			.unwrap()
+			.with_final_message_mode(final_message_mode)
 			.unwrap();
 
 	let n_round_commitments = arities.len();
@@ -81,7 +117,8 @@ fn test_commit_prove_verify_success<U, F, FA>(
 		<PackedType<U, F>>::unpack_scalars(&codeword),
 		&codeword_committed,
 	)
-	.unwrap();
+	.unwrap()
+	.with_round_codewords();
 
 	let mut prover_challenger = ProverTranscript::<HasherChallenger<Groestl256>>::new();
 	prover_challenger.message().write(&codeword_commitment);
@@ -98,9 +135,19 @@ fn test_commit_prove_verify_success<U, F, FA>(
 		}
 	}
 
-	round_prover.finish_proof(&mut prover_challenger).unwrap();
+	let round_codewords = round_prover
+		.finish_proof_with_round_codewords(&mut prover_challenger)
+		.unwrap()
+		.unwrap();
+	let terminate_codeword = match arities.iter().sum::<usize>() {
+		0 => <PackedType<U, F>>::unpack_scalars(&codeword).to_vec(),
+		last_commit_round => round_codewords[last_commit_round - 1].clone(),
+	};
+	let proof = prover_challenger.finalize();
+
 	// Now run the verifier
-	let mut verifier_challenger = prover_challenger.into_verifier();
+	let mut verifier_challenger =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
 	codeword_commitment = verifier_challenger.message().read().unwrap();
 	let mut verifier_challenges = Vec::with_capacity(params.n_fold_rounds());
 
@@ -134,8 +181,13 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	)
 	.unwrap();
 
+	// The FRI proof is the rest of the transcript, and its size matches the bound exactly.
+	assert_eq!(verifier_challenger.decommitment().buffer().remaining(), verifier.max_proof_size());
+
 	let final_fri_value = verifier.verify(&mut verifier_challenger).unwrap();
 	assert_eq!(computed_eval, final_fri_value);
+
+	(terminate_codeword, proof)
 }
 
 #[test]
@@ -214,6 +266,52 @@ fn test_commit_prove_verify_success_without_folding() {
 	);
 }
 
+#[test]
+fn test_commit_prove_verify_committed_final_message() {
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let log_batch_size = 2;
+
+	for arities in [&[3, 2, 1][..], &[2, 1, 1, 1, 1, 1], &[4, 3]] {
+		let prove = |final_message_mode| {
+			commit_prove_verify::<OptimalUnderlier128b, BinaryField128b, BinaryField16b>(
+				log_dimension,
+				log_inv_rate,
+				log_batch_size,
+				arities,
+				final_message_mode,
+			)
+		};
+
+		let contains = |proof: &[u8], terminate_codeword: &[BinaryField128b]| {
+			let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			transcript
+				.decommitment()
+				.write_scalar_slice(terminate_codeword);
+			let plaintext = transcript.finalize();
+			proof
+				.windows(plaintext.len())
+				.any(|window| window == plaintext)
+		};
+
+		let (terminate_codeword, proof) = prove(FinalMessageMode::Plaintext);
+		assert!(contains(&proof, &terminate_codeword));
+
+		let (terminate_codeword, proof) = prove(FinalMessageMode::Committed);
+		assert!(!contains(&proof, &terminate_codeword));
+	}
+}
+
+#[test]
+fn test_committed_final_message_requires_oracles() {
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(4, 2, &NTTOptions::default()).unwrap();
+	let params = FRIParams::<BinaryField128b, _>::new(rs_code, 2, vec![], 3).unwrap();
+	assert_matches!(
+		params.with_final_message_mode(FinalMessageMode::Committed),
+		Err(fri::Error::InvalidArgs(_))
+	);
+}
+
 #[test]
 fn test_parallel_iterator_for_commitments() {
 	// Compare results for small and large chunk sizes to ensure that theyre identical
@@ -261,3 +359,1559 @@ fn test_parallel_iterator_for_commitments() {
 
 	assert_eq!(collected_smaller, collected_bigger);
 }
+
+#[test]
+fn test_verify_last_oracle_rejects_wrong_length() {
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let arities = vec![3, 2];
+
+	let merkle_prover =
+		BinaryMerkleTreeProver::<BinaryField128b, Groestl256, _>::new(Groestl256ByteCompression);
+	let rs_code =
+		ReedSolomonCode::<BinaryField16b>::new(log_dimension, log_inv_rate, &NTTOptions::default())
+			.unwrap();
+	let params = FRIParams::new(rs_code, 0, arities, 1).unwrap();
+
+	let codeword_commitment = Default::default();
+	let round_commitments = vec![Default::default(); params.n_oracles()];
+	let challenges = vec![BinaryField128b::default(); params.n_fold_rounds()];
+	let verifier = FRIVerifier::new(
+		&params,
+		merkle_prover.scheme(),
+		&codeword_commitment,
+		&round_commitments,
+		&challenges,
+	)
+	.unwrap();
+
+	let terminate_codeword = vec![BinaryField128b::default(); params.terminate_codeword_len() - 1];
+	assert_matches!(
+		verifier.verify_last_oracle(&terminate_codeword),
+		Err(fri::Error::Verification(VerificationError::IncorrectTerminateCodewordLength {
+			expected
+		})) if expected == params.terminate_codeword_len()
+	);
+}
+
+#[test]
+fn test_verify_query_phase_rejects_tampered_coset_value() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let arities = vec![3, 2];
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
+		log_dimension,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, 0, arities, 3).unwrap();
+
+	let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take(committed_rs_code_packed.dim() >> <PackedType<U, F>>::LOG_WIDTH)
+		.collect::<Vec<_>>();
+
+	let CommitOutput {
+		commitment: codeword_commitment,
+		committed: codeword_committed,
+		codeword,
+	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+
+	let mut round_prover = FRIFolder::new(
+		&params,
+		&merkle_prover,
+		<PackedType<U, F>>::unpack_scalars(&codeword),
+		&codeword_committed,
+	)
+	.unwrap();
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	prover_transcript.message().write(&codeword_commitment);
+	for _ in 0..params.n_fold_rounds() {
+		let challenge = prover_transcript.sample();
+		if let FoldRoundOutput::Commitment(round_commitment) =
+			round_prover.execute_fold_round(challenge).unwrap()
+		{
+			prover_transcript.message().write(&round_commitment);
+		}
+	}
+	round_prover.finish_proof(&mut prover_transcript).unwrap();
+	let proof = prover_transcript.finalize();
+
+	// Replays the verifier up to the query phase and runs it, returning the result together with
+	// the offset of the first query opening in the proof.
+	let run_query_phase = |proof: Vec<u8>| {
+		let proof_len = proof.len();
+		let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		let codeword_commitment = transcript.message().read().unwrap();
+		let mut challenges = Vec::with_capacity(params.n_fold_rounds());
+		let mut round_commitments = Vec::with_capacity(params.n_oracles());
+		for &arity in params.fold_arities() {
+			challenges.append(&mut transcript.sample_vec(arity));
+			round_commitments.push(transcript.message().read().unwrap());
+		}
+		challenges.append(&mut transcript.sample_vec(params.n_final_challenges()));
+
+		let verifier = FRIVerifier::new(
+			&params,
+			merkle_prover.scheme(),
+			&codeword_commitment,
+			&round_commitments,
+			&challenges,
+		)
+		.unwrap();
+
+		let mut advice = transcript.decommitment();
+		let terminate_codeword = advice
+			.read_scalar_slice(params.terminate_codeword_len())
+			.unwrap();
+		let layers = vcs_optimal_layers_depths_iter(&params, merkle_prover.scheme())
+			.map(|layer_depth| advice.read_vec(1 << layer_depth))
+			.collect::<Result<Vec<_>, _>>()
+			.unwrap();
+		let query_offset = proof_len - advice.buffer().remaining();
+
+		let result = verifier.verify_query_phase(&mut transcript, &terminate_codeword, &layers);
+		(result, query_offset)
+	};
+
+	let (result, query_offset) = run_query_phase(proof.clone());
+	assert!(result.is_ok());
+
+	// Corrupt the first value of the coset opened by the first query in the first round.
+	let mut tampered_proof = proof.clone();
+	tampered_proof[query_offset] ^= 1;
+	let (result, _) = run_query_phase(tampered_proof);
+	assert_matches!(
+		result,
+		Err(fri::Error::QueryFailed {
+			query: 0,
+			round: 0,
+			..
+		})
+	);
+
+	// Cut the proof off in the middle of the first coset opening.
+	let mut truncated_proof = proof;
+	truncated_proof.truncate(query_offset + 1);
+	let (result, _) = run_query_phase(truncated_proof);
+	assert_matches!(
+		result,
+		Err(fri::Error::QueryFailed {
+			query: 0,
+			round: 0,
+			..
+		})
+	);
+}
+
+#[test]
+fn test_parallel_query_phase_matches_sequential() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let arities = vec![3, 2];
+	let n_test_queries = 8;
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
+		log_dimension,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, 0, arities, n_test_queries).unwrap();
+
+	let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take(committed_rs_code_packed.dim() >> <PackedType<U, F>>::LOG_WIDTH)
+		.collect::<Vec<_>>();
+
+	let CommitOutput {
+		commitment: codeword_commitment,
+		committed: codeword_committed,
+		codeword,
+	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+
+	let mut round_prover = FRIFolder::new(
+		&params,
+		&merkle_prover,
+		<PackedType<U, F>>::unpack_scalars(&codeword),
+		&codeword_committed,
+	)
+	.unwrap();
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	prover_transcript.message().write(&codeword_commitment);
+	for _ in 0..params.n_fold_rounds() {
+		let challenge = prover_transcript.sample();
+		if let FoldRoundOutput::Commitment(round_commitment) =
+			round_prover.execute_fold_round(challenge).unwrap()
+		{
+			prover_transcript.message().write(&round_commitment);
+		}
+	}
+	round_prover.finish_proof(&mut prover_transcript).unwrap();
+	let proof = prover_transcript.finalize();
+
+	// Replays the verifier up to the query phase and runs it, returning the result together with
+	// the offset of the first query opening in the proof.
+	let run_query_phase = |proof: Vec<u8>, parallel: bool| {
+		let proof_len = proof.len();
+		let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		let codeword_commitment = transcript.message().read().unwrap();
+		let mut challenges = Vec::with_capacity(params.n_fold_rounds());
+		let mut round_commitments = Vec::with_capacity(params.n_oracles());
+		for &arity in params.fold_arities() {
+			challenges.append(&mut transcript.sample_vec(arity));
+			round_commitments.push(transcript.message().read().unwrap());
+		}
+		challenges.append(&mut transcript.sample_vec(params.n_final_challenges()));
+
+		let verifier = FRIVerifier::new(
+			&params,
+			merkle_prover.scheme(),
+			&codeword_commitment,
+			&round_commitments,
+			&challenges,
+		)
+		.unwrap();
+
+		let mut advice = transcript.decommitment();
+		let terminate_codeword = advice
+			.read_scalar_slice(params.terminate_codeword_len())
+			.unwrap();
+		let layers = vcs_optimal_layers_depths_iter(&params, merkle_prover.scheme())
+			.map(|layer_depth| advice.read_vec(1 << layer_depth))
+			.collect::<Result<Vec<_>, _>>()
+			.unwrap();
+		let query_offset = proof_len - advice.buffer().remaining();
+
+		let result = if parallel {
+			verifier.verify_query_phase_parallel(&mut transcript, &terminate_codeword, &layers)
+		} else {
+			verifier.verify_query_phase(&mut transcript, &terminate_codeword, &layers)
+		};
+		if result.is_ok() {
+			transcript.finalize().unwrap();
+		}
+		(format!("{result:?}"), query_offset)
+	};
+
+	let (sequential, query_offset) = run_query_phase(proof.clone(), false);
+	assert_eq!(sequential, "Ok(())");
+	assert_eq!(run_query_phase(proof.clone(), true), (sequential, query_offset));
+
+	// A rejected proof may leave different amounts of advice unread, but the reported error must be
+	// the same. Tamper with bytes in the openings of different queries and rounds, and cut the proof
+	// off at different points.
+	let query_proof_size = (proof.len() - query_offset) / n_test_queries;
+	let tampered_offsets = [
+		query_offset,
+		query_offset + query_proof_size / 2,
+		query_offset + 3 * query_proof_size + 17,
+		proof.len() - 1,
+	];
+	for offset in tampered_offsets {
+		let mut tampered_proof = proof.clone();
+		tampered_proof[offset] ^= 1;
+		let sequential = run_query_phase(tampered_proof.clone(), false);
+		assert!(sequential.0.starts_with("Err(QueryFailed"));
+		assert_eq!(run_query_phase(tampered_proof, true), sequential);
+	}
+
+	let mut tampered_proof = proof.clone();
+	tampered_proof[query_offset + 2 * query_proof_size] ^= 1;
+	tampered_proof[query_offset + 5 * query_proof_size] ^= 1;
+	let sequential = run_query_phase(tampered_proof.clone(), false);
+	assert!(sequential.0.starts_with("Err(QueryFailed { query: 2,"));
+	assert_eq!(run_query_phase(tampered_proof, true), sequential);
+
+	for len in [
+		query_offset + 1,
+		query_offset + 4 * query_proof_size,
+		proof.len() - 1,
+	] {
+		let mut truncated_proof = proof.clone();
+		truncated_proof.truncate(len);
+		let sequential = run_query_phase(truncated_proof.clone(), false);
+		assert!(sequential.0.starts_with("Err(QueryFailed"));
+		assert_eq!(run_query_phase(truncated_proof, true), sequential);
+	}
+}
+
+#[test]
+fn test_reconstruct_codeword_value() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let log_batch_size = 1;
+	let arities = vec![3, 2];
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
+		log_dimension,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, log_batch_size, arities, 3).unwrap();
+
+	let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take((committed_rs_code_packed.dim() << log_batch_size) >> <PackedType<U, F>>::LOG_WIDTH)
+		.collect::<Vec<_>>();
+
+	let CommitOutput {
+		commitment: codeword_commitment,
+		committed: codeword_committed,
+		codeword,
+	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+	let codeword = <PackedType<U, F>>::unpack_scalars(&codeword);
+
+	let round_commitments = vec![codeword_commitment; params.n_oracles()];
+	let challenges = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(params.n_fold_rounds())
+		.collect::<Vec<_>>();
+	let verifier = FRIVerifier::new(
+		&params,
+		merkle_prover.scheme(),
+		&codeword_commitment,
+		&round_commitments,
+		&challenges,
+	)
+	.unwrap();
+
+	let layer_depth = vcs_optimal_layers_depths_iter(&params, merkle_prover.scheme())
+		.next()
+		.unwrap();
+	let layer = merkle_prover
+		.layer(&codeword_committed, layer_depth)
+		.unwrap()
+		.to_vec();
+
+	// Write the opening of the leaf holding `query_index` the way the prover does.
+	let query_index = 37;
+	let log_coset_size = params.fold_arities()[0] - log_batch_size;
+	let coset_index = query_index >> log_coset_size;
+	let leaf_len = 1 << params.fold_arities()[0];
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let mut advice = prover_transcript.decommitment();
+	advice.write_scalar_slice(&codeword[coset_index * leaf_len..(coset_index + 1) * leaf_len]);
+	merkle_prover
+		.prove_opening(&codeword_committed, layer_depth, coset_index, &mut advice)
+		.unwrap();
+	let proof = prover_transcript.finalize();
+
+	// The interleaved symbols at `query_index` are combined with the tensor of the interleaving
+	// challenge, which is (1 - r, r) for a batch of two.
+	let interleave_challenge = challenges[0];
+	let expected = codeword[query_index << log_batch_size] * (F::ONE - interleave_challenge)
+		+ codeword[(query_index << log_batch_size) + 1] * interleave_challenge;
+
+	let mut verifier_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
+	let value = verifier
+		.reconstruct_codeword_value(query_index, &layer, &mut verifier_transcript.decommitment())
+		.unwrap();
+	assert_eq!(value, expected);
+
+	// Tampering with the opened leaf fails the Merkle check.
+	let mut tampered_proof = proof;
+	tampered_proof[0] ^= 1;
+	let mut verifier_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(tampered_proof);
+	assert_matches!(
+		verifier.reconstruct_codeword_value(
+			query_index,
+			&layer,
+			&mut verifier_transcript.decommitment()
+		),
+		Err(fri::Error::VectorCommit(_))
+	);
+}
+
+#[test]
+fn test_fri_proof_is_deterministic() {
+	binius_utils::rayon::adjust_thread_pool();
+
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 10;
+	let log_inv_rate = 2;
+	let log_batch_size = 2;
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
+		log_dimension,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, log_batch_size, vec![3, 2, 2], 8).unwrap();
+
+	assert_transcript_deterministic::<HasherChallenger<Groestl256>, _>(|transcript| {
+		let mut rng = StdRng::seed_from_u64(0);
+		let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+			.take(committed_rs_code_packed.dim() << log_batch_size >> <PackedType<U, F>>::LOG_WIDTH)
+			.collect::<Vec<_>>();
+
+		let CommitOutput {
+			commitment,
+			committed,
+			codeword,
+		} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+
+		let mut round_prover = FRIFolder::new(
+			&params,
+			&merkle_prover,
+			<PackedType<U, F>>::unpack_scalars(&codeword),
+			&committed,
+		)
+		.unwrap();
+
+		transcript.message().write(&commitment);
+		for _ in 0..params.n_fold_rounds() {
+			let challenge = transcript.sample();
+			if let FoldRoundOutput::Commitment(round_commitment) =
+				round_prover.execute_fold_round(challenge).unwrap()
+			{
+				transcript.message().write(&round_commitment);
+			}
+		}
+		round_prover.finish_proof(transcript).unwrap();
+	});
+}
+
+#[test]
+fn test_check_proof_size() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 8;
+	let log_inv_rate = 1;
+	let log_batch_size = 1;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
+		log_dimension,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, log_batch_size, vec![2, 2], 4).unwrap();
+
+	let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take(committed_rs_code_packed.dim() << log_batch_size >> <PackedType<U, F>>::LOG_WIDTH)
+		.collect::<Vec<_>>();
+	let CommitOutput {
+		commitment,
+		committed,
+		codeword,
+	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+	let mut round_prover = FRIFolder::new(
+		&params,
+		&merkle_prover,
+		<PackedType<U, F>>::unpack_scalars(&codeword),
+		&committed,
+	)
+	.unwrap();
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let mut challenges = Vec::with_capacity(params.n_fold_rounds());
+	let mut round_commitments = Vec::with_capacity(params.n_oracles());
+	for _ in 0..params.n_fold_rounds() {
+		let challenge = prover_transcript.sample();
+		challenges.push(challenge);
+		if let FoldRoundOutput::Commitment(round_commitment) =
+			round_prover.execute_fold_round(challenge).unwrap()
+		{
+			round_commitments.push(round_commitment);
+		}
+	}
+	round_prover.finish_proof(&mut prover_transcript).unwrap();
+	let proof = prover_transcript.finalize();
+
+	let verifier_for = |proof: Vec<u8>| {
+		// The proof is read lazily, so its length is known before any of it is read.
+		let mut transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::from_reader(io::Cursor::new(proof))
+				.unwrap();
+		for _ in 0..params.n_fold_rounds() {
+			let _: F = transcript.sample();
+		}
+		let verifier = FRIVerifier::new(
+			&params,
+			merkle_prover.scheme(),
+			&commitment,
+			&round_commitments,
+			&challenges,
+		)
+		.unwrap();
+		assert_eq!(
+			verifier.max_proof_size(),
+			fri::max_valid_proof_size(&params, 0)
+				+ 32 * {
+					// Layer digests plus one branch per query below each layer.
+					let depths = [params.log_len() - 2, params.log_len() - 4];
+					depths
+						.iter()
+						.map(|&depth| (1 << 2) + params.n_test_queries() * (depth - 2))
+						.sum::<usize>()
+				}
+		);
+		(verifier, transcript)
+	};
+
+	let proof_size = proof.len();
+	let (verifier, mut transcript) = verifier_for(proof.clone());
+	assert_eq!(transcript.decommitment().buffer().remaining(), proof_size);
+	verifier.check_proof_size(proof_size).unwrap();
+	verifier.verify(&mut transcript).unwrap();
+	transcript.finalize().unwrap();
+
+	// A proof one byte longer than the bound is rejected before any of it is read.
+	let mut oversized_proof = proof;
+	oversized_proof.push(0);
+	let (verifier, mut transcript) = verifier_for(oversized_proof);
+	let size = transcript.decommitment().buffer().remaining();
+	assert_matches!(
+		verifier.check_proof_size(size),
+		Err(Error::Verification(VerificationError::ProofTooLarge { size, max_size }))
+			if size == proof_size + 1 && max_size == proof_size
+	);
+}
+
+#[test]
+fn test_fold_challenge_stream_matches_eager_challenges() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let log_batch_size = 1;
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
+		log_dimension,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, log_batch_size, vec![3, 2], 3).unwrap();
+
+	let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take((committed_rs_code_packed.dim() << log_batch_size) >> <PackedType<U, F>>::LOG_WIDTH)
+		.collect::<Vec<_>>();
+	let CommitOutput {
+		commitment: codeword_commitment,
+		committed: codeword_committed,
+		codeword,
+	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+
+	let mut round_prover = FRIFolder::new(
+		&params,
+		&merkle_prover,
+		<PackedType<U, F>>::unpack_scalars(&codeword),
+		&codeword_committed,
+	)
+	.unwrap();
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	for _ in 0..params.n_fold_rounds() {
+		let challenge = prover_transcript.sample();
+		if let FoldRoundOutput::Commitment(round_commitment) =
+			round_prover.execute_fold_round(challenge).unwrap()
+		{
+			prover_transcript.message().write(&round_commitment);
+		}
+	}
+	round_prover.finish_proof(&mut prover_transcript).unwrap();
+	let proof = prover_transcript.finalize();
+
+	// Collect all challenges and round commitments eagerly.
+	let mut eager_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
+	let mut eager_challenges = Vec::<F>::with_capacity(params.n_fold_rounds());
+	let mut eager_round_commitments = Vec::with_capacity(params.n_oracles());
+	for &arity in params.fold_arities() {
+		eager_challenges.append(&mut eager_transcript.sample_vec(arity));
+		eager_round_commitments.push(eager_transcript.message().read().unwrap());
+	}
+	eager_challenges.append(&mut eager_transcript.sample_vec(params.n_final_challenges()));
+	let eager_verifier = FRIVerifier::new(
+		&params,
+		merkle_prover.scheme(),
+		&codeword_commitment,
+		&eager_round_commitments,
+		&eager_challenges,
+	)
+	.unwrap();
+	let eager_value = eager_verifier.verify(&mut eager_transcript).unwrap();
+
+	// Pull the challenges one at a time, folding the message by one variable per challenge.
+	let mut streamed_transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+	let mut stream = fri::FoldChallengeStream::new(&params, &mut streamed_transcript);
+	let mut folded_msg = <PackedType<U, F>>::unpack_scalars(&msg).to_vec();
+	let mut streamed_challenges = Vec::with_capacity(params.n_fold_rounds());
+	for challenge in stream.by_ref() {
+		let challenge = challenge.unwrap();
+		folded_msg = folded_msg
+			.chunks(2)
+			.map(|pair| pair[0] + challenge * (pair[1] - pair[0]))
+			.collect();
+		streamed_challenges.push(challenge);
+	}
+	let streamed_round_commitments = stream.into_round_commitments();
+	assert_eq!(streamed_challenges, eager_challenges);
+	assert_eq!(streamed_round_commitments, eager_round_commitments);
+
+	let streamed_verifier = FRIVerifier::new(
+		&params,
+		merkle_prover.scheme(),
+		&codeword_commitment,
+		&streamed_round_commitments,
+		&streamed_challenges,
+	)
+	.unwrap();
+	let streamed_value = streamed_verifier.verify(&mut streamed_transcript).unwrap();
+	assert_eq!(streamed_value, eager_value);
+	assert_eq!(folded_msg, vec![eager_value]);
+}
+
+#[test]
+fn test_committed_challenges_fold_matches_sampled_fold() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let log_batch_size = 1;
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
+		log_dimension,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, log_batch_size, vec![3, 2], 3).unwrap();
+
+	let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take((committed_rs_code_packed.dim() << log_batch_size) >> <PackedType<U, F>>::LOG_WIDTH)
+		.collect::<Vec<_>>();
+	let CommitOutput {
+		commitment: codeword_commitment,
+		committed: codeword_committed,
+		codeword,
+	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+	let new_folder = || {
+		FRIFolder::new(
+			&params,
+			&merkle_prover,
+			<PackedType<U, F>>::unpack_scalars(&codeword),
+			&codeword_committed,
+		)
+		.unwrap()
+	};
+
+	// Fold with challenges sampled from the transcript.
+	let mut sampled_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let mut round_prover = new_folder();
+	let mut challenges = Vec::with_capacity(params.n_fold_rounds());
+	let mut sampled_round_commitments = Vec::with_capacity(params.n_oracles());
+	for _ in 0..params.n_fold_rounds() {
+		let challenge = sampled_transcript.sample();
+		challenges.push(challenge);
+		if let FoldRoundOutput::Commitment(round_commitment) =
+			round_prover.execute_fold_round(challenge).unwrap()
+		{
+			sampled_transcript.message().write(&round_commitment);
+			sampled_round_commitments.push(round_commitment);
+		}
+	}
+	let (sampled_terminate_codeword, _) = round_prover.finalize().unwrap();
+
+	// Fold with the same challenges taken from a committed vector.
+	let (challenges_commitment, _) = merkle_prover.commit(&challenges, challenges.len()).unwrap();
+	let fold_with_committed_challenges = |transcript: &mut ProverTranscript<_>| {
+		let mut round_prover = new_folder();
+		round_prover
+			.execute_fold_rounds_with_challenges(&challenges, transcript)
+			.unwrap();
+		round_prover
+	};
+	let mut committed_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let (committed_terminate_codeword, _) =
+		fold_with_committed_challenges(&mut ProverTranscript::new())
+			.finalize()
+			.unwrap();
+	assert_eq!(committed_terminate_codeword, sampled_terminate_codeword);
+	fold_with_committed_challenges(&mut committed_transcript)
+		.finish_proof(&mut committed_transcript)
+		.unwrap();
+
+	let mut verifier_transcript = committed_transcript.into_verifier();
+	let round_commitments = (0..params.n_oracles())
+		.map(|_| verifier_transcript.message().read().unwrap())
+		.collect::<Vec<_>>();
+	assert_eq!(round_commitments, sampled_round_commitments);
+	let verifier = FRIVerifier::new_with_committed_challenges(
+		&params,
+		merkle_prover.scheme(),
+		&codeword_commitment,
+		&round_commitments,
+		&challenges,
+		&challenges_commitment.root,
+	)
+	.unwrap();
+	let final_value = verifier.verify(&mut verifier_transcript).unwrap();
+
+	let backend = make_portable_backend();
+	let eval_query = backend.multilinear_query::<F>(&challenges).unwrap();
+	let multilin = MultilinearExtension::from_values_slice(&msg).unwrap();
+	assert_eq!(multilin.evaluate(&eval_query).unwrap(), final_value);
+
+	// Challenges that differ from the committed ones are rejected.
+	let mut wrong_challenges = challenges.clone();
+	wrong_challenges[0] += F::ONE;
+	assert_matches!(
+		FRIVerifier::new_with_committed_challenges(
+			&params,
+			merkle_prover.scheme(),
+			&codeword_commitment,
+			&round_commitments,
+			&wrong_challenges,
+			&challenges_commitment.root,
+		),
+		Err(fri::Error::VectorCommit(_))
+	);
+}
+
+#[test]
+fn test_fold_full_round_matches_fold_codeword() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(8, 2, &NTTOptions::default()).unwrap();
+	let codeword = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+		.take(1 << rs_code.log_len())
+		.collect::<Vec<_>>();
+
+	for (start_round, arity) in [(0, 1), (0, 3), (2, 2), (5, 3)] {
+		let challenges = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+			.take(arity)
+			.collect::<Vec<_>>();
+		let expected = fri::fold_codeword(&rs_code, &codeword, start_round + arity, &challenges);
+		let folded = fri::fold_full_round(&rs_code, start_round, arity, &codeword, &challenges);
+		assert_eq!(folded, expected);
+	}
+}
+
+#[test]
+fn test_fold_with_subspace_eval_cache_matches_ntt() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(8, 2, &NTTOptions::default()).unwrap();
+	let cached_code = ReedSolomonCode::<BinaryField16b>::new(8, 2, &NTTOptions::default())
+		.unwrap()
+		.with_subspace_eval_cache();
+
+	for (start_round, arity) in [(0, 1), (0, 3), (2, 2), (5, 3)] {
+		// The codeword folded at `start_round` has `2^(log_len - start_round)` values.
+		let codeword = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+			.take(1 << (rs_code.log_len() - start_round))
+			.collect::<Vec<_>>();
+		let challenges = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+			.take(arity)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			fri::fold_full_round(&cached_code, start_round, arity, &codeword, &challenges),
+			fri::fold_full_round(&rs_code, start_round, arity, &codeword, &challenges)
+		);
+	}
+}
+
+#[test]
+fn test_arity_one_folds_match_higher_arity_fold() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(8, 2, &NTTOptions::default()).unwrap();
+	let codeword = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+		.take(1 << rs_code.log_len())
+		.collect::<Vec<_>>();
+
+	for (start_round, arity) in [(0, 2), (0, 4), (3, 3)] {
+		let challenges = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+			.take(arity)
+			.collect::<Vec<_>>();
+		let expected = fri::fold_full_round(&rs_code, start_round, arity, &codeword, &challenges);
+		let folded =
+			challenges
+				.iter()
+				.enumerate()
+				.fold(codeword.clone(), |codeword, (i, &challenge)| {
+					fri::fold_full_round(&rs_code, start_round + i, 1, &codeword, &[challenge])
+				});
+		assert_eq!(folded, expected);
+	}
+}
+
+#[test]
+fn test_partial_final_fold_round_matches_full_fold() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(8, 2, &NTTOptions::default()).unwrap();
+	let codeword = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+		.take(1 << rs_code.log_len())
+		.collect::<Vec<_>>();
+
+	// 7 committed rounds do not divide into arity 3, leaving a final fold round of arity 1.
+	let arities = fri::fold_arities_with_partial_round(rs_code.log_dim(), 3);
+	assert_eq!(arities, [3, 3, 1]);
+	let challenges = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+		.take(arities.iter().sum())
+		.collect::<Vec<_>>();
+
+	let mut round = 0;
+	let folded = arities.iter().fold(codeword.clone(), |codeword, &arity| {
+		let folded = fri::fold_codeword(
+			&rs_code,
+			&codeword,
+			round + arity,
+			&challenges[round..round + arity],
+		);
+		round += arity;
+		folded
+	});
+	let expected = challenges
+		.iter()
+		.enumerate()
+		.fold(codeword, |codeword, (i, &challenge)| {
+			fri::fold_full_round(&rs_code, i, 1, &codeword, &[challenge])
+		});
+	assert_eq!(folded, expected);
+}
+
+#[test]
+fn test_commit_prove_verify_with_partial_final_fold_round() {
+	let log_dimension = 8;
+	let log_inv_rate = 2;
+	for (log_batch_size, arity) in [(0, 3), (2, 4), (1, 5)] {
+		let arities = fri::fold_arities_with_partial_round(log_dimension + log_batch_size, arity);
+		assert!(arities.iter().sum::<usize>() % arity != 0);
+		test_commit_prove_verify_success::<OptimalUnderlier128b, BinaryField128b, BinaryField16b>(
+			log_dimension,
+			log_inv_rate,
+			log_batch_size,
+			&arities,
+		);
+	}
+}
+
+#[test]
+fn test_commit_prove_verify_with_varying_coset_sizes() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 8;
+	let log_inv_rate = 2;
+	// Increasing, decreasing and mixed coset sizes, with and without an interleaved first round.
+	let schedules: [(usize, &[usize]); 4] = [
+		(0, &[1, 2, 4]),
+		(0, &[4, 2, 1]),
+		(1, &[2, 4, 1, 1]),
+		(2, &[3, 1, 4]),
+	];
+	for (log_batch_size, arities) in schedules {
+		for final_message_mode in [FinalMessageMode::Plaintext, FinalMessageMode::Committed] {
+			commit_prove_verify::<U, F, FA>(
+				log_dimension,
+				log_inv_rate,
+				log_batch_size,
+				arities,
+				final_message_mode,
+			);
+		}
+	}
+
+	// A proof with per-round coset sizes [4, 2, 1] is rejected by a verifier expecting the same
+	// number of oracles folded with coset sizes [1, 2, 4].
+	let (_, proof) = commit_prove_verify::<U, F, FA>(
+		log_dimension,
+		log_inv_rate,
+		0,
+		&[4, 2, 1],
+		FinalMessageMode::Plaintext,
+	);
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, 0, vec![1, 2, 4], 3).unwrap();
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+	let codeword_commitment = transcript.message().read().unwrap();
+	let mut challenges = Vec::<F>::with_capacity(params.n_fold_rounds());
+	let mut round_commitments = Vec::with_capacity(params.n_oracles());
+	for &arity in params.fold_arities() {
+		challenges.append(&mut transcript.sample_vec(arity));
+		round_commitments.push(transcript.message().read().unwrap());
+	}
+	challenges.append(&mut transcript.sample_vec(params.n_final_challenges()));
+
+	let verifier = FRIVerifier::new(
+		&params,
+		merkle_prover.scheme(),
+		&codeword_commitment,
+		&round_commitments,
+		&challenges,
+	)
+	.unwrap();
+	assert!(verifier.verify(&mut transcript).is_err());
+}
+
+#[test]
+fn test_fold_query_path_matches_full_fold() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(8, 2, &NTTOptions::default()).unwrap();
+	let codeword = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+		.take(1 << rs_code.log_len())
+		.collect::<Vec<_>>();
+	let arities = [2, 1, 3];
+	let challenges = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+		.take(arities.iter().sum())
+		.collect::<Vec<_>>();
+
+	// Fold the whole codeword oracle by oracle, keeping every intermediate codeword.
+	let mut oracles = vec![codeword];
+	let mut round = 0;
+	for &arity in &arities {
+		let folded = fri::fold_full_round(
+			&rs_code,
+			round,
+			arity,
+			oracles.last().unwrap(),
+			&challenges[round..round + arity],
+		);
+		oracles.push(folded);
+		round += arity;
+	}
+
+	for query_index in [0, 37, (1 << rs_code.log_len()) - 1] {
+		let mut round = 0;
+		let openings = iter::zip(&arities, &oracles)
+			.map(|(&arity, oracle)| {
+				let (_, coset_range) = fri::coset_layout(query_index, round, arity);
+				round += arity;
+				oracle[coset_range].to_vec()
+			})
+			.collect::<Vec<_>>();
+
+		let path = fri::fold_query_path(&rs_code, query_index, &arities, &openings, &challenges);
+		assert_eq!(path.len(), arities.len());
+
+		let mut round = 0;
+		for (i, &arity) in arities.iter().enumerate() {
+			round += arity;
+			assert_eq!(path[i], oracles[i + 1][query_index >> round]);
+		}
+		assert_eq!(*path.last().unwrap(), oracles.last().unwrap()[query_index >> challenges.len()]);
+	}
+}
+
+#[test]
+fn test_batch_verifier_rejects_single_tampered_proof() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let arities = vec![3, 2];
+	let n_proofs = 3;
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
+		log_dimension,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, 0, arities, 3).unwrap();
+
+	let proofs = repeat_with(|| {
+		let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+			.take(committed_rs_code_packed.dim() >> <PackedType<U, F>>::LOG_WIDTH)
+			.collect::<Vec<_>>();
+
+		let CommitOutput {
+			commitment: codeword_commitment,
+			committed: codeword_committed,
+			codeword,
+		} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+
+		let mut round_prover = FRIFolder::new(
+			&params,
+			&merkle_prover,
+			<PackedType<U, F>>::unpack_scalars(&codeword),
+			&codeword_committed,
+		)
+		.unwrap();
+
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		prover_transcript.message().write(&codeword_commitment);
+		for _ in 0..params.n_fold_rounds() {
+			let challenge = prover_transcript.sample();
+			if let FoldRoundOutput::Commitment(round_commitment) =
+				round_prover.execute_fold_round(challenge).unwrap()
+			{
+				prover_transcript.message().write(&round_commitment);
+			}
+		}
+		round_prover.finish_proof(&mut prover_transcript).unwrap();
+		prover_transcript.finalize()
+	})
+	.take(n_proofs)
+	.collect::<Vec<_>>();
+
+	// Replays the commit and fold phases of every proof and runs the batch verifier on the query
+	// phases.
+	let run_batch = |proofs: Vec<Vec<u8>>| {
+		let mut transcripts = proofs
+			.into_iter()
+			.map(VerifierTranscript::<HasherChallenger<Groestl256>>::new)
+			.collect::<Vec<_>>();
+		let public = transcripts
+			.iter_mut()
+			.map(|transcript| {
+				let codeword_commitment = transcript.message().read().unwrap();
+				let mut challenges = Vec::with_capacity(params.n_fold_rounds());
+				let mut round_commitments = Vec::with_capacity(params.n_oracles());
+				for &arity in params.fold_arities() {
+					challenges.append(&mut transcript.sample_vec(arity));
+					round_commitments.push(transcript.message().read().unwrap());
+				}
+				challenges.append(&mut transcript.sample_vec(params.n_final_challenges()));
+				(codeword_commitment, round_commitments, challenges)
+			})
+			.collect::<Vec<_>>();
+		let claims = public
+			.iter()
+			.map(|(codeword_commitment, round_commitments, challenges)| FRIBatchClaim {
+				codeword_commitment,
+				round_commitments,
+				challenges,
+			})
+			.collect::<Vec<_>>();
+
+		BatchFriVerifier::new(&params, merkle_prover.scheme()).verify(&claims, &mut transcripts)
+	};
+
+	let final_values = run_batch(proofs.clone()).unwrap();
+	assert_eq!(final_values.len(), n_proofs);
+
+	for tampered_index in 0..n_proofs {
+		// Corrupt the last byte of the proof, which belongs to the opening of the last query.
+		let mut tampered_proofs = proofs.clone();
+		*tampered_proofs[tampered_index].last_mut().unwrap() ^= 1;
+		assert_matches!(
+			run_batch(tampered_proofs),
+			Err(fri::Error::BatchProofFailed { proof, .. }) if proof == tampered_index
+		);
+	}
+}
+
+#[test]
+fn test_round_codewords_match_iterated_fold_full_round() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let log_batch_size = 2;
+	let arities = vec![3, 2];
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
+		log_dimension,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, log_batch_size, arities, 3).unwrap();
+
+	let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take(committed_rs_code_packed.dim() << log_batch_size >> <PackedType<U, F>>::LOG_WIDTH)
+		.collect::<Vec<_>>();
+
+	let CommitOutput {
+		commitment: codeword_commitment,
+		committed: codeword_committed,
+		codeword,
+	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+
+	let mut round_prover = FRIFolder::new(
+		&params,
+		&merkle_prover,
+		<PackedType<U, F>>::unpack_scalars(&codeword),
+		&codeword_committed,
+	)
+	.unwrap()
+	.with_round_codewords();
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	prover_transcript.message().write(&codeword_commitment);
+	let mut challenges = Vec::with_capacity(params.n_fold_rounds());
+	for _ in 0..params.n_fold_rounds() {
+		let challenge = prover_transcript.sample();
+		challenges.push(challenge);
+		if let FoldRoundOutput::Commitment(round_commitment) =
+			round_prover.execute_fold_round(challenge).unwrap()
+		{
+			prover_transcript.message().write(&round_commitment);
+		}
+	}
+
+	let round_codewords = round_prover.round_codewords().unwrap().to_vec();
+	assert_eq!(round_codewords.len(), params.n_fold_rounds());
+
+	// After the interleaving rounds, each codeword is the previous one folded by one round.
+	for round in log_batch_size + 1..params.n_fold_rounds() {
+		let expected = fri::fold_full_round(
+			params.rs_code(),
+			round - log_batch_size,
+			1,
+			&round_codewords[round - 1],
+			&challenges[round..round + 1],
+		);
+		assert_eq!(round_codewords[round], expected);
+	}
+
+	// The codeword committed in the last commitment round is sent in the clear.
+	let (terminate_codeword, _) = round_prover.finalize().unwrap();
+	let last_commit_round = params.fold_arities().iter().sum::<usize>();
+	assert_eq!(terminate_codeword, round_codewords[last_commit_round - 1]);
+
+	// Folding all rounds yields a repetition codeword.
+	let final_codeword = round_codewords.last().unwrap();
+	assert!(final_codeword
+		.iter()
+		.all(|&value| value == final_codeword[0]));
+}
+
+/// Returns the rank of the matrix with the given rows, by Gaussian elimination.
+fn matrix_rank<F: Field>(mut rows: Vec<Vec<F>>) -> usize {
+	let n_cols = rows.first().map_or(0, Vec::len);
+	let mut rank = 0;
+	for col in 0..n_cols {
+		let Some(pivot) = (rank..rows.len()).find(|&i| rows[i][col] != F::ZERO) else {
+			continue;
+		};
+		rows.swap(rank, pivot);
+		let pivot_inv = rows[rank][col].invert().unwrap();
+		let pivot_row = rows[rank]
+			.iter()
+			.map(|&x| x * pivot_inv)
+			.collect::<Vec<_>>();
+		for row in &mut rows[rank + 1..] {
+			let coeff = row[col];
+			for (x, &pivot_x) in iter::zip(row, &pivot_row) {
+				*x -= coeff * pivot_x;
+			}
+		}
+		rank += 1;
+	}
+	rank
+}
+
+#[test]
+fn test_blinded_commit_prove_verify() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+	type P = PackedType<U, F>;
+
+	let log_dimension = 6;
+	let log_inv_rate = 2;
+	let log_batch_size = 1;
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
+		log_dimension,
+		log_inv_rate,
+		&NTTOptions::default(),
+	)
+	.unwrap();
+	let committed_rs_code =
+		ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+	let params = FRIParams::new(committed_rs_code, log_batch_size, vec![3, 2], 3).unwrap();
+	let padding_var = params.blinding_padding_var().unwrap();
+	let first_arity = params.fold_arities()[0];
+
+	let mask_len = committed_rs_code_packed.dim() << log_batch_size >> P::LOG_WIDTH;
+	let random_vec = |rng: &mut StdRng, len| {
+		repeat_with(|| P::random(&mut *rng))
+			.take(len)
+			.collect::<Vec<_>>()
+	};
+	let msg = random_vec(&mut rng, mask_len / 2);
+	let padding = random_vec(&mut rng, mask_len / 2);
+	let mask = random_vec(&mut rng, mask_len);
+
+	let BlindedCommitOutput {
+		padded,
+		mask: mask_output,
+	} = fri::commit_interleaved_with_blinding(
+		&committed_rs_code_packed,
+		&params,
+		&merkle_prover,
+		&msg,
+		&padding,
+		&mask,
+	)
+	.unwrap();
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	prover_transcript.message().write(&padded.commitment);
+	prover_transcript.message().write(&mask_output.commitment);
+	let batch_coeff: F = prover_transcript.sample();
+
+	// Fold the padded codeword plus a random multiple of the mask, and the mask alone, with the
+	// same challenges. The padding variable is folded with zero.
+	let padded_codeword = P::unpack_scalars(&padded.codeword);
+	let mask_codeword = P::unpack_scalars(&mask_output.codeword);
+	let batched_codeword = fri::batch_blinded_codeword(padded_codeword, mask_codeword, batch_coeff);
+	let mut blinded_prover = FRIFolder::new_blinded(
+		&params,
+		&merkle_prover,
+		&batched_codeword,
+		(padded_codeword, &padded.committed),
+		(mask_codeword, &mask_output.committed),
+	)
+	.unwrap();
+	let mut mask_prover =
+		FRIFolder::new(&params, &merkle_prover, mask_codeword, &mask_output.committed).unwrap();
+	for round in 0..params.n_fold_rounds() {
+		let challenge = if round == padding_var {
+			F::ZERO
+		} else {
+			prover_transcript.sample()
+		};
+		for round_prover in [&mut blinded_prover, &mut mask_prover] {
+			if let FoldRoundOutput::Commitment(round_commitment) =
+				round_prover.execute_fold_round(challenge).unwrap()
+			{
+				prover_transcript.message().write(&round_commitment);
+			}
+		}
+	}
+
+	// Finish the blinded proof by hand to learn which cosets of the padded codeword are opened.
+	let (terminate_codeword, query_prover) = blinded_prover.finalize().unwrap();
+	let mut advice = prover_transcript.decommitment();
+	advice.write_scalar_slice(&terminate_codeword);
+	for layer in query_prover.vcs_optimal_layers().unwrap() {
+		advice.write_slice(&layer);
+	}
+	let query_indices = repeat_with(|| prover_transcript.sample_bits(params.index_bits()))
+		.take(params.n_test_queries())
+		.collect::<Vec<_>>();
+	for &index in &query_indices {
+		query_prover
+			.prove_query(index, prover_transcript.decommitment())
+			.unwrap();
+	}
+	mask_prover.finish_proof(&mut prover_transcript).unwrap();
+	let proof = prover_transcript.finalize();
+
+	// The openings of the padded codeword hide the message if the padding alone can produce any
+	// values at the opened positions, that is, if the encodings of the padding restricted to
+	// those positions have full rank. The coset at index zero is never hidden.
+	assert!(!query_indices.contains(&0));
+	let opened_positions = query_indices
+		.iter()
+		.collect::<std::collections::BTreeSet<_>>()
+		.into_iter()
+		.flat_map(|&index| (index << first_arity)..((index + 1) << first_arity))
+		.collect::<Vec<_>>();
+	let zero_msg = vec![P::zero(); msg.len()];
+	let zero_mask = vec![P::zero(); mask.len()];
+	let padding_encodings = (0..msg.len() << P::LOG_WIDTH)
+		.map(|i| {
+			let mut unit_padding = zero_msg.clone();
+			P::unpack_scalars_mut(&mut unit_padding)[i] = F::ONE;
+			fri::commit_interleaved_with_blinding(
+				&committed_rs_code_packed,
+				&params,
+				&merkle_prover,
+				&zero_msg,
+				&unit_padding,
+				&zero_mask,
+			)
+			.unwrap()
+			.padded
+			.codeword
+		})
+		.collect::<Vec<_>>();
+	let padding_matrix = opened_positions
+		.iter()
+		.map(|&position| {
+			padding_encodings
+				.iter()
+				.map(|encoding| P::unpack_scalars(encoding)[position])
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(matrix_rank(padding_matrix), opened_positions.len());
+
+	let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+	let padded_commitment = transcript.message().read().unwrap();
+	let mask_commitment = transcript.message().read().unwrap();
+	let batch_coeff: F = transcript.sample();
+	let commit_rounds = params.fold_commit_rounds();
+	let mut challenges = Vec::with_capacity(params.n_fold_rounds());
+	let mut round_commitments = Vec::with_capacity(params.n_oracles());
+	let mut mask_round_commitments = Vec::with_capacity(params.n_oracles());
+	for round in 0..params.n_fold_rounds() {
+		challenges.push(if round == padding_var {
+			F::ZERO
+		} else {
+			transcript.sample()
+		});
+		if commit_rounds.contains(&round) {
+			round_commitments.push(transcript.message().read().unwrap());
+			mask_round_commitments.push(transcript.message().read().unwrap());
+		}
+	}
+
+	// The padding variable must be folded with zero.
+	let mut nonzero_challenges = challenges.clone();
+	nonzero_challenges[padding_var] = F::ONE;
+	assert_matches!(
+		FRIVerifier::new_blinded(
+			&params,
+			merkle_prover.scheme(),
+			&padded_commitment,
+			&mask_commitment,
+			batch_coeff,
+			&round_commitments,
+			&nonzero_challenges,
+		),
+		Err(Error::InvalidArgs(_))
+	);
+
+	let verifier = FRIVerifier::new_blinded(
+		&params,
+		merkle_prover.scheme(),
+		&padded_commitment,
+		&mask_commitment,
+		batch_coeff,
+		&round_commitments,
+		&challenges,
+	)
+	.unwrap();
+	let mask_verifier = FRIVerifier::new(
+		&params,
+		merkle_prover.scheme(),
+		&mask_commitment,
+		&mask_round_commitments,
+		&challenges,
+	)
+	.unwrap();
+	let value = verifier
+		.verify_blinded(&mask_verifier, &mut transcript)
+		.unwrap();
+	transcript.finalize().unwrap();
+
+	// The corrected final value is the evaluation of the message at the challenges of every
+	// variable but the padding variable.
+	let mut msg_point = challenges;
+	msg_point.remove(padding_var);
+	let backend = make_portable_backend();
+	let eval_query = backend.multilinear_query::<F>(&msg_point).unwrap();
+	let msg_eval = MultilinearExtension::from_values_slice(&msg)
+		.unwrap()
+		.evaluate(&eval_query)
+		.unwrap();
+	assert_eq!(value, msg_eval);
+}
+
+#[test]
+fn test_dual_code_commit_prove_verify() {
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type FA = BinaryField16b;
+
+	let log_dimension = 6;
+	let log_batch_size = 1;
+
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	// The two codes differ in rate, and so in evaluation domain, and are folded on different
+	// schedules.
+	let code_params = [(2, vec![3, 2]), (3, vec![2, 2, 1])];
+	let rs_codes_packed = code_params.clone().map(|(log_inv_rate, _)| {
+		ReedSolomonCode::<PackedType<U, FA>>::new(
+			log_dimension,
+			log_inv_rate,
+			&NTTOptions::default(),
+		)
+		.unwrap()
+	});
+	let params = code_params.map(|(log_inv_rate, arities)| {
+		let rs_code =
+			ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate, &NTTOptions::default())
+				.unwrap();
+		FRIParams::<F, FA>::new(rs_code, log_batch_size, arities, 3).unwrap()
+	});
+
+	let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take(rs_codes_packed[0].dim() << log_batch_size >> <PackedType<U, F>>::LOG_WIDTH)
+		.collect::<Vec<_>>();
+
+	let commit_outputs = fri::commit_interleaved_dual_code(
+		[&rs_codes_packed[0], &rs_codes_packed[1]],
+		[&params[0], &params[1]],
+		&merkle_prover,
+		&msg,
+	)
+	.unwrap();
+
+	// The rounds after which each code commits a round oracle.
+	let commit_rounds = params.each_ref().map(|params| {
+		params
+			.fold_arities()
+			.iter()
+			.scan(0, |round, &arity| {
+				*round += arity;
+				Some(*round)
+			})
+			.collect::<Vec<_>>()
+	});
+
+	let mut round_provers = [0, 1].map(|i| {
+		FRIFolder::new(
+			&params[i],
+			&merkle_prover,
+			<PackedType<U, F>>::unpack_scalars(&commit_outputs[i].codeword),
+			&commit_outputs[i].committed,
+		)
+		.unwrap()
+	});
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	for commit_output in &commit_outputs {
+		prover_transcript.message().write(&commit_output.commitment);
+	}
+	for _ in 0..params[0].n_fold_rounds() {
+		let challenge = prover_transcript.sample();
+		for round_prover in &mut round_provers {
+			if let FoldRoundOutput::Commitment(round_commitment) =
+				round_prover.execute_fold_round(challenge).unwrap()
+			{
+				prover_transcript.message().write(&round_commitment);
+			}
+		}
+	}
+	for round_prover in round_provers {
+		round_prover.finish_proof(&mut prover_transcript).unwrap();
+	}
+	let proof = prover_transcript.finalize();
+
+	// Replays the verifier, returning the result and the offset of the first FRI proof.
+	let run_verifier = |proof: Vec<u8>| {
+		let proof_len = proof.len();
+		let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		let codeword_commitments: [_; 2] = array::from_fn(|_| transcript.message().read().unwrap());
+		let mut challenges = Vec::with_capacity(params[0].n_fold_rounds());
+		let mut round_commitments = [Vec::new(), Vec::new()];
+		for round in 1..=params[0].n_fold_rounds() {
+			challenges.push(transcript.sample());
+			for (commit_rounds, round_commitments) in izip!(&commit_rounds, &mut round_commitments)
+			{
+				if commit_rounds.contains(&round) {
+					round_commitments.push(transcript.message().read().unwrap());
+				}
+			}
+		}
+		let proof_offset = proof_len - transcript.decommitment().buffer().remaining();
+
+		let verifiers = [0, 1].map(|i| {
+			FRIVerifier::new(
+				&params[i],
+				merkle_prover.scheme(),
+				&codeword_commitments[i],
+				&round_commitments[i],
+				&challenges,
+			)
+			.unwrap()
+		});
+		let result = verifiers[0].verify_dual_code(&verifiers[1], &mut transcript);
+		if result.is_ok() {
+			transcript.finalize().unwrap();
+		}
+		(result, challenges, proof_offset)
+	};
+
+	// Both query phases pass, and the final value is the evaluation of the message.
+	let (result, challenges, proof_offset) = run_verifier(proof.clone());
+	let backend = make_portable_backend();
+	let eval_query = backend.multilinear_query::<F>(&challenges).unwrap();
+	let multilin = MultilinearExtension::from_values_slice(&msg).unwrap();
+	assert_eq!(result.unwrap(), multilin.evaluate(&eval_query).unwrap());
+
+	// Tampering with the proof of either code is rejected.
+	for offset in [proof_offset, proof.len() - 1] {
+		let mut tampered_proof = proof.clone();
+		tampered_proof[offset] ^= 1;
+		assert!(run_verifier(tampered_proof).0.is_err());
+	}
+}
@@ -1,17 +1,25 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::iter;
+use std::{borrow::Cow, iter};
 
-use binius_field::{BinaryField, ExtensionField, TowerField};
+use binius_field::{util::inner_product_unchecked, BinaryField, ExtensionField, TowerField};
 use binius_hal::{make_portable_backend, ComputationBackend};
+use binius_maybe_rayon::prelude::*;
 use binius_utils::{bail, DeserializeBytes};
 use bytes::Buf;
 use itertools::izip;
 use tracing::instrument;
 
-use super::{common::vcs_optimal_layers_depths_iter, error::Error, VerificationError};
+use super::{
+	common::{
+		coset_layout, max_valid_proof_size, vcs_optimal_layers_depths_iter,
+		vcs_terminate_layer_depth, FinalMessageMode,
+	},
+	error::Error,
+	VerificationError,
+};
 use crate::{
-	fiat_shamir::{CanSampleBits, Challenger},
+	fiat_shamir::{CanSample, CanSampleBits, Challenger},
 	merkle_tree::MerkleTreeScheme,
 	protocols::fri::common::{fold_chunk, fold_interleaved_chunk, FRIParams},
 	transcript::{TranscriptReader, VerifierTranscript},
@@ -32,12 +40,19 @@ where
 	params: &'a FRIParams<F, FA>,
 	/// Received commitment to the codeword.
 	codeword_commitment: &'a VCS::Digest,
+	/// Received commitment to the mask of a blinded codeword, with its batching coefficient.
+	mask: Option<(&'a VCS::Digest, F)>,
 	/// Received commitments to the round messages.
 	round_commitments: &'a [VCS::Digest],
 	/// The challenges for each round.
 	interleave_tensor: Vec<F>,
 	/// The challenges for each round.
 	fold_challenges: &'a [F],
+	/// The Merkle layer depths at which each oracle is decommitted.
+	layer_depths: Cow<'a, [usize]>,
+	/// The Merkle layer depth at which the last oracle is decommitted, if the final message is
+	/// committed.
+	terminate_layer_depth: Option<usize>,
 }
 
 impl<'a, F, FA, VCS> FRIVerifier<'a, F, FA, VCS>
@@ -53,6 +68,90 @@ where
 		codeword_commitment: &'a VCS::Digest,
 		round_commitments: &'a [VCS::Digest],
 		challenges: &'a [F],
+	) -> Result<Self, Error> {
+		let layer_depths = vcs_optimal_layers_depths_iter(params, vcs).collect::<Vec<_>>();
+		Self::with_layer_depths(
+			params,
+			vcs,
+			codeword_commitment,
+			round_commitments,
+			challenges,
+			Cow::Owned(layer_depths),
+		)
+	}
+
+	/// Constructs a verifier whose folding challenges are fixed by a commitment.
+	///
+	/// Instead of being sampled from the transcript, the challenges are checked to be the vector
+	/// committed under `challenges_commitment` as a single leaf. This pairs with
+	/// [`super::FRIFolder::execute_fold_rounds_with_challenges`] on the prover side.
+	///
+	/// ## Soundness
+	///
+	/// Checking the challenges against their commitment does not bind them to the round
+	/// commitments, which the prover computes knowing every challenge. The resulting proof is
+	/// **unsound** unless the caller derives the challenges from an outer transcript that absorbed
+	/// the codeword commitment before they were fixed, and binds the round commitments to them in
+	/// the surrounding protocol.
+	pub fn new_with_committed_challenges(
+		params: &'a FRIParams<F, FA>,
+		vcs: &'a VCS,
+		codeword_commitment: &'a VCS::Digest,
+		round_commitments: &'a [VCS::Digest],
+		challenges: &'a [F],
+		challenges_commitment: &VCS::Digest,
+	) -> Result<Self, Error> {
+		vcs.verify_vector(challenges_commitment, challenges, challenges.len())
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+		Self::new(params, vcs, codeword_commitment, round_commitments, challenges)
+	}
+
+	/// Constructs a verifier for a codeword committed by
+	/// [`super::commit_interleaved_with_blinding`].
+	///
+	/// The proof is a FRI proof of the padded codeword plus `batch_coeff` times the mask,
+	/// generated with [`super::FRIFolder::new_blinded`]. `batch_coeff` must be sampled after both
+	/// commitments have been observed. The test queries open the padded codeword and the mask at
+	/// the same positions, and every later oracle is a fold of the batched codeword. The
+	/// [padding variable](FRIParams::blinding_padding_var) must have a zero challenge.
+	///
+	/// The final value of the batched codeword alone is not tied to the message, so the proof
+	/// should be checked with [`Self::verify_blinded`] together with a proof of the mask.
+	#[allow(clippy::too_many_arguments)]
+	pub fn new_blinded(
+		params: &'a FRIParams<F, FA>,
+		vcs: &'a VCS,
+		padded_commitment: &'a VCS::Digest,
+		mask_commitment: &'a VCS::Digest,
+		batch_coeff: F,
+		round_commitments: &'a [VCS::Digest],
+		challenges: &'a [F],
+	) -> Result<Self, Error> {
+		let Some(padding_var) = params.blinding_padding_var() else {
+			bail!(Error::InvalidArgs(
+				"the parameters have no variable to hold the blinding padding".to_string()
+			));
+		};
+		if challenges.get(padding_var) != Some(&F::ZERO) {
+			bail!(Error::InvalidArgs(
+				"the padding variable of a blinded codeword must be folded with zero".to_string()
+			));
+		}
+
+		let verifier = Self::new(params, vcs, padded_commitment, round_commitments, challenges)?;
+		Ok(Self {
+			mask: Some((mask_commitment, batch_coeff)),
+			..verifier
+		})
+	}
+
+	fn with_layer_depths(
+		params: &'a FRIParams<F, FA>,
+		vcs: &'a VCS,
+		codeword_commitment: &'a VCS::Digest,
+		round_commitments: &'a [VCS::Digest],
+		challenges: &'a [F],
+		layer_depths: Cow<'a, [usize]>,
 	) -> Result<Self, Error> {
 		if round_commitments.len() != params.n_oracles() {
 			bail!(Error::InvalidArgs(format!(
@@ -81,9 +180,12 @@ where
 			params,
 			vcs,
 			codeword_commitment,
+			mask: None,
 			round_commitments,
 			interleave_tensor,
 			fold_challenges,
+			layer_depths,
+			terminate_layer_depth: vcs_terminate_layer_depth(params, vcs),
 		})
 	}
 
@@ -92,6 +194,61 @@ where
 		self.params.n_oracles()
 	}
 
+	/// The maximum number of bytes of the proof this verifier reads, as given by
+	/// [`max_valid_proof_size`].
+	///
+	/// Each oracle committed in the fold rounds decommits its Merkle layer at the optimal depth,
+	/// and every test query opens a branch from a leaf of the oracle up to that layer.
+	pub fn max_proof_size(&self) -> usize {
+		let mut tree_depth = self.params.log_len();
+		let vcs_proof_size = iter::zip(self.params.fold_arities(), self.layer_depths.iter())
+			.map(|(&arity, &layer_depth)| {
+				tree_depth -= arity;
+				let n_digests =
+					(1 << layer_depth) + self.params.n_test_queries() * (tree_depth - layer_depth);
+				n_digests * self.vcs.digest_size()
+			})
+			.sum::<usize>();
+		// A committed final message is opened once and by every test query in the last oracle.
+		let terminate_proof_size = self.terminate_layer_depth.map_or(0, |layer_depth| {
+			let tree_depth = self.params.rs_code().log_inv_rate();
+			let n_digests = (1 << layer_depth)
+				+ (self.params.n_test_queries() + 1) * (tree_depth - layer_depth);
+			n_digests * self.vcs.digest_size()
+		});
+		// The mask of a blinded codeword is decommitted and opened like the codeword.
+		let mask_proof_size = self.mask.map_or(0, |_| {
+			let arity = self.params.fold_arities()[0];
+			let layer_depth = self.layer_depths[0];
+			let tree_depth = self.params.log_len() - arity;
+			let n_digests =
+				(1 << layer_depth) + self.params.n_test_queries() * (tree_depth - layer_depth);
+			n_digests * self.vcs.digest_size()
+				+ (self.params.n_test_queries() << arity) * size_of::<F>()
+		});
+		max_valid_proof_size(self.params, vcs_proof_size + terminate_proof_size) + mask_proof_size
+	}
+
+	/// Rejects a FRI proof of `proof_size` bytes that is larger than [`Self::max_proof_size`].
+	///
+	/// This is meant to be called on the length of the proof before it is read, such as a length
+	/// prefix received over the network or the remaining length of a transcript created with
+	/// [`VerifierTranscript::from_reader`], so that an oversized proof is rejected before any
+	/// buffer is allocated for it. [`Self::verify`] does not call it, because the FRI proof is
+	/// usually followed by other data in the transcript, and every read of the verifier has a size
+	/// fixed by the parameters anyway.
+	pub fn check_proof_size(&self, proof_size: usize) -> Result<(), Error> {
+		let max_size = self.max_proof_size();
+		if proof_size > max_size {
+			return Err(VerificationError::ProofTooLarge {
+				size: proof_size,
+				max_size,
+			}
+			.into());
+		}
+		Ok(())
+	}
+
 	pub fn verify<Challenger_>(
 		&self,
 		transcript: &mut VerifierTranscript<Challenger_>,
@@ -99,22 +256,109 @@ where
 	where
 		Challenger_: Challenger,
 	{
-		// Verify that the last oracle sent is a codeword.
-		let terminate_codeword_len =
-			1 << (self.params.n_final_challenges() + self.params.rs_code().log_inv_rate());
+		self.verify_with_scratch(transcript, &mut self.create_scratch_buffer())
+	}
+
+	/// Verifies FRI proofs of the same message committed under two codes.
+	///
+	/// This verifier checks the codeword of the first code and `other` the codeword of the second,
+	/// as committed by [`super::commit_interleaved_dual_code`], with the proof of the first code
+	/// read before the proof of the second. Both must use the same folding challenges. Both query
+	/// phases must pass, and the fully-folded values must agree, since the codewords encode the same
+	/// message. The common final value is returned.
+	pub fn verify_dual_code<Challenger_>(
+		&self,
+		other: &FRIVerifier<'_, F, FA, VCS>,
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<F, Error>
+	where
+		Challenger_: Challenger,
+	{
+		if other.fold_challenges != self.fold_challenges
+			|| other.interleave_tensor != self.interleave_tensor
+		{
+			bail!(Error::InvalidArgs(
+				"the codewords of both codes must be folded with the same challenges".to_string()
+			));
+		}
+
+		let final_value = self.verify(transcript)?;
+		let other_final_value = other.verify(transcript)?;
+		if final_value != other_final_value {
+			return Err(VerificationError::DualCodeFinalValueMismatch.into());
+		}
+		Ok(final_value)
+	}
+
+	/// Verifies the FRI proof of a blinded codeword followed by the FRI proof of its mask.
+	///
+	/// This verifier must be constructed with [`Self::new_blinded`], and `mask` with [`Self::new`]
+	/// for the committed mask and the same challenges. The batched final value is the evaluation
+	/// of the padded message plus `batch_coeff` times that of the mask, and the proof of the mask
+	/// proves the latter. Since the padding variable is folded with zero, the returned difference
+	/// is the evaluation of the message at the challenges without the padding variable.
+	pub fn verify_blinded<Challenger_>(
+		&self,
+		mask: &FRIVerifier<'_, F, FA, VCS>,
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<F, Error>
+	where
+		Challenger_: Challenger,
+	{
+		let Some((mask_commitment, batch_coeff)) = self.mask else {
+			bail!(Error::InvalidArgs("the verifier is not for a blinded codeword".to_string()));
+		};
+		if mask.mask.is_some() || mask.codeword_commitment != mask_commitment {
+			bail!(Error::InvalidArgs(
+				"the mask verifier must check the committed mask".to_string()
+			));
+		}
+		if mask.fold_challenges != self.fold_challenges
+			|| mask.interleave_tensor != self.interleave_tensor
+		{
+			bail!(Error::InvalidArgs(
+				"the codeword and the mask must be folded with the same challenges".to_string()
+			));
+		}
+
+		let batched_value = self.verify(transcript)?;
+		let mask_value = mask.verify(transcript)?;
+		Ok(batched_value - batch_coeff * mask_value)
+	}
+
+	fn verify_with_scratch<Challenger_>(
+		&self,
+		transcript: &mut VerifierTranscript<Challenger_>,
+		scratch_buffer: &mut [F],
+	) -> Result<F, Error>
+	where
+		Challenger_: Challenger,
+	{
 		let mut advice = transcript.decommitment();
-		let terminate_codeword = advice
-			.read_scalar_slice(terminate_codeword_len)
-			.map_err(Error::TranscriptError)?;
-		let final_value = self.verify_last_oracle(&terminate_codeword)?;
+
+		// Verify that the last oracle sent is a codeword. A committed last oracle is instead
+		// decommitted after the other layers.
+		let terminate_codeword = match self.params.final_message_mode() {
+			FinalMessageMode::Plaintext => {
+				let terminate_codeword = advice
+					.read_scalar_slice(self.params.terminate_codeword_len())
+					.map_err(Error::TranscriptError)?;
+				let final_value = self.verify_last_oracle(&terminate_codeword)?;
+				Some((terminate_codeword, final_value))
+			}
+			FinalMessageMode::Committed => None,
+		};
 
 		// Verify that the provided layers match the commitments.
-		let layers = vcs_optimal_layers_depths_iter(self.params, self.vcs)
+		let layers = self
+			.oracle_layer_depths()
 			.map(|layer_depth| advice.read_vec(1 << layer_depth))
 			.collect::<Result<Vec<_>, _>>()?;
 		for (commitment, layer_depth, layer) in izip!(
-			iter::once(self.codeword_commitment).chain(self.round_commitments),
-			vcs_optimal_layers_depths_iter(self.params, self.vcs),
+			iter::once(self.codeword_commitment)
+				.chain(self.mask.map(|(commitment, _)| commitment))
+				.chain(self.round_commitments),
+			self.oracle_layer_depths(),
 			&layers
 		) {
 			self.vcs
@@ -122,27 +366,203 @@ where
 				.map_err(|err| Error::VectorCommit(Box::new(err)))?;
 		}
 
+		let (last_oracle, final_value) = match &terminate_codeword {
+			Some((terminate_codeword, final_value)) => {
+				(LastOracle::Plaintext(terminate_codeword), *final_value)
+			}
+			None => {
+				let (layer, final_value) = self.open_final_message(&mut advice, scratch_buffer)?;
+				(LastOracle::Committed { layer, final_value }, final_value)
+			}
+		};
+
 		// Verify the random openings against the decommitted layers.
+		self.verify_queries_with_scratch(transcript, &last_oracle, &layers, scratch_buffer)?;
+
+		Ok(final_value)
+	}
+
+	/// Runs the query phase, sampling every test query index and verifying its openings in all
+	/// fold rounds.
+	///
+	/// The terminate codeword and the decommitted layers must have already been checked against
+	/// the commitments, as is done in [`Self::verify`]. On failure, the error reports the first
+	/// failing query and the oracle round at which it failed.
+	pub fn verify_query_phase<Challenger_>(
+		&self,
+		transcript: &mut VerifierTranscript<Challenger_>,
+		terminate_codeword: &[F],
+		layers: &[Vec<VCS::Digest>],
+	) -> Result<(), Error>
+	where
+		Challenger_: Challenger,
+	{
+		self.verify_queries_with_scratch(
+			transcript,
+			&LastOracle::Plaintext(terminate_codeword),
+			layers,
+			&mut self.create_scratch_buffer(),
+		)
+	}
 
-		let mut scratch_buffer = self.create_scratch_buffer();
-		for _ in 0..self.params.n_test_queries() {
+	fn verify_queries_with_scratch<Challenger_>(
+		&self,
+		transcript: &mut VerifierTranscript<Challenger_>,
+		last_oracle: &LastOracle<'_, F, VCS::Digest>,
+		layers: &[Vec<VCS::Digest>],
+		scratch_buffer: &mut [F],
+	) -> Result<(), Error>
+	where
+		Challenger_: Challenger,
+	{
+		transcript
+			.reserve_output(self.params.n_test_queries() * self.params.index_bits().div_ceil(8));
+		for query in 0..self.params.n_test_queries() {
 			let index = transcript.sample_bits(self.params.index_bits());
 			self.verify_query_internal(
 				index,
-				&terminate_codeword,
-				&layers,
+				last_oracle,
+				layers,
 				&mut transcript.decommitment(),
-				&mut scratch_buffer,
-			)?
+				scratch_buffer,
+			)
+			.map_err(|(round, err)| Error::QueryFailed {
+				query,
+				round,
+				source: Box::new(err),
+			})?;
 		}
+		Ok(())
+	}
 
-		Ok(final_value)
+	/// Runs the query phase like [`Self::verify_query_phase`], verifying the queries in parallel.
+	///
+	/// The test query indices are sampled up front, and since every query opens a fixed number of
+	/// bytes, the advice is split into one slice per query. Each query then folds down its path
+	/// independently. The outcome is the same as for the sequential query phase: on failure, the
+	/// error reports the failing query with the lowest index.
+	pub fn verify_query_phase_parallel<Challenger_>(
+		&self,
+		transcript: &mut VerifierTranscript<Challenger_>,
+		terminate_codeword: &[F],
+		layers: &[Vec<VCS::Digest>],
+	) -> Result<(), Error>
+	where
+		Challenger_: Challenger,
+		VCS::Digest: Send + Sync,
+	{
+		self.verify_queries_parallel(transcript, &LastOracle::Plaintext(terminate_codeword), layers)
+	}
+
+	fn verify_queries_parallel<Challenger_>(
+		&self,
+		transcript: &mut VerifierTranscript<Challenger_>,
+		last_oracle: &LastOracle<'_, F, VCS::Digest>,
+		layers: &[Vec<VCS::Digest>],
+	) -> Result<(), Error>
+	where
+		Challenger_: Challenger,
+		VCS::Digest: Send + Sync,
+	{
+		let n_queries = self.params.n_test_queries();
+		transcript.reserve_output(n_queries * self.params.index_bits().div_ceil(8));
+		let indices = (0..n_queries)
+			.map(|_| transcript.sample_bits(self.params.index_bits()))
+			.collect::<Vec<_>>();
+
+		// A truncated proof leaves the trailing queries with short slices, which then fail just as
+		// they would when read sequentially.
+		let query_proof_size = self.query_proof_size();
+		let mut advice = transcript.decommitment();
+		let n_bytes = advice
+			.buffer()
+			.remaining()
+			.min(n_queries * query_proof_size);
+		let mut proof_bytes = vec![0u8; n_bytes];
+		advice.read_bytes(&mut proof_bytes)?;
+
+		let results = indices
+			.into_par_iter()
+			.enumerate()
+			.map_init(
+				|| self.create_scratch_buffer(),
+				|scratch_buffer, (query, index)| {
+					let start = (query * query_proof_size).min(n_bytes);
+					let end = (start + query_proof_size).min(n_bytes);
+					let mut query_proof = &proof_bytes[start..end];
+					self.verify_query_internal(
+						index,
+						last_oracle,
+						layers,
+						&mut advice.with_buffer(&mut query_proof),
+						scratch_buffer,
+					)
+				},
+			)
+			.collect::<Vec<_>>();
+
+		for (query, result) in results.into_iter().enumerate() {
+			result.map_err(|(round, err)| Error::QueryFailed {
+				query,
+				round,
+				source: Box::new(err),
+			})?;
+		}
+		Ok(())
+	}
+
+	/// The Merkle layer depths at which each oracle is decommitted, in the order in which the
+	/// layers are read. The layer of the mask of a blinded codeword follows the layer of the
+	/// codeword.
+	fn oracle_layer_depths(&self) -> impl Iterator<Item = usize> + '_ {
+		let mask_layer_depth = self.mask.map(|_| self.layer_depths[0]);
+		self.layer_depths
+			.first()
+			.copied()
+			.into_iter()
+			.chain(mask_layer_depth)
+			.chain(self.layer_depths.iter().skip(1).copied())
+	}
+
+	/// The number of advice bytes that a single test query reads.
+	fn query_proof_size(&self) -> usize {
+		if self.params.fold_arities().is_empty() {
+			return 0;
+		}
+
+		let mut tree_depth = self.params.log_len();
+		let mut n_values = 0;
+		let mut n_digests = 0;
+		for (&arity, &layer_depth) in
+			iter::zip(self.params.fold_arities(), self.layer_depths.iter())
+		{
+			tree_depth -= arity;
+			n_values += 1 << arity;
+			n_digests += tree_depth - layer_depth;
+		}
+		if self.mask.is_some() {
+			let arity = self.params.fold_arities()[0];
+			n_values += 1 << arity;
+			n_digests += self.params.log_len() - arity - self.layer_depths[0];
+		}
+		if let Some(layer_depth) = self.terminate_layer_depth {
+			n_values += 1 << self.params.n_final_challenges();
+			n_digests += self.params.rs_code().log_inv_rate() - layer_depth;
+		}
+		n_values * size_of::<F>() + n_digests * self.vcs.digest_size()
 	}
 
 	/// Verifies that the last oracle sent is a codeword.
 	///
 	/// Returns the fully-folded message value.
 	pub fn verify_last_oracle(&self, terminate_codeword: &[F]) -> Result<F, Error> {
+		if terminate_codeword.len() != self.params.terminate_codeword_len() {
+			return Err(VerificationError::IncorrectTerminateCodewordLength {
+				expected: self.params.terminate_codeword_len(),
+			}
+			.into());
+		}
+
 		self.vcs
 			.verify_vector(
 				self.round_commitments
@@ -209,6 +629,65 @@ where
 		Ok(final_value)
 	}
 
+	/// Opens the committed codeword at a position of the code domain and returns its value.
+	///
+	/// The committed codeword interleaves `2^log_batch_size` Reed–Solomon codewords, each leaf
+	/// holding the interleaved symbols of a coset of positions. The returned value combines the
+	/// interleaved symbols at `query_index` with the interleaving challenges, giving the value of
+	/// the codeword that the first fold round operates on.
+	///
+	/// The leaf is read from `advice` and verified against `layer`, which is first checked against
+	/// the codeword commitment.
+	///
+	/// ## Arguments
+	///
+	/// * `query_index` - an index into the Reed–Solomon code domain
+	/// * `layer` - the decommitted Merkle layer of the codeword commitment
+	pub fn reconstruct_codeword_value<B: Buf>(
+		&self,
+		query_index: usize,
+		layer: &[VCS::Digest],
+		advice: &mut TranscriptReader<B>,
+	) -> Result<F, Error> {
+		let Some(&first_fold_arity) = self.params.fold_arities().first() else {
+			bail!(Error::InvalidArgs(
+				"the codeword is not committed in cosets when there are no fold rounds".to_string()
+			));
+		};
+		if self.mask.is_some() {
+			bail!(Error::InvalidArgs(
+				"the value of a blinded codeword depends on its mask".to_string()
+			));
+		}
+		if query_index >= self.params.rs_code().len() {
+			bail!(Error::InvalidArgs(format!(
+				"query index {query_index} is out of range for a codeword of length {}",
+				self.params.rs_code().len(),
+			)));
+		}
+
+		let layer_depth = self.layer_depths[0];
+		self.vcs
+			.verify_layer(self.codeword_commitment, layer_depth, layer)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+		let log_batch_size = self.params.log_batch_size();
+		let log_coset_size = first_fold_arity - log_batch_size;
+		let values = verify_coset_opening(
+			self.vcs,
+			query_index >> log_coset_size,
+			first_fold_arity,
+			layer_depth,
+			self.params.index_bits(),
+			layer,
+			advice,
+		)?;
+
+		let offset = query_index % (1 << log_coset_size);
+		let symbols = &values[offset << log_batch_size..(offset + 1) << log_batch_size];
+		Ok(inner_product_unchecked(symbols.iter().copied(), self.interleave_tensor.iter().copied()))
+	}
+
 	/// Verifies a FRI challenge query.
 	///
 	/// A FRI challenge query tests for consistency between all consecutive oracles sent by the
@@ -228,27 +707,27 @@ where
 	) -> Result<(), Error> {
 		self.verify_query_internal(
 			index,
-			terminate_codeword,
+			&LastOracle::Plaintext(terminate_codeword),
 			layers,
 			advice,
 			&mut self.create_scratch_buffer(),
 		)
+		.map_err(|(_round, err)| err)
 	}
 
+	/// Verifies a single query, returning the index of the oracle round at which it failed along
+	/// with the error.
 	#[instrument(skip_all, name = "fri::FRIVerifier::verify_query", level = "debug")]
 	fn verify_query_internal<B: Buf>(
 		&self,
 		mut index: usize,
-		terminate_codeword: &[F],
+		last_oracle: &LastOracle<'_, F, VCS::Digest>,
 		layers: &[Vec<VCS::Digest>],
 		advice: &mut TranscriptReader<B>,
 		scratch_buffer: &mut [F],
-	) -> Result<(), Error> {
+	) -> Result<(), (usize, Error)> {
 		let mut arities_iter = self.params.fold_arities().iter().copied();
 
-		let mut layer_digest_and_optimal_layer_depth =
-			iter::zip(layers, vcs_optimal_layers_depths_iter(self.params, self.vcs));
-
 		let Some(first_fold_arity) = arities_iter.next() else {
 			// If there are no query proofs, that means that no oracles were sent during the FRI
 			// fold rounds. In that case, the original interleaved codeword is decommitted and
@@ -256,6 +735,16 @@ where
 			return Ok(());
 		};
 
+		// The layer of the mask of a blinded codeword follows the layer of the codeword.
+		let (mask_layer, round_layers) = match self.mask {
+			Some(_) => (Some(&layers[1]), &layers[2..]),
+			None => (None, &layers[1..]),
+		};
+		let mut layer_digest_and_optimal_layer_depth = iter::zip(
+			iter::once(&layers[0]).chain(round_layers),
+			self.layer_depths.iter().copied(),
+		);
+
 		let (first_layer, first_optimal_layer_depth) = layer_digest_and_optimal_layer_depth
 			.next()
 			.expect("The length should be the same as the amount of proofs.");
@@ -267,7 +756,7 @@ where
 		// Check the first fold round before the main loop. It is special because in the first
 		// round we need to fold as an interleaved chunk instead of a regular coset.
 		let log_coset_size = first_fold_arity - self.params.log_batch_size();
-		let values = verify_coset_opening(
+		let mut values = verify_coset_opening(
 			self.vcs,
 			index,
 			first_fold_arity,
@@ -275,7 +764,23 @@ where
 			log_n_cosets,
 			first_layer,
 			advice,
-		)?;
+		)
+		.map_err(|err| (0, err))?;
+		if let (Some((_, batch_coeff)), Some(mask_layer)) = (self.mask, mask_layer) {
+			let mask_values = verify_coset_opening(
+				self.vcs,
+				index,
+				first_fold_arity,
+				first_optimal_layer_depth,
+				log_n_cosets,
+				mask_layer,
+				advice,
+			)
+			.map_err(|err| (0, err))?;
+			for (value, mask_value) in iter::zip(&mut values, mask_values) {
+				*value += batch_coeff * mask_value;
+			}
+		}
 		let mut next_value = fold_interleaved_chunk(
 			self.params.rs_code(),
 			self.params.log_batch_size(),
@@ -290,7 +795,7 @@ where
 		for (i, (arity, (layer, optimal_layer_depth))) in
 			izip!(arities_iter, layer_digest_and_optimal_layer_depth).enumerate()
 		{
-			let coset_index = index >> arity;
+			let (coset_index, coset_range) = coset_layout(index, 0, arity);
 
 			log_n_cosets -= arity;
 
@@ -302,14 +807,18 @@ where
 				log_n_cosets,
 				layer,
 				advice,
-			)?;
+			)
+			.map_err(|err| (i + 1, err))?;
 
-			if next_value != values[index % (1 << arity)] {
-				return Err(VerificationError::IncorrectFold {
-					query_round: i,
-					index,
-				}
-				.into());
+			if next_value != values[index - coset_range.start] {
+				return Err((
+					i + 1,
+					VerificationError::IncorrectFold {
+						query_round: i,
+						index,
+					}
+					.into(),
+				));
 			}
 
 			next_value = fold_chunk(
@@ -324,31 +833,329 @@ where
 			fold_round += arity;
 		}
 
-		if next_value != terminate_codeword[index] {
-			return Err(VerificationError::IncorrectFold {
-				query_round: self.n_oracles() - 1,
-				index,
+		let terminate_value = match last_oracle {
+			LastOracle::Plaintext(terminate_codeword) => terminate_codeword[index],
+			LastOracle::Committed { layer, final_value } => {
+				// The coset of the last oracle containing the folded value must fold to the final
+				// message in turn.
+				let log_coset_size = self.params.n_final_challenges();
+				let coset_index = index >> log_coset_size;
+				let values = self
+					.open_terminate_coset(coset_index, layer, advice)
+					.map_err(|err| (self.n_oracles(), err))?;
+				let folded = self.fold_terminate_coset(coset_index, &values, scratch_buffer);
+				if folded != *final_value {
+					return Err((self.n_oracles(), VerificationError::IncorrectDegree.into()));
+				}
+				values[index % (1 << log_coset_size)]
 			}
-			.into());
+		};
+		if next_value != terminate_value {
+			return Err((
+				self.n_oracles(),
+				VerificationError::IncorrectFold {
+					query_round: self.n_oracles() - 1,
+					index,
+				}
+				.into(),
+			));
 		}
 
 		Ok(())
 	}
 
+	/// The commitment to the last oracle, which holds the terminate codeword.
+	fn last_oracle_commitment(&self) -> &VCS::Digest {
+		self.round_commitments
+			.last()
+			.unwrap_or(self.codeword_commitment)
+	}
+
+	/// Decommits the layer of the committed last oracle and opens the final message, which is
+	/// the fold of the first coset of the last oracle with the final challenges.
+	fn open_final_message<B: Buf>(
+		&self,
+		advice: &mut TranscriptReader<B>,
+		scratch_buffer: &mut [F],
+	) -> Result<(Vec<VCS::Digest>, F), Error> {
+		let layer_depth = self
+			.terminate_layer_depth
+			.expect("the final message is committed");
+		let layer = advice.read_vec(1 << layer_depth)?;
+		self.vcs
+			.verify_layer(self.last_oracle_commitment(), layer_depth, &layer)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+		let values = self.open_terminate_coset(0, &layer, advice)?;
+		let final_value = self.fold_terminate_coset(0, &values, scratch_buffer);
+		Ok((layer, final_value))
+	}
+
+	fn open_terminate_coset<B: Buf>(
+		&self,
+		coset_index: usize,
+		layer: &[VCS::Digest],
+		advice: &mut TranscriptReader<B>,
+	) -> Result<Vec<F>, Error> {
+		verify_coset_opening(
+			self.vcs,
+			coset_index,
+			self.params.n_final_challenges(),
+			self.terminate_layer_depth
+				.expect("the final message is committed"),
+			self.params.rs_code().log_inv_rate(),
+			layer,
+			advice,
+		)
+	}
+
+	fn fold_terminate_coset(
+		&self,
+		coset_index: usize,
+		values: &[F],
+		scratch_buffer: &mut [F],
+	) -> F {
+		let n_final_challenges = self.params.n_final_challenges();
+		let n_prior_challenges = self.fold_challenges.len() - n_final_challenges;
+		fold_chunk(
+			self.params.rs_code(),
+			n_prior_challenges,
+			coset_index,
+			values,
+			&self.fold_challenges[n_prior_challenges..],
+			scratch_buffer,
+		)
+	}
+
 	// scratch buffer used in `fold_chunk`.
 	fn create_scratch_buffer(&self) -> Vec<F> {
-		let max_arity = self
-			.params
-			.fold_arities()
-			.iter()
-			.copied()
-			.max()
-			.unwrap_or_default();
-		let max_buffer_size = 2 * (1 << max_arity);
-		vec![F::default(); max_buffer_size]
+		create_scratch_buffer(self.params)
+	}
+}
+
+/// The last oracle of the fold rounds, against which the test queries are checked.
+enum LastOracle<'a, F, Digest> {
+	/// The terminate codeword, received in the clear.
+	Plaintext(&'a [F]),
+	/// The decommitted Merkle layer of the last oracle, along with the final message opened from
+	/// it.
+	Committed { layer: Vec<Digest>, final_value: F },
+}
+
+/// A pull-based stream of the FRI folding challenges, sampled from the verifier transcript.
+///
+/// Each call to [`Iterator::next`] samples the challenge of the next fold round, in the same order
+/// the prover samples them before calling [`super::FRIFolder::execute_fold_round`]. Whenever the
+/// sampled rounds complete a fold arity, the round commitment that the prover sent after them is
+/// read from the transcript, so a consumer only needs to hold the current challenge and whatever
+/// fold state it accumulates from it.
+#[derive(Debug)]
+pub struct FoldChallengeStream<'a, F, FA, Digest, Challenger_>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	params: &'a FRIParams<F, FA>,
+	transcript: &'a mut VerifierTranscript<Challenger_>,
+	/// The number of challenges sampled so far.
+	round: usize,
+	/// The round after which the next round commitment is read, if any.
+	next_commit_round: Option<usize>,
+	round_commitments: Vec<Digest>,
+}
+
+impl<'a, F, FA, Digest, Challenger_> FoldChallengeStream<'a, F, FA, Digest, Challenger_>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	pub fn new(
+		params: &'a FRIParams<F, FA>,
+		transcript: &'a mut VerifierTranscript<Challenger_>,
+	) -> Self {
+		Self {
+			params,
+			transcript,
+			round: 0,
+			next_commit_round: params.fold_arities().first().copied(),
+			round_commitments: Vec::with_capacity(params.n_oracles()),
+		}
+	}
+
+	/// The round commitments read from the transcript so far.
+	pub fn round_commitments(&self) -> &[Digest] {
+		&self.round_commitments
+	}
+
+	/// Consumes the stream, returning the round commitments read from the transcript.
+	pub fn into_round_commitments(self) -> Vec<Digest> {
+		self.round_commitments
+	}
+}
+
+impl<F, FA, Digest, Challenger_> Iterator for FoldChallengeStream<'_, F, FA, Digest, Challenger_>
+where
+	F: TowerField + ExtensionField<FA>,
+	FA: BinaryField,
+	Digest: DeserializeBytes,
+	Challenger_: Challenger,
+{
+	type Item = Result<F, Error>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.round == self.params.n_fold_rounds() {
+			return None;
+		}
+
+		let challenge = self.transcript.sample();
+		self.round += 1;
+
+		if self.next_commit_round == Some(self.round) {
+			match self.transcript.message().read() {
+				Ok(commitment) => self.round_commitments.push(commitment),
+				Err(err) => return Some(Err(err.into())),
+			}
+			self.next_commit_round = self
+				.params
+				.fold_arities()
+				.get(self.round_commitments.len())
+				.map(|arity| self.round + arity);
+		}
+
+		Some(Ok(challenge))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.params.n_fold_rounds() - self.round;
+		(remaining, Some(remaining))
 	}
 }
 
+/// The public inputs of one FRI proof verified by a [`BatchFriVerifier`].
+#[derive(Debug)]
+pub struct FRIBatchClaim<'a, F, Digest> {
+	/// Received commitment to the codeword.
+	pub codeword_commitment: &'a Digest,
+	/// Received commitments to the round messages.
+	pub round_commitments: &'a [Digest],
+	/// The challenges for each fold round.
+	pub challenges: &'a [F],
+}
+
+// Manual impls so that the claim is copyable regardless of the bounds on `F` and `Digest`.
+impl<F, Digest> Clone for FRIBatchClaim<'_, F, Digest> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<F, Digest> Copy for FRIBatchClaim<'_, F, Digest> {}
+
+/// A verifier for the query phase of many FRI proofs sharing the same parameters.
+///
+/// All proofs must use the same [`FRIParams`] and vector commitment scheme. The state that depends
+/// only on these, namely the Merkle layer depths at which each oracle is decommitted and the fold
+/// scratch buffer, is computed once and reused for every proof. Each proof is still checked
+/// against its own commitments, challenges, and transcript.
+#[derive(Debug)]
+pub struct BatchFriVerifier<'a, F, FA, VCS>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+	VCS: MerkleTreeScheme<F>,
+{
+	vcs: &'a VCS,
+	params: &'a FRIParams<F, FA>,
+	layer_depths: Vec<usize>,
+}
+
+impl<'a, F, FA, VCS> BatchFriVerifier<'a, F, FA, VCS>
+where
+	F: TowerField + ExtensionField<FA>,
+	FA: BinaryField,
+	VCS: MerkleTreeScheme<F, Digest: DeserializeBytes>,
+{
+	pub fn new(params: &'a FRIParams<F, FA>, vcs: &'a VCS) -> Self {
+		let layer_depths = vcs_optimal_layers_depths_iter(params, vcs).collect();
+		Self {
+			vcs,
+			params,
+			layer_depths,
+		}
+	}
+
+	/// Constructs the verifier for a single proof, borrowing the shared state.
+	pub fn verifier<'b>(
+		&'b self,
+		claim: FRIBatchClaim<'b, F, VCS::Digest>,
+	) -> Result<FRIVerifier<'b, F, FA, VCS>, Error> {
+		FRIVerifier::with_layer_depths(
+			self.params,
+			self.vcs,
+			claim.codeword_commitment,
+			claim.round_commitments,
+			claim.challenges,
+			Cow::Borrowed(&self.layer_depths),
+		)
+	}
+
+	/// Verifies every proof against its transcript, returning the fully-folded values in order.
+	///
+	/// On failure, the error reports the index of the first proof that was rejected.
+	pub fn verify<Challenger_>(
+		&self,
+		claims: &[FRIBatchClaim<'_, F, VCS::Digest>],
+		transcripts: &mut [VerifierTranscript<Challenger_>],
+	) -> Result<Vec<F>, Error>
+	where
+		Challenger_: Challenger,
+	{
+		if claims.len() != transcripts.len() {
+			bail!(Error::InvalidArgs(format!(
+				"got {} claims and {} transcripts",
+				claims.len(),
+				transcripts.len(),
+			)));
+		}
+
+		let mut scratch_buffer = create_scratch_buffer(self.params);
+		izip!(claims, transcripts)
+			.enumerate()
+			.map(|(proof, (&claim, transcript))| {
+				self.verifier(claim)
+					.and_then(|verifier| {
+						verifier.verify_with_scratch(transcript, &mut scratch_buffer)
+					})
+					.map_err(|err| Error::BatchProofFailed {
+						proof,
+						source: Box::new(err),
+					})
+			})
+			.collect()
+	}
+}
+
+// scratch buffer used in `fold_chunk`.
+fn create_scratch_buffer<F, FA>(params: &FRIParams<F, FA>) -> Vec<F>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	let max_arity = params
+		.fold_arities()
+		.iter()
+		.copied()
+		.max()
+		.unwrap_or_default();
+	// A committed final message is folded from cosets of the last oracle.
+	let max_arity = match params.final_message_mode() {
+		FinalMessageMode::Plaintext => max_arity,
+		FinalMessageMode::Committed => max_arity.max(params.n_final_challenges()),
+	};
+	let max_buffer_size = 2 * (1 << max_arity);
+	vec![F::default(); max_buffer_size]
+}
+
 /// Verifies that the coset opening provided in the proof is consistent with the VCS commitment.
 #[allow(clippy::too_many_arguments)]
 fn verify_coset_opening<F, MTScheme, B>(
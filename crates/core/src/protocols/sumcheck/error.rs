@@ -67,6 +67,8 @@ pub enum Error {
 		"the number of prime polynomial sums does not match the number of zerocheck compositions"
 	)]
 	IncorrectClaimedPrimeSumsLength,
+	#[error("claim sizes do not partition the compositions of the shared witness prover")]
+	SharedClaimSizesMismatch,
 	#[error("batch proof shape does not conform to the provided indexed claims")]
 	ClaimProofMismatch,
 	#[error("either too many or too few sumcheck challenges")]
@@ -79,10 +81,22 @@ pub enum Error {
 		"specified Lagrange evaluation domain is too small to uniquely recover round polynomial"
 	)]
 	LagrangeDomainTooSmall,
+	#[error("got {got} batching coefficients, expected one per claim ({expected})")]
+	IncorrectNumberOfBatchCoeffs { expected: usize, got: usize },
 	#[error("adding together Lagrange basis evaluations over domains of different sizes")]
 	LagrangeRoundEvalsSizeMismatch,
+	#[error(
+		"Lagrange round evaluations with a zeros prefix of {zeros_prefix_len} and {n_evals} \
+		 explicit evaluations do not describe a nonempty domain"
+	)]
+	MalformedLagrangeRoundEvals {
+		zeros_prefix_len: usize,
+		n_evals: usize,
+	},
 	#[error("length of the zero prefix does not match the expected value")]
 	IncorrectZerosPrefixLen,
+	#[error("the univariate round window size must be nonzero")]
+	ZeroWindowSize,
 	#[error("oracle error: {0}")]
 	Oracle(#[from] OracleError),
 	#[error("witness error: {0}")]
@@ -123,6 +137,10 @@ pub enum VerificationError {
 	IncorrectZerosPrefixLen,
 	#[error("non-zero Lagrange evals count does not match expected univariate domain size")]
 	IncorrectLagrangeRoundEvalsLen,
+	#[error("the batching coefficients in the proof do not match the supplied ones")]
+	IncorrectBatchCoeffs,
 	#[error("claimed multilinear evaluations do not match univariate round at challenge point")]
 	ClaimedSumRoundEvalsMismatch,
+	#[error("univariate round evaluations exceed the degree bound {max_degree}")]
+	RoundDegreeTooHigh { max_degree: usize },
 }
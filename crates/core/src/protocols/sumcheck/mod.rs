@@ -22,6 +22,6 @@ pub use common::*;
 pub use error::*;
 pub use oracles::*;
 pub use prove::batch_prove;
-pub use univariate_zerocheck::batch_verify_zerocheck_univariate_round;
+pub use univariate_zerocheck::{batch_verify_zerocheck_univariate_round, optimal_skip_rounds};
 pub use verify::{batch_verify, batch_verify_with_start};
 pub use zerocheck::ZerocheckClaim;
@@ -1,13 +1,18 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::{Field, TowerField};
+use binius_field::{
+	ExtensionField, Field, PackedExtension, PackedFieldIndexable, PackedSubfield, TowerField,
+};
+use binius_hal::ComputationBackend;
+use binius_math::{CompositionPoly, MultilinearPoly};
+use binius_maybe_rayon::prelude::*;
 use binius_utils::{bail, sorting::is_sorted_ascending};
 use tracing::instrument;
 
 use crate::{
 	fiat_shamir::{CanSample, Challenger},
 	protocols::sumcheck::{
-		prove::{batch_prove::BatchProveStart, SumcheckProver},
+		prove::{batch_prove::BatchProveStart, SumcheckProver, UnivariateZerocheck},
 		univariate::LagrangeRoundEvals,
 		Error,
 	},
@@ -34,6 +39,13 @@ pub trait UnivariateZerocheckProver<'a, F: Field> {
 	/// Maximal required Lagrange domain size among compositions in this prover.
 	fn domain_size(&self, skip_rounds: usize) -> usize;
 
+	/// Maximal minimal Lagrange domain size among compositions in this prover.
+	///
+	/// Unlike [`Self::domain_size`], this is not rounded up to a multiple of `2^skip_rounds` and
+	/// only covers as many points as needed to determine the round polynomial given the degrees of
+	/// the compositions. The batch prover sizes the round message with it.
+	fn tight_domain_size(&self, skip_rounds: usize) -> usize;
+
 	/// Computes the prover message for the univariate round as a univariate polynomial.
 	///
 	/// The prover message mixes the univariate polynomials of the underlying composites using
@@ -67,6 +79,10 @@ impl<'a, F: Field, Prover: UnivariateZerocheckProver<'a, F> + ?Sized>
 		(**self).domain_size(skip_rounds)
 	}
 
+	fn tight_domain_size(&self, skip_rounds: usize) -> usize {
+		(**self).tight_domain_size(skip_rounds)
+	}
+
 	fn execute_univariate_round(
 		&mut self,
 		skip_rounds: usize,
@@ -100,16 +116,300 @@ pub struct BatchZerocheckUnivariateProveOutput<F: Field, Prover> {
 /// provided to [`crate::protocols::sumcheck::batch_verify_zerocheck_univariate_round`] during proof
 /// verification.
 #[allow(clippy::type_complexity)]
-#[instrument(skip_all, level = "debug")]
 pub fn batch_prove_zerocheck_univariate_round<'a, F, Prover, Challenger_>(
+	provers: Vec<Prover>,
+	skip_rounds: usize,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<BatchZerocheckUnivariateProveOutput<F, Box<dyn SumcheckProver<F> + 'a>>, Error>
+where
+	F: TowerField,
+	Prover: UnivariateZerocheckProver<'a, F>,
+	Challenger_: Challenger,
+{
+	batch_prove_zerocheck_univariate_round_with_subfield_coeffs::<F, F, _, _>(
+		provers,
+		skip_rounds,
+		transcript,
+	)
+}
+
+/// Prove a batched univariate zerocheck round, sampling the batching coefficients from a subfield
+/// `FSub` of `F`.
+///
+/// The coefficients are lifted to `F` before being applied, so batching costs the same, but a
+/// false claim survives the random linear combination with probability up to $1 / |FSub|$ instead
+/// of $1 / |F|$. `FSub` must therefore be large enough for the target security level on its own.
+/// The verifier must call
+/// [`batch_verify_zerocheck_univariate_round_with_subfield_coeffs`](crate::protocols::sumcheck::univariate_zerocheck::batch_verify_zerocheck_univariate_round_with_subfield_coeffs)
+/// with the same `FSub`.
+#[allow(clippy::type_complexity)]
+#[instrument(skip_all, level = "debug")]
+pub fn batch_prove_zerocheck_univariate_round_with_subfield_coeffs<
+	'a,
+	F,
+	FSub,
+	Prover,
+	Challenger_,
+>(
+	provers: Vec<Prover>,
+	skip_rounds: usize,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<BatchZerocheckUnivariateProveOutput<F, Box<dyn SumcheckProver<F> + 'a>>, Error>
+where
+	F: TowerField + ExtensionField<FSub>,
+	FSub: TowerField,
+	Prover: UnivariateZerocheckProver<'a, F>,
+	Challenger_: Challenger,
+{
+	let shape = batch_shape(&provers, skip_rounds)?;
+
+	// Executing a univariate round does not touch the transcript, so the coefficients may all be
+	// sampled up front.
+	let batch_coeffs = (0..provers.len())
+		.map(|_| transcript.sample_subfield::<FSub, F>())
+		.collect();
+	execute_batched_univariate_round(provers, skip_rounds, shape, batch_coeffs, transcript)
+}
+
+/// Prove a batched univariate zerocheck round, optionally with externally supplied batching
+/// coefficients.
+///
+/// With `external_batch_coeffs` set to `None` this is [`batch_prove_zerocheck_univariate_round`].
+/// Otherwise the given coefficients, one per prover, are used in place of sampled ones and written
+/// to the transcript, so that the rest of the protocol is bound to them. The caller is responsible
+/// for choosing coefficients that are unpredictable to the provers, for instance by deriving them
+/// from an outer protocol; the verifier must call
+/// [`batch_verify_zerocheck_univariate_round_with_batch_coeffs`](crate::protocols::sumcheck::univariate_zerocheck::batch_verify_zerocheck_univariate_round_with_batch_coeffs)
+/// with the same coefficients.
+#[allow(clippy::type_complexity)]
+#[instrument(skip_all, level = "debug")]
+pub fn batch_prove_zerocheck_univariate_round_with_batch_coeffs<'a, F, Prover, Challenger_>(
+	provers: Vec<Prover>,
+	skip_rounds: usize,
+	external_batch_coeffs: Option<Vec<F>>,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<BatchZerocheckUnivariateProveOutput<F, Box<dyn SumcheckProver<F> + 'a>>, Error>
+where
+	F: TowerField,
+	Prover: UnivariateZerocheckProver<'a, F>,
+	Challenger_: Challenger,
+{
+	let Some(batch_coeffs) = external_batch_coeffs else {
+		return batch_prove_zerocheck_univariate_round(provers, skip_rounds, transcript);
+	};
+	if batch_coeffs.len() != provers.len() {
+		bail!(Error::IncorrectNumberOfBatchCoeffs {
+			expected: provers.len(),
+			got: batch_coeffs.len(),
+		});
+	}
+
+	let shape = batch_shape(&provers, skip_rounds)?;
+	transcript.message().write_scalar_slice(&batch_coeffs);
+	execute_batched_univariate_round(provers, skip_rounds, shape, batch_coeffs, transcript)
+}
+
+/// Executes the univariate round of every prover with its batching coefficient, accumulates the
+/// batched round evaluations and finishes the round.
+#[allow(clippy::type_complexity)]
+fn execute_batched_univariate_round<'a, F, Prover, Challenger_>(
 	mut provers: Vec<Prover>,
 	skip_rounds: usize,
+	shape: BatchShape,
+	batch_coeffs: Vec<F>,
 	transcript: &mut ProverTranscript<Challenger_>,
 ) -> Result<BatchZerocheckUnivariateProveOutput<F, Box<dyn SumcheckProver<F> + 'a>>, Error>
 where
 	F: TowerField,
 	Prover: UnivariateZerocheckProver<'a, F>,
 	Challenger_: Challenger,
+{
+	let BatchShape {
+		max_n_vars,
+		max_domain_size,
+		..
+	} = shape;
+
+	let mut round_evals = LagrangeRoundEvals::zeros(max_domain_size);
+	for (prover, &batch_coeff) in provers.iter_mut().zip(&batch_coeffs) {
+		let prover_round_evals = prover.execute_univariate_round(
+			skip_rounds + prover.n_vars() - max_n_vars,
+			max_domain_size,
+			batch_coeff,
+		)?;
+
+		round_evals.add_assign_lagrange(&(prover_round_evals * batch_coeff))?;
+	}
+
+	finish_univariate_round(provers, skip_rounds, shape, batch_coeffs, round_evals, transcript)
+}
+
+/// Prove a batched univariate zerocheck round, executing the provers in windows of at most
+/// `window_size` provers at a time.
+///
+/// The provers within a window execute their univariate rounds in parallel, and each window's
+/// Lagrange round evaluations are accumulated and dropped before the next window starts. The
+/// window size therefore bounds the number of provers holding univariate round scratch memory at
+/// once, trading parallelism for peak memory usage.
+///
+/// All batching coefficients are sampled up front. Executing a univariate round does not touch the
+/// transcript, so the proof is identical to the one produced by
+/// [`batch_prove_zerocheck_univariate_round`] for any window size.
+///
+/// Note that the provers themselves are retained until the end of the round: folding a prover
+/// requires the univariate challenge, which is only sampled after all round evaluations have been
+/// written to the transcript, so the witness memory of the provers cannot be released earlier.
+#[allow(clippy::type_complexity)]
+#[instrument(skip_all, level = "debug")]
+pub fn batch_prove_zerocheck_univariate_round_windowed<'a, F, Prover, Challenger_>(
+	mut provers: Vec<Prover>,
+	skip_rounds: usize,
+	window_size: usize,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<BatchZerocheckUnivariateProveOutput<F, Box<dyn SumcheckProver<F> + 'a>>, Error>
+where
+	F: TowerField,
+	Prover: UnivariateZerocheckProver<'a, F> + Send,
+	Challenger_: Challenger,
+{
+	if window_size == 0 {
+		bail!(Error::ZeroWindowSize);
+	}
+
+	let shape = batch_shape(&provers, skip_rounds)?;
+	let BatchShape {
+		max_n_vars,
+		max_domain_size,
+		..
+	} = shape;
+
+	let batch_coeffs: Vec<F> = transcript.sample_vec(provers.len());
+
+	let mut round_evals = LagrangeRoundEvals::zeros(max_domain_size);
+	for (window, window_batch_coeffs) in provers
+		.chunks_mut(window_size)
+		.zip(batch_coeffs.chunks(window_size))
+	{
+		let window_round_evals = window
+			.par_iter_mut()
+			.zip(window_batch_coeffs)
+			.map(|(prover, &batch_coeff)| {
+				let prover_round_evals = prover.execute_univariate_round(
+					skip_rounds + prover.n_vars() - max_n_vars,
+					max_domain_size,
+					batch_coeff,
+				)?;
+				Ok(prover_round_evals * batch_coeff)
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		for prover_round_evals in &window_round_evals {
+			round_evals.add_assign_lagrange(prover_round_evals)?;
+		}
+	}
+
+	finish_univariate_round(provers, skip_rounds, shape, batch_coeffs, round_evals, transcript)
+}
+
+/// Prove a batched univariate zerocheck round for several claims sharing the same multilinears.
+///
+/// `prover` holds the shared multilinears once, with the compositions of all claims laid out
+/// consecutively; `claim_sizes` gives the number of compositions in each claim. The univariate
+/// round evaluations of every composition are computed in a single pass over the hypercube, and
+/// the multilinears are projected onto the univariate challenge only once.
+///
+/// The proof is identical to the one produced by [`batch_prove_zerocheck_univariate_round`] given
+/// one standalone prover per claim, and the claims are verified in the same way. The reduction
+/// provers in the output are in claim order.
+#[allow(clippy::type_complexity)]
+#[instrument(skip_all, level = "debug")]
+pub fn batch_prove_zerocheck_univariate_round_shared_witness<
+	'a,
+	'm,
+	F,
+	FDomain,
+	FBase,
+	P,
+	CompositionBase,
+	Composition,
+	M,
+	Backend,
+	Challenger_,
+>(
+	mut prover: UnivariateZerocheck<
+		'a,
+		'm,
+		FDomain,
+		FBase,
+		P,
+		CompositionBase,
+		Composition,
+		M,
+		Backend,
+	>,
+	claim_sizes: &[usize],
+	skip_rounds: usize,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<BatchZerocheckUnivariateProveOutput<F, Box<dyn SumcheckProver<F> + 'a>>, Error>
+where
+	F: TowerField,
+	FDomain: TowerField,
+	FBase: ExtensionField<FDomain>,
+	P: PackedFieldIndexable<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FBase, PackedSubfield: PackedFieldIndexable>
+		+ PackedExtension<FDomain, PackedSubfield: PackedFieldIndexable>,
+	CompositionBase: CompositionPoly<PackedSubfield<P, FBase>> + 'static,
+	Composition: CompositionPoly<P> + 'static,
+	M: MultilinearPoly<P> + Send + Sync + 'm,
+	Backend: ComputationBackend,
+	Challenger_: Challenger,
+{
+	let max_domain_size = prover.tight_domain_size(skip_rounds);
+
+	let batch_coeffs = claim_sizes
+		.iter()
+		.map(|_| transcript.sample())
+		.collect::<Vec<F>>();
+
+	let round_evals = prover.execute_univariate_round_for_claims(
+		skip_rounds,
+		max_domain_size,
+		claim_sizes,
+		&batch_coeffs,
+	)?;
+
+	let zeros_prefix_len = (1 << skip_rounds).min(max_domain_size);
+	if zeros_prefix_len != round_evals.zeros_prefix_len {
+		bail!(Error::IncorrectZerosPrefixLen);
+	}
+
+	transcript.message().write_scalar_slice(&round_evals.evals);
+	let univariate_challenge = transcript.sample();
+
+	let reduction_provers =
+		prover.fold_univariate_round_for_claims(univariate_challenge, claim_sizes)?;
+
+	Ok(BatchZerocheckUnivariateProveOutput {
+		univariate_challenge,
+		batch_prove_start: BatchProveStart {
+			batch_coeffs,
+			reduction_provers,
+		},
+	})
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BatchShape {
+	max_n_vars: usize,
+	min_n_vars: usize,
+	max_domain_size: usize,
+}
+
+fn batch_shape<'a, F, Prover>(provers: &[Prover], skip_rounds: usize) -> Result<BatchShape, Error>
+where
+	F: Field,
+	Prover: UnivariateZerocheckProver<'a, F>,
 {
 	// Check that the provers are in descending order by n_vars
 	if !is_sorted_ascending(provers.iter().map(|prover| prover.n_vars()).rev()) {
@@ -125,24 +425,36 @@ where
 
 	let max_domain_size = provers
 		.iter()
-		.map(|prover| prover.domain_size(skip_rounds + prover.n_vars() - max_n_vars))
+		.map(|prover| prover.tight_domain_size(skip_rounds + prover.n_vars() - max_n_vars))
 		.max()
 		.unwrap_or(0);
 
-	let mut batch_coeffs = Vec::with_capacity(provers.len());
-	let mut round_evals = LagrangeRoundEvals::zeros(max_domain_size);
-	for prover in &mut provers {
-		let next_batch_coeff = transcript.sample();
-		batch_coeffs.push(next_batch_coeff);
-
-		let prover_round_evals = prover.execute_univariate_round(
-			skip_rounds + prover.n_vars() - max_n_vars,
-			max_domain_size,
-			next_batch_coeff,
-		)?;
+	Ok(BatchShape {
+		max_n_vars,
+		min_n_vars,
+		max_domain_size,
+	})
+}
 
-		round_evals.add_assign_lagrange(&(prover_round_evals * next_batch_coeff))?;
-	}
+#[allow(clippy::type_complexity)]
+fn finish_univariate_round<'a, F, Prover, Challenger_>(
+	provers: Vec<Prover>,
+	skip_rounds: usize,
+	shape: BatchShape,
+	batch_coeffs: Vec<F>,
+	round_evals: LagrangeRoundEvals<F>,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<BatchZerocheckUnivariateProveOutput<F, Box<dyn SumcheckProver<F> + 'a>>, Error>
+where
+	F: TowerField,
+	Prover: UnivariateZerocheckProver<'a, F>,
+	Challenger_: Challenger,
+{
+	let BatchShape {
+		max_n_vars,
+		min_n_vars,
+		max_domain_size,
+	} = shape;
 
 	let zeros_prefix_len = (1 << (skip_rounds + min_n_vars - max_n_vars)).min(max_domain_size);
 	if zeros_prefix_len != round_evals.zeros_prefix_len {
@@ -12,7 +12,10 @@ pub mod zerocheck;
 
 pub use batch_prove::{batch_prove, batch_prove_with_start, SumcheckProver};
 pub use batch_prove_univariate_zerocheck::{
-	batch_prove_zerocheck_univariate_round, UnivariateZerocheckProver,
+	batch_prove_zerocheck_univariate_round, batch_prove_zerocheck_univariate_round_shared_witness,
+	batch_prove_zerocheck_univariate_round_windowed,
+	batch_prove_zerocheck_univariate_round_with_batch_coeffs,
+	batch_prove_zerocheck_univariate_round_with_subfield_coeffs, UnivariateZerocheckProver,
 };
 pub use oracles::{
 	constraint_set_sumcheck_prover, constraint_set_zerocheck_prover, split_constraint_set,
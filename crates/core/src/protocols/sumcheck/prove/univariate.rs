@@ -31,7 +31,7 @@ use crate::{
 		univariate::{
 			lagrange_evals_multilinear_extension, univariatizing_reduction_composite_sum_claims,
 		},
-		univariate_zerocheck::{domain_size, extrapolated_scalars_count},
+		univariate_zerocheck::{domain_size, extrapolated_scalars_count, tight_domain_size},
 		Error, VerificationError,
 	},
 };
@@ -322,6 +322,48 @@ pub fn zerocheck_univariate_evals<F, FDomain, FBase, P, Composition, M, Backend>
 	max_domain_size: usize,
 	backend: &Backend,
 ) -> Result<ZerocheckUnivariateEvalsOutput<F, P, Backend>, Error>
+where
+	FDomain: TowerField,
+	FBase: ExtensionField<FDomain>,
+	F: TowerField,
+	P: PackedFieldIndexable<Scalar = F>
+		+ PackedExtension<FBase, PackedSubfield: PackedFieldIndexable>
+		+ PackedExtension<FDomain, PackedSubfield: PackedFieldIndexable>,
+	Composition: CompositionPoly<PackedSubfield<P, FBase>>,
+	M: MultilinearPoly<P> + Send + Sync,
+	Backend: ComputationBackend,
+{
+	// Smaller subcubes are batched together to reduce interpolation/evaluation overhead.
+	const MAX_SUBCUBE_VARS: usize = 12;
+	zerocheck_univariate_evals_chunked::<_, FDomain, FBase, _, _, _, _>(
+		multilinears,
+		compositions,
+		zerocheck_challenges,
+		skip_rounds,
+		max_domain_size,
+		MAX_SUBCUBE_VARS,
+		backend,
+	)
+}
+
+/// Compute univariate skip round evaluations for zerocheck, processing the hypercube in chunks of
+/// at most `2^max(max_subcube_vars, skip_rounds)` vertices.
+///
+/// The witness is sampled one chunk at a time and the round evaluations are accumulated across
+/// chunks, so the scratch memory of each worker is bounded by the chunk size rather than by the
+/// size of the hypercube. Smaller chunks lower peak memory at the cost of more
+/// interpolation/evaluation overhead. The output is identical to [`zerocheck_univariate_evals`]
+/// for any chunk size.
+#[instrument(skip_all, level = "debug")]
+pub fn zerocheck_univariate_evals_chunked<F, FDomain, FBase, P, Composition, M, Backend>(
+	multilinears: &[M],
+	compositions: &[Composition],
+	zerocheck_challenges: &[F],
+	skip_rounds: usize,
+	max_domain_size: usize,
+	max_subcube_vars: usize,
+	backend: &Backend,
+) -> Result<ZerocheckUnivariateEvalsOutput<F, P, Backend>, Error>
 where
 	FDomain: TowerField,
 	FBase: ExtensionField<FDomain>,
@@ -351,11 +393,14 @@ where
 	let composition_degrees = compositions.iter().map(|composition| composition.degree());
 	let composition_max_degree = composition_degrees.clone().max().unwrap_or(0);
 
-	if max_domain_size < domain_size(composition_max_degree, skip_rounds) {
+	if max_domain_size < tight_domain_size(composition_max_degree, skip_rounds) {
 		bail!(Error::LagrangeDomainTooSmall);
 	}
 
-	let min_domain_bits = log2_ceil_usize(max_domain_size).max(1);
+	// Extrapolation happens on the conservative domain, which may exceed a tight max domain.
+	let min_domain_bits =
+		log2_ceil_usize(max_domain_size.max(domain_size(composition_max_degree, skip_rounds)))
+			.max(1);
 	if min_domain_bits > FDomain::N_BITS {
 		bail!(MathError::DomainSizeTooLarge);
 	}
@@ -369,8 +414,7 @@ where
 		.precompute_twiddles();
 
 	// Smaller subcubes are batched together to reduce interpolation/evaluation overhead.
-	const MAX_SUBCUBE_VARS: usize = 12;
-	let log_batch = MAX_SUBCUBE_VARS.min(n_vars).saturating_sub(skip_rounds);
+	let log_batch = max_subcube_vars.min(n_vars).saturating_sub(skip_rounds);
 	let packed_log_batch = log_batch.saturating_sub(P::LOG_WIDTH);
 
 	// Expand the multilinear query in all but the first `skip_rounds` variables,
@@ -483,9 +527,6 @@ where
 					)?
 				}
 
-				let partial_eq_ind_evals_subslice = &partial_eq_ind_evals
-					[subcube_index << packed_log_batch..][..1 << packed_log_batch];
-
 				// Obtain 1 << log_batch partial equality indicator constant factors for each
 				// of the subcubes of size 1 << skip_rounds.
 				let partial_eq_ind_evals_scalars_subslice =
@@ -525,6 +566,11 @@ where
 							);
 						}
 					} else {
+						// Packed equality indicator factors are only addressable when the batch
+						// spans at least one whole packed element.
+						let partial_eq_ind_evals_subslice = &partial_eq_ind_evals
+							[subcube_index << packed_log_batch..][..1 << packed_log_batch];
+
 						#[allow(clippy::needless_range_loop)]
 						for i in 0..round_evals.len() {
 							let mut temp = <PackedSubfield<P, FBase>>::zero();
@@ -607,8 +653,15 @@ fn extrapolate_round_evals<F: TowerField>(
 	max_domain_size: usize,
 ) -> Result<Vec<Vec<F>>, Error> {
 	// Instantiate a large enough NTT over F to be able to forward transform to full domain size.
+	// The staggered evals may span more points than a tight max domain.
 	// REVIEW: should be possible to use an existing FDomain NTT with striding.
-	let ntt = SingleThreadedNTT::with_canonical_field(log2_ceil_usize(max_domain_size))?;
+	let staggered_domain_size = round_evals
+		.iter()
+		.map(|round_evals| round_evals.len() + (1 << skip_rounds))
+		.max()
+		.unwrap_or(0);
+	let log_ntt_size = log2_ceil_usize(max_domain_size.max(staggered_domain_size));
+	let ntt = SingleThreadedNTT::with_canonical_field(log_ntt_size)?;
 
 	// Cache OddInterpolate instances, which, albeit small in practice, take cubic time to create.
 	let mut odd_interpolates = HashMap::new();
@@ -631,8 +684,9 @@ fn extrapolate_round_evals<F: TowerField>(
 		// Obtain novel polynomial basis representation of round evaluations.
 		odd_interpolate.inverse_transform(&ntt, round_evals)?;
 
-		// Use forward NTT to extrapolate novel representation to the max domain size.
-		let next_power_of_two = 1 << log2_ceil_usize(max_domain_size);
+		// Use forward NTT to extrapolate novel representation to the max domain size, or to the
+		// staggered domain size when a tight max domain is smaller than that.
+		let next_power_of_two = 1 << log2_ceil_usize(max_domain_size.max(n));
 		round_evals.resize(next_power_of_two, F::ZERO);
 
 		ntt.forward_transform(round_evals, 0, 0)?;
@@ -733,8 +787,12 @@ const fn extrapolated_evals_packed_len<P: PackedField>(
 
 #[cfg(test)]
 mod tests {
-	use std::sync::Arc;
+	use std::sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	};
 
+	use assert_matches::assert_matches;
 	use binius_field::{
 		arch::{OptimalUnderlier128b, OptimalUnderlier512b},
 		as_packed_field::{PackScalar, PackedType},
@@ -753,7 +811,13 @@ mod tests {
 		composition::{IndexComposition, ProductComposition},
 		polynomial::CompositionScalarAdapter,
 		protocols::{
-			sumcheck::prove::univariate::{domain_size, zerocheck_univariate_evals},
+			sumcheck::{
+				prove::univariate::{
+					domain_size, zerocheck_univariate_evals, zerocheck_univariate_evals_chunked,
+				},
+				univariate_zerocheck::univariate_round_mul_count,
+				Error,
+			},
 			test_utils::generate_zero_product_multilinears,
 		},
 		transparent::eq_ind::EqIndPartialEval,
@@ -829,6 +893,114 @@ mod tests {
 		}
 	}
 
+	/// A product composition that counts the field multiplications it performs.
+	#[derive(Debug, Default)]
+	struct CountingProductComposition<const N: usize> {
+		mul_count: AtomicUsize,
+	}
+
+	impl<P: PackedField, const N: usize> CompositionPoly<P> for CountingProductComposition<N> {
+		fn n_vars(&self) -> usize {
+			N
+		}
+
+		fn degree(&self) -> usize {
+			N
+		}
+
+		fn binary_tower_level(&self) -> usize {
+			0
+		}
+
+		fn expression(&self) -> binius_math::ArithExpr<P::Scalar> {
+			CompositionPoly::<P>::expression(&ProductComposition::<N> {})
+		}
+
+		fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+			self.mul_count
+				.fetch_add((N - 1) * P::WIDTH, Ordering::Relaxed);
+			CompositionPoly::<P>::evaluate(&ProductComposition::<N> {}, query)
+		}
+	}
+
+	#[test]
+	fn univariate_round_mul_count_matches_instrumented_run() {
+		type U = OptimalUnderlier128b;
+		type F = BinaryField128b;
+		type FBase = BinaryField16b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 8;
+
+		let multilinears = generate_zero_product_multilinears::<
+			PackedType<U, BinaryField1b>,
+			PackedType<U, F>,
+		>(&mut rng, n_vars, 3);
+		let backend = make_portable_backend();
+		let zerocheck_challenges = (0..n_vars)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+
+		for skip_rounds in 1..=5 {
+			let composition = Arc::new(CountingProductComposition::<3>::default());
+			let compositions = [composition.clone()];
+
+			zerocheck_univariate_evals::<F, BinaryField8b, FBase, PackedType<U, F>, _, _, _>(
+				&multilinears,
+				&compositions,
+				&zerocheck_challenges[skip_rounds..],
+				skip_rounds,
+				domain_size(3, skip_rounds),
+				&backend,
+			)
+			.unwrap();
+
+			// Each composition evaluation is followed by one multiplication by the equality
+			// indicator.
+			let composition_muls = composition.mul_count.load(Ordering::Relaxed);
+			let eq_ind_muls = composition_muls / 2;
+			assert_eq!(
+				univariate_round_mul_count(3, skip_rounds, n_vars),
+				composition_muls + eq_ind_muls
+			);
+		}
+	}
+
+	#[test]
+	fn zerocheck_univariate_evals_rejects_small_domain() {
+		type U = OptimalUnderlier128b;
+		type F = BinaryField128b;
+		type FBase = BinaryField16b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 7;
+		let skip_rounds = 3;
+
+		let multilinears = generate_zero_product_multilinears::<
+			PackedType<U, BinaryField1b>,
+			PackedType<U, F>,
+		>(&mut rng, n_vars, 4);
+		let compositions =
+			[Arc::new(ProductComposition::<4> {})
+				as Arc<dyn CompositionPoly<PackedType<U, FBase>>>];
+
+		let backend = make_portable_backend();
+		let zerocheck_challenges = (0..n_vars - skip_rounds)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+
+		// The domain is sized for a degree 3 composition, while the composition has degree 4.
+		let result = zerocheck_univariate_evals::<F, BinaryField8b, FBase, PackedType<U, F>, _, _, _>(
+			&multilinears,
+			&compositions,
+			&zerocheck_challenges,
+			skip_rounds,
+			domain_size(3, skip_rounds),
+			&backend,
+		);
+		assert_matches!(result, Err(Error::LagrangeDomainTooSmall));
+	}
+
 	#[test]
 	fn zerocheck_univariate_evals_invariants_basic() {
 		zerocheck_univariate_evals_invariants_helper::<
@@ -904,6 +1076,29 @@ mod tests {
 				)
 				.unwrap();
 
+			// Processing the hypercube in smaller chunks yields the same round evals.
+			for max_subcube_vars in [skip_rounds, skip_rounds + 1] {
+				let chunked_output = zerocheck_univariate_evals_chunked::<
+					F,
+					FDomain,
+					FBase,
+					PackedType<U, F>,
+					_,
+					_,
+					_,
+				>(
+					&multilinears,
+					&compositions,
+					&zerocheck_challenges[skip_rounds..],
+					skip_rounds,
+					max_domain_size,
+					max_subcube_vars,
+					&backend,
+				)
+				.unwrap();
+				assert_eq!(chunked_output.round_evals, output.round_evals);
+			}
+
 			let zerocheck_eq_ind = EqIndPartialEval::new(
 				n_vars - skip_rounds,
 				zerocheck_challenges[skip_rounds..].to_vec(),
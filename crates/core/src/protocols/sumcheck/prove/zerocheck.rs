@@ -33,7 +33,8 @@ use crate::{
 			ProverState, SumcheckInterpolator, SumcheckProver, UnivariateZerocheckProver,
 		},
 		univariate::LagrangeRoundEvals,
-		univariate_zerocheck::domain_size,
+		univariate_zerocheck::{domain_size, tight_domain_size},
+		zerocheck::ExtraProduct,
 		Error, RoundCoeffs,
 	},
 	witness::MultilinearWitness,
@@ -243,8 +244,79 @@ where
 }
 
 impl<'a, 'm, F, FDomain, FBase, P, CompositionBase, Composition, M, Backend>
-	UnivariateZerocheckProver<'a, F>
-	for UnivariateZerocheck<'a, 'm, FDomain, FBase, P, CompositionBase, Composition, M, Backend>
+	UnivariateZerocheck<
+		'a,
+		'm,
+		FDomain,
+		FBase,
+		P,
+		ExtraProduct<CompositionBase>,
+		ExtraProduct<Composition>,
+		M,
+		Backend,
+	>
+where
+	F: Field,
+	FDomain: Field,
+	FBase: ExtensionField<FDomain>,
+	P: PackedFieldIndexable<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FBase>
+		+ PackedExtension<FDomain>,
+	CompositionBase: CompositionPoly<<P as PackedExtension<FBase>>::PackedSubfield>,
+	Composition: CompositionPoly<P>,
+	M: MultilinearPoly<P> + Send + Sync + 'm,
+	Backend: ComputationBackend,
+{
+	/// Constructs a zerocheck prover with all constraints masked by a selector multilinear.
+	///
+	/// Each composition `C` is replaced with `C * selector`, with `selector` appended as the last
+	/// multilinear, so that constraints are only enforced on the rows where the selector is one
+	/// (and padding rows are left unconstrained). The masked compositions still vanish on the whole
+	/// hypercube, hence the `zeros_prefix_len` of the univariate round is unaffected; the extra
+	/// factor raises the degree of every composition by one, which enlarges the univariate domain
+	/// reported by [`UnivariateZerocheckProver::domain_size`] and
+	/// [`UnivariateZerocheckProver::tight_domain_size`].
+	///
+	/// The verifier checks the same claim by wrapping its compositions into [`ExtraProduct`] and
+	/// appending the selector to the claim's multilinears.
+	pub fn new_with_selector(
+		multilinears: Vec<M>,
+		selector: M,
+		zero_claims: impl IntoIterator<Item = (String, CompositionBase, Composition)>,
+		zerocheck_challenges: &[F],
+		evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+		switchover_fn: impl Fn(usize) -> usize,
+		backend: &'a Backend,
+	) -> Result<Self, Error> {
+		let mut multilinears = multilinears;
+		multilinears.push(selector);
+
+		let zero_claims = zero_claims
+			.into_iter()
+			.map(|(name, composition_base, composition)| {
+				(
+					name,
+					ExtraProduct {
+						inner: composition_base,
+					},
+					ExtraProduct { inner: composition },
+				)
+			});
+
+		Self::new(
+			multilinears,
+			zero_claims,
+			zerocheck_challenges,
+			evaluation_domain_factory,
+			switchover_fn,
+			backend,
+		)
+	}
+}
+
+impl<'a, 'm, F, FDomain, FBase, P, CompositionBase, Composition, M, Backend>
+	UnivariateZerocheck<'a, 'm, FDomain, FBase, P, CompositionBase, Composition, M, Backend>
 where
 	F: TowerField,
 	FDomain: TowerField,
@@ -258,81 +330,50 @@ where
 	M: MultilinearPoly<P> + Send + Sync + 'm,
 	Backend: ComputationBackend,
 {
-	fn n_vars(&self) -> usize {
-		self.n_vars
-	}
-
-	fn domain_size(&self, skip_rounds: usize) -> usize {
-		self.compositions
-			.iter()
-			.map(|(_, composition, _)| domain_size(composition.degree(), skip_rounds))
-			.max()
-			.unwrap_or(0)
-	}
-
-	#[instrument(skip_all, level = "debug")]
-	fn execute_univariate_round(
+	/// Executes the univariate round on behalf of several zerocheck claims sharing the
+	/// multilinears of this prover.
+	///
+	/// `claim_sizes` partitions the compositions into consecutive claims, and claim `i` is batched
+	/// with `batch_coeffs[i]`. The round evaluations of all compositions are computed in a single
+	/// pass over the hypercube, and the result equals the sum of the round evaluations that
+	/// standalone provers over each claim would contribute to
+	/// [`batch_prove_zerocheck_univariate_round`](super::batch_prove_zerocheck_univariate_round).
+	pub(super) fn execute_univariate_round_for_claims(
 		&mut self,
 		skip_rounds: usize,
 		max_domain_size: usize,
-		batch_coeff: F,
+		claim_sizes: &[usize],
+		batch_coeffs: &[F],
 	) -> Result<LagrangeRoundEvals<F>, Error> {
-		if self.univariate_evals_output.is_some() {
-			bail!(Error::ExpectedFold);
+		if claim_sizes.iter().sum::<usize>() != self.compositions.len() {
+			bail!(Error::SharedClaimSizesMismatch);
 		}
+		debug_assert_eq!(claim_sizes.len(), batch_coeffs.len());
 
-		// Only use base compositions in the univariate round (it's the whole point)
-		let compositions_base = self
-			.compositions
-			.iter()
-			.map(|(_, composition_base, _)| composition_base)
-			.collect::<Vec<_>>();
-
-		// Output contains values that are needed for computations that happen after
-		// the round challenge has been sampled
-		let univariate_evals_output = zerocheck_univariate_evals::<_, _, FBase, _, _, _, _>(
-			&self.multilinears,
-			&compositions_base,
-			&self.zerocheck_challenges,
-			skip_rounds,
-			max_domain_size,
-			self.backend,
-		)?;
-
-		// Batch together Lagrange round evals using powers of batch_coeff
-		let zeros_prefix_len = 1 << skip_rounds;
-		let batched_round_evals = univariate_evals_output
-			.round_evals
-			.iter()
-			.zip(powers(batch_coeff))
-			.map(|(evals, scalar)| {
-				let round_evals = LagrangeRoundEvals {
-					zeros_prefix_len,
-					evals: evals.clone(),
-				};
-				round_evals * scalar
-			})
-			.try_fold(
-				LagrangeRoundEvals::zeros(max_domain_size),
-				|mut accum, evals| -> Result<_, Error> {
-					accum.add_assign_lagrange(&evals)?;
-					Ok(accum)
-				},
-			)?;
-
-		self.univariate_evals_output = Some(univariate_evals_output);
+		// A standalone prover mixes its compositions with powers of its batch coefficient, which
+		// the batch prover then multiplies by the coefficient once more.
+		let weights = izip!(claim_sizes, batch_coeffs)
+			.flat_map(|(&claim_size, &batch_coeff)| powers(batch_coeff).skip(1).take(claim_size));
 
-		Ok(batched_round_evals)
+		self.execute_weighted_univariate_round(skip_rounds, max_domain_size, weights)
 	}
 
-	#[instrument(skip_all, level = "debug")]
-	fn fold_univariate_round(
-		self: Box<Self>,
+	/// Folds into one regular multilinear prover per claim, following
+	/// [`Self::execute_univariate_round_for_claims`].
+	///
+	/// The multilinears are projected onto the univariate challenge once, and the projections are
+	/// shared between the resulting provers.
+	pub(super) fn fold_univariate_round_for_claims(
+		self,
 		challenge: F,
-	) -> Result<Box<dyn SumcheckProver<F> + 'a>, Error> {
+		claim_sizes: &[usize],
+	) -> Result<Vec<Box<dyn SumcheckProver<F> + 'a>>, Error> {
 		if self.univariate_evals_output.is_none() {
 			bail!(Error::ExpectedExecution);
 		}
+		if claim_sizes.iter().sum::<usize>() != self.compositions.len() {
+			bail!(Error::SharedClaimSizesMismatch);
+		}
 
 		// Once the challenge is known, values required for the instantiation of the
 		// multilinear prover for the remaining rounds become known.
@@ -378,32 +419,156 @@ where
 			.map(|switchover_round| switchover_round.saturating_sub(skip_rounds))
 			.collect::<Vec<_>>();
 
-		let zerocheck_challenges = self.zerocheck_challenges.clone();
-
-		let compositions = self
+		let mut compositions = self
 			.compositions
 			.into_iter()
-			.map(|(_, _, composition)| composition)
-			.collect();
-
-		// This is also regular multilinear zerocheck constructor, but "jump started" in round
-		// `skip_rounds` while using witness with a projected univariate round.
-		// NB: first round evaluator has to be overridden due to issues proving
-		// `P: RepackedExtension<P>` relation in the generic context, as well as the need
-		// to use later round evaluator (as this _is_ a "later" round, albeit numbered at zero)
-		let regular_prover = ZerocheckProver::new(
-			partial_low_multilinears,
-			&switchover_rounds,
-			compositions,
-			partial_eq_ind_evals,
-			zerocheck_challenges,
-			claimed_prime_sums,
-			self.domains,
-			RegularFirstRound::LaterRound,
+			.map(|(_, _, composition)| composition);
+		let mut claimed_prime_sums = claimed_prime_sums.into_iter();
+		let mut domains = self.domains.into_iter();
+
+		// The last prover takes ownership of the equality indicator evaluations, the others clone.
+		let mut partial_eq_ind_evals = Some(partial_eq_ind_evals);
+		let mut provers = Vec::with_capacity(claim_sizes.len());
+		for (index, &claim_size) in claim_sizes.iter().enumerate() {
+			let partial_eq_ind_evals = if index + 1 == claim_sizes.len() {
+				partial_eq_ind_evals.take().expect("taken only once")
+			} else {
+				let evals = partial_eq_ind_evals.as_ref().expect("taken only once");
+				Backend::to_hal_slice(evals.to_vec())
+			};
+
+			// This is also regular multilinear zerocheck constructor, but "jump started" in round
+			// `skip_rounds` while using witness with a projected univariate round.
+			// NB: first round evaluator has to be overridden due to issues proving
+			// `P: RepackedExtension<P>` relation in the generic context, as well as the need
+			// to use later round evaluator (as this _is_ a "later" round, albeit numbered at zero)
+			let regular_prover = ZerocheckProver::new(
+				partial_low_multilinears.clone(),
+				&switchover_rounds,
+				compositions.by_ref().take(claim_size).collect(),
+				partial_eq_ind_evals,
+				self.zerocheck_challenges.clone(),
+				claimed_prime_sums.by_ref().take(claim_size).collect(),
+				domains.by_ref().take(claim_size).collect(),
+				RegularFirstRound::LaterRound,
+				self.backend,
+			)?;
+
+			provers.push(Box::new(regular_prover) as Box<dyn SumcheckProver<F> + 'a>);
+		}
+
+		Ok(provers)
+	}
+
+	fn execute_weighted_univariate_round(
+		&mut self,
+		skip_rounds: usize,
+		max_domain_size: usize,
+		weights: impl IntoIterator<Item = F>,
+	) -> Result<LagrangeRoundEvals<F>, Error> {
+		if self.univariate_evals_output.is_some() {
+			bail!(Error::ExpectedFold);
+		}
+
+		// Only use base compositions in the univariate round (it's the whole point)
+		let compositions_base = self
+			.compositions
+			.iter()
+			.map(|(_, composition_base, _)| composition_base)
+			.collect::<Vec<_>>();
+
+		// Output contains values that are needed for computations that happen after
+		// the round challenge has been sampled
+		let univariate_evals_output = zerocheck_univariate_evals::<_, _, FBase, _, _, _, _>(
+			&self.multilinears,
+			&compositions_base,
+			&self.zerocheck_challenges,
+			skip_rounds,
+			max_domain_size,
 			self.backend,
 		)?;
 
-		Ok(Box::new(regular_prover) as Box<dyn SumcheckProver<F> + 'a>)
+		let zeros_prefix_len = 1 << skip_rounds;
+		let batched_round_evals = univariate_evals_output
+			.round_evals
+			.iter()
+			.zip(weights)
+			.map(|(evals, scalar)| {
+				let round_evals = LagrangeRoundEvals {
+					zeros_prefix_len,
+					evals: evals.clone(),
+				};
+				round_evals * scalar
+			})
+			.try_fold(
+				LagrangeRoundEvals::zeros(max_domain_size),
+				|mut accum, evals| -> Result<_, Error> {
+					accum.add_assign_lagrange(&evals)?;
+					Ok(accum)
+				},
+			)?;
+
+		self.univariate_evals_output = Some(univariate_evals_output);
+
+		Ok(batched_round_evals)
+	}
+}
+
+impl<'a, 'm, F, FDomain, FBase, P, CompositionBase, Composition, M, Backend>
+	UnivariateZerocheckProver<'a, F>
+	for UnivariateZerocheck<'a, 'm, FDomain, FBase, P, CompositionBase, Composition, M, Backend>
+where
+	F: TowerField,
+	FDomain: TowerField,
+	FBase: ExtensionField<FDomain>,
+	P: PackedFieldIndexable<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FBase, PackedSubfield: PackedFieldIndexable>
+		+ PackedExtension<FDomain, PackedSubfield: PackedFieldIndexable>,
+	CompositionBase: CompositionPoly<PackedSubfield<P, FBase>> + 'static,
+	Composition: CompositionPoly<P> + 'static,
+	M: MultilinearPoly<P> + Send + Sync + 'm,
+	Backend: ComputationBackend,
+{
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn domain_size(&self, skip_rounds: usize) -> usize {
+		self.compositions
+			.iter()
+			.map(|(_, composition, _)| domain_size(composition.degree(), skip_rounds))
+			.max()
+			.unwrap_or(0)
+	}
+
+	fn tight_domain_size(&self, skip_rounds: usize) -> usize {
+		self.compositions
+			.iter()
+			.map(|(_, composition, _)| tight_domain_size(composition.degree(), skip_rounds))
+			.max()
+			.unwrap_or(0)
+	}
+
+	#[instrument(skip_all, level = "debug")]
+	fn execute_univariate_round(
+		&mut self,
+		skip_rounds: usize,
+		max_domain_size: usize,
+		batch_coeff: F,
+	) -> Result<LagrangeRoundEvals<F>, Error> {
+		// Batch together Lagrange round evals using powers of batch_coeff
+		self.execute_weighted_univariate_round(skip_rounds, max_domain_size, powers(batch_coeff))
+	}
+
+	#[instrument(skip_all, level = "debug")]
+	fn fold_univariate_round(
+		self: Box<Self>,
+		challenge: F,
+	) -> Result<Box<dyn SumcheckProver<F> + 'a>, Error> {
+		let n_compositions = self.compositions.len();
+		let mut provers = (*self).fold_univariate_round_for_claims(challenge, &[n_compositions])?;
+		Ok(provers.pop().expect("exactly one claim"))
 	}
 }
 
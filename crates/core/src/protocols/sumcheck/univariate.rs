@@ -5,11 +5,13 @@ use std::{
 	ops::{Mul, MulAssign},
 };
 
-use binius_field::{ExtensionField, Field, PackedFieldIndexable, TowerField};
+use binius_field::{
+	util::inner_product_unchecked, ExtensionField, Field, PackedFieldIndexable, TowerField,
+};
 use binius_hal::{make_portable_backend, ComputationBackendExt};
 use binius_math::{
-	EvaluationDomain, EvaluationDomainFactory, IsomorphicEvaluationDomainFactory,
-	MultilinearExtension,
+	EvaluationDomain, EvaluationDomainFactory, InterpolationDomain,
+	IsomorphicEvaluationDomainFactory, MultilinearExtension,
 };
 use binius_utils::{bail, checked_arithmetics::log2_strict_usize, sorting::is_sorted_ascending};
 use bytemuck::zeroed_vec;
@@ -43,9 +45,28 @@ impl<F: Field> LagrangeRoundEvals<F> {
 		}
 	}
 
+	/// Checks that the representation is well-formed.
+	///
+	/// The zeros prefix is implicit and `evals` holds only the evaluations after it, so the
+	/// represented domain has `zeros_prefix_len + evals.len()` points. That size must be nonzero
+	/// and must not overflow; a zeros prefix that covers the entire domain is allowed and
+	/// represents the zero polynomial, see [`Self::zeros`].
+	pub fn validate(&self) -> Result<(), Error> {
+		match self.zeros_prefix_len.checked_add(self.evals.len()) {
+			Some(domain_size) if domain_size > 0 => Ok(()),
+			_ => Err(Error::MalformedLagrangeRoundEvals {
+				zeros_prefix_len: self.zeros_prefix_len,
+				n_evals: self.evals.len(),
+			}),
+		}
+	}
+
 	/// An assigning addition of two polynomials in Lagrange basis. May fail,
 	/// thus it's not simply an `AddAssign` overload due to signature mismatch.
 	pub fn add_assign_lagrange(&mut self, rhs: &Self) -> Result<(), Error> {
+		self.validate()?;
+		rhs.validate()?;
+
 		let lhs_len = self.zeros_prefix_len + self.evals.len();
 		let rhs_len = rhs.zeros_prefix_len + rhs.evals.len();
 
@@ -70,6 +91,67 @@ impl<F: Field> LagrangeRoundEvals<F> {
 	}
 }
 
+impl<F: TowerField> LagrangeRoundEvals<F> {
+	/// Interpolates the round polynomial into its coefficients in the monomial basis, lowest degree
+	/// first.
+	///
+	/// The evaluations, including the zeros prefix, are taken to be over the first
+	/// `zeros_prefix_len + evals.len()` points of the univariate skip domain, so the returned vector
+	/// has that many coefficients.
+	pub fn to_monomial(&self) -> Result<Vec<F>, Error> {
+		self.validate()?;
+
+		let domain_size = self.zeros_prefix_len + self.evals.len();
+		let values = repeat_n(F::ZERO, self.zeros_prefix_len)
+			.chain(self.evals.iter().copied())
+			.collect::<Vec<_>>();
+
+		let domain_factory = IsomorphicEvaluationDomainFactory::<F::Canonical>::default();
+		let domain = InterpolationDomain::from(EvaluationDomainFactory::<F>::create(
+			&domain_factory,
+			domain_size,
+		)?);
+		Ok(domain.interpolate(&values)?)
+	}
+
+	/// Evaluates the round polynomial at `point`.
+	///
+	/// As in [`Self::to_monomial`], the evaluations are over the first
+	/// `zeros_prefix_len + evals.len()` points of the univariate skip domain. The value is the inner
+	/// product of the evaluations with the Lagrange basis polynomials at `point`, skipping the zeros
+	/// prefix. Without any evaluations past the zeros prefix, including over an empty domain, the
+	/// polynomial is zero.
+	pub fn evaluate(&self, point: F) -> Result<F, Error> {
+		if self.evals.is_empty() {
+			return Ok(F::ZERO);
+		}
+		self.validate()?;
+
+		let domain_factory = IsomorphicEvaluationDomainFactory::<F::Canonical>::default();
+		let domain = EvaluationDomainFactory::<F>::create(
+			&domain_factory,
+			self.zeros_prefix_len + self.evals.len(),
+		)?;
+		let lagrange_coeffs = domain.lagrange_evals(point);
+		Ok(inner_product_unchecked::<F, F>(
+			self.evals.iter().copied(),
+			lagrange_coeffs[self.zeros_prefix_len..].iter().copied(),
+		))
+	}
+}
+
+/// Returns the claim that the univariate round reduces to.
+///
+/// The univariate round polynomial sums the batched composites over the skipped variables, and
+/// after the verifier samples `challenge`, the remaining sumcheck claims the round polynomial
+/// evaluated at it.
+pub fn update_claim<F: TowerField>(
+	round_evals: &LagrangeRoundEvals<F>,
+	challenge: F,
+) -> Result<F, Error> {
+	round_evals.evaluate(challenge)
+}
+
 impl<F: Field> Mul<F> for LagrangeRoundEvals<F> {
 	type Output = Self;
 
@@ -221,17 +303,19 @@ where
 mod tests {
 	use std::{iter, sync::Arc};
 
+	use assert_matches::assert_matches;
 	use binius_field::{
 		arch::{OptimalUnderlier128b, OptimalUnderlier512b},
 		as_packed_field::{PackScalar, PackedType},
 		underlier::UnderlierType,
 		AESTowerField128b, AESTowerField16b, AESTowerField8b, BinaryField128b, BinaryField16b,
-		Field, PackedBinaryField1x128b, PackedBinaryField4x32b, PackedFieldIndexable, TowerField,
+		BinaryField32b, BinaryField64b, BinaryField8b, Field, PackedBinaryField1x128b,
+		PackedBinaryField4x32b, PackedField, PackedFieldIndexable, TowerField,
 	};
 	use binius_hal::ComputationBackend;
 	use binius_math::{
 		CompositionPoly, DefaultEvaluationDomainFactory, EvaluationDomainFactory,
-		IsomorphicEvaluationDomainFactory, MultilinearPoly,
+		IsomorphicEvaluationDomainFactory, MultilinearExtension, MultilinearPoly,
 	};
 	use groestl_crypto::Groestl256;
 	use rand::{prelude::StdRng, SeedableRng};
@@ -246,16 +330,24 @@ mod tests {
 				batch_verify, batch_verify_with_start, batch_verify_zerocheck_univariate_round,
 				prove::{
 					batch_prove, batch_prove_with_start, batch_prove_zerocheck_univariate_round,
+					batch_prove_zerocheck_univariate_round_shared_witness,
+					batch_prove_zerocheck_univariate_round_windowed,
+					batch_prove_zerocheck_univariate_round_with_batch_coeffs,
+					batch_prove_zerocheck_univariate_round_with_subfield_coeffs,
 					univariate::{reduce_to_skipped_projection, univariatizing_reduction_prover},
-					SumcheckProver, UnivariateZerocheck,
+					zerocheck, SumcheckProver, UnivariateZerocheck, UnivariateZerocheckProver,
 				},
 				standard_switchover_heuristic,
-				zerocheck::reduce_to_sumchecks,
+				univariate_zerocheck::{
+					batch_verify_zerocheck_univariate_round_with_batch_coeffs,
+					batch_verify_zerocheck_univariate_round_with_subfield_coeffs,
+				},
+				zerocheck::{reduce_to_sumchecks, ExtraProduct},
 				ZerocheckClaim,
 			},
 			test_utils::generate_zero_product_multilinears,
 		},
-		transcript::ProverTranscript,
+		transcript::{ProverTranscript, VerifierTranscript},
 	};
 
 	#[test]
@@ -566,4 +658,629 @@ mod tests {
 			verifier_proof.finalize().unwrap()
 		}
 	}
+
+	#[test]
+	fn test_univariatized_zerocheck_with_selector_masking_padding() {
+		type P = PackedBinaryField1x128b;
+		type PBase = PackedBinaryField4x32b;
+		type F = BinaryField128b;
+		type FBase = BinaryField32b;
+		type FDomain = BinaryField8b;
+
+		let n_vars = 6;
+		let skip_rounds = 3;
+		let n_active = 1 << (n_vars - 1);
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+		let mut rng = StdRng::seed_from_u64(0);
+
+		// The product a * b vanishes on the active rows only; the second half are padding rows
+		// holding arbitrary nonzero values.
+		let mut a = iter::repeat_with(|| <FBase as Field>::random(&mut rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		let mut b = a.clone();
+		b.reverse();
+		for i in 0..n_active {
+			if i % 2 == 0 {
+				a[i] = FBase::ZERO;
+			} else {
+				b[i] = FBase::ZERO;
+			}
+		}
+		let selector = (0..1 << n_vars)
+			.map(|i| {
+				if i < n_active {
+					FBase::ONE
+				} else {
+					FBase::ZERO
+				}
+			})
+			.collect::<Vec<_>>();
+
+		let to_multilinear = |values: &[FBase]| {
+			let packed = values
+				.chunks(PBase::WIDTH)
+				.map(|chunk| PBase::from_fn(|i| chunk[i]))
+				.collect::<Vec<_>>();
+			MultilinearExtension::new(n_vars, packed)
+				.unwrap()
+				.specialize::<P>()
+		};
+		let multilinears = vec![to_multilinear(&a), to_multilinear(&b)];
+		let selector = to_multilinear(&selector);
+
+		// Without the selector, the padding rows violate the constraint.
+		let unmasked = [("pair".to_string(), ProductComposition::<2> {})];
+		assert!(zerocheck::validate_witness(&multilinears, &unmasked).is_err());
+
+		let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let zerocheck_challenges: Vec<F> = proof.sample_vec(n_vars - skip_rounds);
+
+		let prover = UnivariateZerocheck::<FDomain, FBase, P, _, _, _, _>::new_with_selector(
+			multilinears,
+			selector,
+			[("pair".into(), ProductComposition::<2> {}, ProductComposition::<2> {})],
+			&zerocheck_challenges,
+			domain_factory,
+			standard_switchover_heuristic(-2),
+			&backend,
+		)
+		.unwrap();
+
+		let prover_univariate_output =
+			batch_prove_zerocheck_univariate_round(vec![prover], skip_rounds, &mut proof).unwrap();
+		let _ = batch_prove_with_start(
+			prover_univariate_output.batch_prove_start,
+			Vec::<Box<dyn SumcheckProver<F>>>::new(),
+			&mut proof,
+		)
+		.unwrap();
+
+		let mut verifier_proof = proof.into_verifier();
+		let _: Vec<F> = verifier_proof.sample_vec(n_vars - skip_rounds);
+
+		let claims = [ZerocheckClaim::<F, _>::new(
+			n_vars,
+			3,
+			vec![ExtraProduct {
+				inner: ProductComposition::<2> {},
+			}],
+		)
+		.unwrap()];
+		let verifier_univariate_output =
+			batch_verify_zerocheck_univariate_round(&claims, skip_rounds, &mut verifier_proof)
+				.unwrap();
+		let sumcheck_claims = reduce_to_sumchecks(&claims).unwrap();
+		let _ = batch_verify_with_start(
+			verifier_univariate_output.batch_verify_start,
+			&sumcheck_claims,
+			&mut verifier_proof,
+		)
+		.unwrap();
+
+		verifier_proof.finalize().unwrap()
+	}
+
+	#[test]
+	fn test_batch_prove_zerocheck_univariate_round_windowed_matches_all_at_once() {
+		type P = PackedBinaryField1x128b;
+		type PBase = PackedBinaryField4x32b;
+		type F = BinaryField128b;
+		type FDomain = BinaryField8b;
+
+		let max_n_vars = 6;
+		let skip_rounds = 3;
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+		let prove = |window_size: Option<usize>| {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			let zerocheck_challenges: Vec<F> = proof.sample_vec(max_n_vars - skip_rounds);
+
+			let provers = (max_n_vars - 2..=max_n_vars)
+				.rev()
+				.map(|n_vars| {
+					let multilinears =
+						generate_zero_product_multilinears::<PBase, P>(&mut rng, n_vars, 2);
+					UnivariateZerocheck::<FDomain, BinaryField32b, P, _, _, _, _>::new(
+						multilinears,
+						[("pair".into(), ProductComposition::<2> {}, ProductComposition::<2> {})],
+						&zerocheck_challenges[(max_n_vars - n_vars).saturating_sub(skip_rounds)..],
+						domain_factory.clone(),
+						standard_switchover_heuristic(-2),
+						&backend,
+					)
+					.unwrap()
+				})
+				.collect::<Vec<_>>();
+
+			let output = match window_size {
+				Some(window_size) => batch_prove_zerocheck_univariate_round_windowed(
+					provers,
+					skip_rounds,
+					window_size,
+					&mut proof,
+				),
+				None => batch_prove_zerocheck_univariate_round(provers, skip_rounds, &mut proof),
+			}
+			.unwrap();
+
+			let _ = batch_prove_with_start(
+				output.batch_prove_start,
+				Vec::<Box<dyn SumcheckProver<F>>>::new(),
+				&mut proof,
+			)
+			.unwrap();
+			proof.finalize()
+		};
+
+		let expected = prove(None);
+		for window_size in 1..=4 {
+			assert_eq!(prove(Some(window_size)), expected);
+		}
+	}
+
+	#[test]
+	fn test_batch_prove_zerocheck_univariate_round_shared_witness_matches_independent() {
+		type P = PackedBinaryField1x128b;
+		type PBase = PackedBinaryField4x32b;
+		type F = BinaryField128b;
+		type FDomain = BinaryField8b;
+
+		let n_vars = 6;
+		let skip_rounds = 3;
+		let claim_sizes = [2, 1, 3];
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+		let mut rng = StdRng::seed_from_u64(0);
+		let multilinears = generate_zero_product_multilinears::<PBase, P>(&mut rng, n_vars, 2);
+
+		let zero_claims = |n_compositions: usize| {
+			(0..n_compositions).map(|i| {
+				(format!("pair_{i}"), ProductComposition::<2> {}, ProductComposition::<2> {})
+			})
+		};
+
+		let prove = |shared: bool| {
+			let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			let zerocheck_challenges: Vec<F> = proof.sample_vec(n_vars - skip_rounds);
+
+			let new_prover = |n_compositions: usize| {
+				UnivariateZerocheck::<FDomain, BinaryField32b, P, _, _, _, _>::new(
+					multilinears.iter().collect(),
+					zero_claims(n_compositions),
+					&zerocheck_challenges,
+					domain_factory.clone(),
+					standard_switchover_heuristic(-2),
+					&backend,
+				)
+				.unwrap()
+			};
+
+			let output = if shared {
+				batch_prove_zerocheck_univariate_round_shared_witness(
+					new_prover(claim_sizes.iter().sum()),
+					&claim_sizes,
+					skip_rounds,
+					&mut proof,
+				)
+			} else {
+				let provers = claim_sizes
+					.iter()
+					.map(|&claim_size| new_prover(claim_size))
+					.collect();
+				batch_prove_zerocheck_univariate_round(provers, skip_rounds, &mut proof)
+			}
+			.unwrap();
+
+			assert_eq!(output.batch_prove_start.reduction_provers.len(), claim_sizes.len());
+
+			let _ = batch_prove_with_start(
+				output.batch_prove_start,
+				Vec::<Box<dyn SumcheckProver<F>>>::new(),
+				&mut proof,
+			)
+			.unwrap();
+			proof.finalize()
+		};
+
+		assert_eq!(prove(true), prove(false));
+	}
+
+	#[test]
+	fn test_tight_domain_size_shrinks_round_message() {
+		type P = PackedBinaryField1x128b;
+		type PBase = PackedBinaryField4x32b;
+		type F = BinaryField128b;
+		type FDomain = BinaryField8b;
+
+		let n_vars = 6;
+		let skip_rounds = 3;
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let zerocheck_challenges: Vec<F> = proof.sample_vec(n_vars - skip_rounds);
+
+		let multilinears = generate_zero_product_multilinears::<PBase, P>(&mut rng, n_vars, 2);
+		let prover = UnivariateZerocheck::<FDomain, BinaryField32b, P, _, _, _, _>::new(
+			multilinears,
+			[("pair".into(), ProductComposition::<2> {}, ProductComposition::<2> {})],
+			&zerocheck_challenges,
+			domain_factory,
+			standard_switchover_heuristic(-2),
+			&backend,
+		)
+		.unwrap();
+
+		let tight_domain_size = prover.tight_domain_size(skip_rounds);
+		assert!(tight_domain_size < prover.domain_size(skip_rounds));
+
+		let output =
+			batch_prove_zerocheck_univariate_round(vec![prover], skip_rounds, &mut proof).unwrap();
+		let _ = batch_prove_with_start(
+			output.batch_prove_start,
+			Vec::<Box<dyn SumcheckProver<F>>>::new(),
+			&mut proof,
+		)
+		.unwrap();
+
+		let mut verifier_proof = proof.into_verifier();
+		let verifier_zerocheck_challenges: Vec<F> = verifier_proof.sample_vec(n_vars - skip_rounds);
+		assert_eq!(verifier_zerocheck_challenges, zerocheck_challenges);
+
+		let claims =
+			vec![ZerocheckClaim::<F, _>::new(n_vars, 2, vec![ProductComposition::<2> {}]).unwrap()];
+		let verifier_univariate_output =
+			batch_verify_zerocheck_univariate_round(&claims, skip_rounds, &mut verifier_proof)
+				.unwrap();
+		let sumcheck_claims = reduce_to_sumchecks(&claims).unwrap();
+		let _ = batch_verify_with_start(
+			verifier_univariate_output.batch_verify_start,
+			&sumcheck_claims,
+			&mut verifier_proof,
+		)
+		.unwrap();
+		verifier_proof.finalize().unwrap();
+	}
+
+	#[test]
+	fn test_subfield_batch_coeffs_prove_and_verify() {
+		type P = PackedBinaryField1x128b;
+		type PBase = PackedBinaryField4x32b;
+		type F = BinaryField128b;
+		type FSub = BinaryField64b;
+		type FDomain = BinaryField8b;
+
+		let max_n_vars = 6;
+		let skip_rounds = 3;
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let zerocheck_challenges: Vec<F> = proof.sample_vec(max_n_vars - skip_rounds);
+
+		let n_vars_range = (max_n_vars - 1..=max_n_vars).rev();
+		let provers = n_vars_range
+			.clone()
+			.map(|n_vars| {
+				let multilinears =
+					generate_zero_product_multilinears::<PBase, P>(&mut rng, n_vars, 2);
+				UnivariateZerocheck::<FDomain, BinaryField32b, P, _, _, _, _>::new(
+					multilinears,
+					[("pair".into(), ProductComposition::<2> {}, ProductComposition::<2> {})],
+					&zerocheck_challenges[(max_n_vars - n_vars).saturating_sub(skip_rounds)..],
+					domain_factory.clone(),
+					standard_switchover_heuristic(-2),
+					&backend,
+				)
+				.unwrap()
+			})
+			.collect::<Vec<_>>();
+
+		let output = batch_prove_zerocheck_univariate_round_with_subfield_coeffs::<F, FSub, _, _>(
+			provers,
+			skip_rounds,
+			&mut proof,
+		)
+		.unwrap();
+		let _ = batch_prove_with_start(
+			output.batch_prove_start,
+			Vec::<Box<dyn SumcheckProver<F>>>::new(),
+			&mut proof,
+		)
+		.unwrap();
+		let proof = proof.finalize();
+
+		let claims = n_vars_range
+			.map(|n_vars| {
+				ZerocheckClaim::<F, _>::new(n_vars, 2, vec![ProductComposition::<2> {}]).unwrap()
+			})
+			.collect::<Vec<_>>();
+		let sumcheck_claims = reduce_to_sumchecks(&claims).unwrap();
+
+		let mut verifier_proof =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
+		let _: Vec<F> = verifier_proof.sample_vec(max_n_vars - skip_rounds);
+		let verifier_univariate_output =
+			batch_verify_zerocheck_univariate_round_with_subfield_coeffs::<F, FSub, _, _>(
+				&claims,
+				skip_rounds,
+				&mut verifier_proof,
+			)
+			.unwrap();
+		for &batch_coeff in &verifier_univariate_output.batch_verify_start.batch_coeffs {
+			assert!(FSub::try_from(batch_coeff).is_ok());
+		}
+		let _ = batch_verify_with_start(
+			verifier_univariate_output.batch_verify_start,
+			&sumcheck_claims,
+			&mut verifier_proof,
+		)
+		.unwrap();
+		verifier_proof.finalize().unwrap();
+
+		// A verifier sampling full field coefficients derives a different batch and rejects.
+		let mut verifier_proof = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		let _: Vec<F> = verifier_proof.sample_vec(max_n_vars - skip_rounds);
+		let result =
+			batch_verify_zerocheck_univariate_round(&claims, skip_rounds, &mut verifier_proof)
+				.and_then(|output| {
+					batch_verify_with_start(
+						output.batch_verify_start,
+						&sumcheck_claims,
+						&mut verifier_proof,
+					)
+				});
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn test_external_batch_coeffs_prove_and_verify() {
+		type P = PackedBinaryField1x128b;
+		type PBase = PackedBinaryField4x32b;
+		type F = BinaryField128b;
+		type FDomain = BinaryField8b;
+
+		let max_n_vars = 6;
+		let skip_rounds = 3;
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+		let n_vars_range = (max_n_vars - 1..=max_n_vars).rev();
+		let make_provers = |zerocheck_challenges: &[F]| {
+			let mut rng = StdRng::seed_from_u64(0);
+			n_vars_range
+				.clone()
+				.map(|n_vars| {
+					let multilinears =
+						generate_zero_product_multilinears::<PBase, P>(&mut rng, n_vars, 2);
+					UnivariateZerocheck::<FDomain, BinaryField32b, P, _, _, _, _>::new(
+						multilinears,
+						[("pair".into(), ProductComposition::<2> {}, ProductComposition::<2> {})],
+						&zerocheck_challenges[(max_n_vars - n_vars).saturating_sub(skip_rounds)..],
+						domain_factory.clone(),
+						standard_switchover_heuristic(-2),
+						&backend,
+					)
+					.unwrap()
+				})
+				.collect::<Vec<_>>()
+		};
+
+		// Sample the batching coefficients as usual.
+		let mut sampled_proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let zerocheck_challenges: Vec<F> = sampled_proof.sample_vec(max_n_vars - skip_rounds);
+		let sampled_output = batch_prove_zerocheck_univariate_round_with_batch_coeffs(
+			make_provers(&zerocheck_challenges),
+			skip_rounds,
+			None,
+			&mut sampled_proof,
+		)
+		.unwrap();
+		let batch_coeffs = sampled_output.batch_prove_start.batch_coeffs;
+		let sampled_proof = sampled_proof.finalize();
+
+		// Supplying the same coefficients only prepends them to the proof.
+		let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let zerocheck_challenges: Vec<F> = proof.sample_vec(max_n_vars - skip_rounds);
+		let output = batch_prove_zerocheck_univariate_round_with_batch_coeffs(
+			make_provers(&zerocheck_challenges),
+			skip_rounds,
+			Some(batch_coeffs.clone()),
+			&mut proof,
+		)
+		.unwrap();
+		assert_eq!(output.batch_prove_start.batch_coeffs, batch_coeffs);
+		let round_evals_start = batch_coeffs.len() * size_of::<F>();
+		let _ = batch_prove_with_start(
+			output.batch_prove_start,
+			Vec::<Box<dyn SumcheckProver<F>>>::new(),
+			&mut proof,
+		)
+		.unwrap();
+		let proof = proof.finalize();
+		assert_eq!(
+			proof[round_evals_start..round_evals_start + sampled_proof.len()],
+			sampled_proof[..]
+		);
+
+		let claims = n_vars_range
+			.map(|n_vars| {
+				ZerocheckClaim::<F, _>::new(n_vars, 2, vec![ProductComposition::<2> {}]).unwrap()
+			})
+			.collect::<Vec<_>>();
+		let sumcheck_claims = reduce_to_sumchecks(&claims).unwrap();
+
+		let mut verifier_proof =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
+		let _: Vec<F> = verifier_proof.sample_vec(max_n_vars - skip_rounds);
+		let verifier_univariate_output = batch_verify_zerocheck_univariate_round_with_batch_coeffs(
+			&claims,
+			skip_rounds,
+			Some(batch_coeffs.clone()),
+			&mut verifier_proof,
+		)
+		.unwrap();
+		assert_eq!(verifier_univariate_output.batch_verify_start.batch_coeffs, batch_coeffs);
+		let _ = batch_verify_with_start(
+			verifier_univariate_output.batch_verify_start,
+			&sumcheck_claims,
+			&mut verifier_proof,
+		)
+		.unwrap();
+		verifier_proof.finalize().unwrap();
+
+		// A verifier expecting different coefficients rejects the proof.
+		let mut wrong_coeffs = batch_coeffs;
+		wrong_coeffs[0] += F::ONE;
+		let mut verifier_proof = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		let _: Vec<F> = verifier_proof.sample_vec(max_n_vars - skip_rounds);
+		let result = batch_verify_zerocheck_univariate_round_with_batch_coeffs(
+			&claims,
+			skip_rounds,
+			Some(wrong_coeffs),
+			&mut verifier_proof,
+		);
+		assert_matches!(result, Err(Error::Verification(VerificationError::IncorrectBatchCoeffs)));
+	}
+
+	#[test]
+	fn test_update_claim_matches_manual_evaluation() {
+		type F = BinaryField128b;
+
+		let domain_factory = IsomorphicEvaluationDomainFactory::<F>::default();
+		let domain = EvaluationDomainFactory::<F>::create(&domain_factory, 4).unwrap();
+		let points = domain.finite_points();
+
+		// The cubic (x - p_0)(x - p_1)(3 + 5x) vanishes on the two-point zeros prefix.
+		let coeffs = [
+			points[0] * points[1] * F::new(3),
+			points[0] * points[1] * F::new(5) - (points[0] + points[1]) * F::new(3),
+			F::new(3) - (points[0] + points[1]) * F::new(5),
+			F::new(5),
+		];
+		let round_evals = LagrangeRoundEvals {
+			zeros_prefix_len: 2,
+			evals: points[2..]
+				.iter()
+				.map(|&point| binius_math::evaluate_univariate(&coeffs, point))
+				.collect(),
+		};
+
+		let challenge = F::new(0x1234_5678);
+		let expected = binius_math::evaluate_univariate(&coeffs, challenge);
+		assert_eq!(update_claim(&round_evals, challenge).unwrap(), expected);
+
+		// The claim at a domain point is the evaluation there.
+		for (i, &point) in points.iter().enumerate() {
+			let expected = if i < 2 {
+				F::ZERO
+			} else {
+				round_evals.evals[i - 2]
+			};
+			assert_eq!(update_claim(&round_evals, point).unwrap(), expected);
+		}
+	}
+
+	#[test]
+	fn test_lagrange_round_evals_to_monomial_roundtrip() {
+		type F = BinaryField128b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let domain_size = 16;
+		let domain_factory = IsomorphicEvaluationDomainFactory::<F>::default();
+		let domain = EvaluationDomainFactory::<F>::create(&domain_factory, domain_size).unwrap();
+
+		for zeros_prefix_len in [0, 4] {
+			// A random polynomial of degree less than the domain size that vanishes on the zeros
+			// prefix of the domain.
+			let mut coeffs = vec![F::ONE];
+			for &point in &domain.finite_points()[..zeros_prefix_len] {
+				let mut shifted = vec![F::ZERO];
+				shifted.extend_from_slice(&coeffs);
+				for (shifted_coeff, &coeff) in iter::zip(&mut shifted, &coeffs) {
+					*shifted_coeff -= point * coeff;
+				}
+				coeffs = shifted;
+			}
+			let quotient = iter::repeat_with(|| <F as Field>::random(&mut rng))
+				.take(domain_size - zeros_prefix_len)
+				.collect::<Vec<_>>();
+			let mut product = vec![F::ZERO; domain_size];
+			for (i, &lhs) in coeffs.iter().enumerate() {
+				for (j, &rhs) in quotient.iter().enumerate() {
+					product[i + j] += lhs * rhs;
+				}
+			}
+
+			let values = domain
+				.finite_points()
+				.iter()
+				.map(|&point| binius_math::evaluate_univariate(&product, point))
+				.collect::<Vec<_>>();
+			assert!(values[..zeros_prefix_len]
+				.iter()
+				.all(|&value| value == F::ZERO));
+
+			let round_evals = LagrangeRoundEvals {
+				zeros_prefix_len,
+				evals: values[zeros_prefix_len..].to_vec(),
+			};
+			assert_eq!(round_evals.to_monomial().unwrap(), product);
+		}
+	}
+
+	#[test]
+	fn test_lagrange_round_evals_validate() {
+		type F = BinaryField128b;
+
+		let valid = [
+			LagrangeRoundEvals::<F>::zeros(4),
+			LagrangeRoundEvals {
+				zeros_prefix_len: 0,
+				evals: vec![F::ONE; 4],
+			},
+			LagrangeRoundEvals {
+				zeros_prefix_len: 4,
+				evals: vec![F::ONE; 12],
+			},
+		];
+		for round_evals in &valid {
+			round_evals.validate().unwrap();
+		}
+
+		let empty = LagrangeRoundEvals::<F>::zeros(0);
+		assert_matches!(
+			empty.validate(),
+			Err(Error::MalformedLagrangeRoundEvals {
+				zeros_prefix_len: 0,
+				n_evals: 0,
+			})
+		);
+
+		let overflowing = LagrangeRoundEvals {
+			zeros_prefix_len: usize::MAX,
+			evals: vec![F::ONE],
+		};
+		assert_matches!(overflowing.validate(), Err(Error::MalformedLagrangeRoundEvals { .. }));
+
+		// Malformed operands are rejected before they are combined.
+		let mut lhs = valid[2].clone();
+		assert_matches!(
+			lhs.add_assign_lagrange(&overflowing),
+			Err(Error::MalformedLagrangeRoundEvals { .. })
+		);
+		assert_eq!(lhs.evals, valid[2].evals);
+	}
 }
@@ -1,12 +1,15 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use binius_field::{util::inner_product_unchecked, Field, TowerField};
+use std::iter;
+
+use binius_field::{ExtensionField, Field, TowerField};
 use binius_math::{CompositionPoly, EvaluationDomainFactory, IsomorphicEvaluationDomainFactory};
 use binius_utils::{bail, sorting::is_sorted_ascending};
 use tracing::instrument;
 
 use super::{
 	error::{Error, VerificationError},
+	univariate::{update_claim, LagrangeRoundEvals},
 	verify::BatchVerifyStart,
 	zerocheck::ZerocheckClaim,
 };
@@ -31,23 +34,234 @@ pub const fn domain_size(composition_degree: usize, skip_rounds: usize) -> usize
 	composition_degree << skip_rounds
 }
 
+/// Minimal univariatized domain size.
+///
+/// The round polynomial of a composition of degree $d$ has degree $d (2^n - 1)$, so it is
+/// uniquely determined by its evaluations on $d (2^n - 1) + 1$ points. This is at most
+/// [`domain_size`] and is strictly smaller whenever $d > 1$, which shrinks the round message by
+/// $d - 1$ evaluations.
+pub const fn tight_domain_size(composition_degree: usize, skip_rounds: usize) -> usize {
+	if composition_degree == 0 {
+		0
+	} else {
+		round_degree(composition_degree, skip_rounds) + 1
+	}
+}
+
 /// For zerocheck, we know that a honest prover would evaluate to zero on the skipped domain.
 pub const fn extrapolated_scalars_count(composition_degree: usize, skip_rounds: usize) -> usize {
 	composition_degree.saturating_sub(1) << skip_rounds
 }
 
+/// Degree of the univariate round polynomial of a zerocheck with the given composition degree.
+///
+/// Univariatizing `skip_rounds` variables turns each multilinear into a univariate of degree
+/// $2^n - 1$, so the composition has degree $d (2^n - 1)$.
+pub const fn round_degree(composition_degree: usize, skip_rounds: usize) -> usize {
+	composition_degree * ((1 << skip_rounds) - 1)
+}
+
+/// Estimated number of field multiplications performed by the univariate round of a zerocheck.
+///
+/// The prover evaluates the composition on the [`extrapolated_scalars_count`] points outside of the
+/// skipped hypercube for each of the $2^{n - k}$ subcubes, where $k$ is `skip_rounds`. Each such
+/// evaluation costs roughly `composition_degree` multiplications: $d - 1$ for a degree $d$
+/// product plus one to weight it by the equality indicator. NTT extrapolation and the final
+/// interpolation are not included, as they are dominated by the composition evaluations.
+///
+/// This is a cost model meant for choosing `skip_rounds`, not an exact operation count.
+pub const fn univariate_round_mul_count(
+	composition_degree: usize,
+	skip_rounds: usize,
+	n_vars: usize,
+) -> usize {
+	(extrapolated_scalars_count(composition_degree, skip_rounds) << (n_vars - skip_rounds))
+		* composition_degree
+}
+
+/// Bit width of the extension field that the multilinear rounds of a zerocheck run over.
+const EXTENSION_FIELD_BITS: usize = 128;
+
+/// Estimated prover cost of a zerocheck that univariatizes `skip_rounds` of its `n_vars`
+/// variables.
+///
+/// The cost is a count of field multiplications, each weighted by the bit width of the field it
+/// happens in. The univariate round runs over a domain field of `field_bits` bits and is charged
+/// [`univariate_round_mul_count`] plus the additive NTTs extrapolating each of the
+/// `composition_degree` multilinears from every subcube onto the [`domain_size`] points. The
+/// remaining $n - k$ multilinear rounds run over the 128-bit extension field, each evaluating the
+/// composition on `composition_degree` points over half of the remaining hypercube.
+pub const fn zerocheck_prover_cost(
+	composition_degree: usize,
+	skip_rounds: usize,
+	n_vars: usize,
+	field_bits: usize,
+) -> usize {
+	let univariate_cost = if skip_rounds == 0 {
+		0
+	} else {
+		// An NTT of size $2^k$ costs $k 2^{k - 1}$ multiplications, and each multilinear on each of
+		// the $2^{n - k}$ subcubes takes one inverse NTT plus one forward NTT per extrapolated
+		// coset.
+		let ntt_mul_count = (composition_degree * composition_degree * skip_rounds) << (n_vars - 1);
+		(univariate_round_mul_count(composition_degree, skip_rounds, n_vars) + ntt_mul_count)
+			* field_bits
+	};
+	let multilinear_mul_count =
+		composition_degree * composition_degree * ((1 << (n_vars - skip_rounds)) - 1);
+	univariate_cost + multilinear_mul_count * EXTENSION_FIELD_BITS
+}
+
+/// Number of variables to univariatize that minimizes [`zerocheck_prover_cost`].
+///
+/// A univariate domain of [`domain_size`] points has to fit into the `field_bits`-bit domain
+/// field, which bounds the feasible `skip_rounds` from above along with `n_vars`. Ties are broken
+/// towards fewer skipped rounds.
+pub fn optimal_skip_rounds(composition_degree: usize, n_vars: usize, field_bits: usize) -> usize {
+	(0..=n_vars)
+		.take_while(|&skip_rounds| {
+			skip_rounds == 0
+				|| field_bits >= usize::BITS as usize
+				|| domain_size(composition_degree, skip_rounds) <= 1 << field_bits
+		})
+		.min_by_key(|&skip_rounds| {
+			zerocheck_prover_cost(composition_degree, skip_rounds, n_vars, field_bits)
+		})
+		.expect("skip_rounds = 0 is always feasible")
+}
+
+/// Checks that univariate round evaluations in Lagrange basis are consistent with a polynomial of
+/// degree at most `max_degree`.
+///
+/// The evaluations are over the lexicographically first points of the binary subspace, including
+/// the zeros prefix. Finite differences do not apply over a characteristic two subspace domain, so
+/// instead the polynomial is interpolated on the first `max_degree + 1` points and extrapolated to
+/// each of the remaining points, all of which must match the provided evaluations.
+pub fn check_round_degree<F: TowerField>(
+	round_evals: &LagrangeRoundEvals<F>,
+	max_degree: usize,
+) -> Result<(), Error> {
+	let domain_size = round_evals.zeros_prefix_len + round_evals.evals.len();
+	if max_degree + 1 >= domain_size {
+		return Ok(());
+	}
+
+	let values = iter::repeat_n(F::ZERO, round_evals.zeros_prefix_len)
+		.chain(round_evals.evals.iter().copied())
+		.collect::<Vec<_>>();
+
+	let domain_factory = IsomorphicEvaluationDomainFactory::<F::Canonical>::default();
+	let full_domain = EvaluationDomainFactory::<F>::create(&domain_factory, domain_size)?;
+	let prefix_domain = EvaluationDomainFactory::<F>::create(&domain_factory, max_degree + 1)?;
+
+	let (prefix_values, suffix_values) = values.split_at(max_degree + 1);
+	for (&point, &value) in full_domain.finite_points()[max_degree + 1..]
+		.iter()
+		.zip(suffix_values)
+	{
+		if prefix_domain.extrapolate(prefix_values, point)? != value {
+			bail!(VerificationError::RoundDegreeTooHigh { max_degree });
+		}
+	}
+
+	Ok(())
+}
+
 /// Verify a batched zerocheck univariate round.
 ///
 /// Unlike `batch_verify`, all round evaluations are on a univariate domain of predetermined size,
 /// and batching happens over a single round. This method batches claimed univariatized evaluations
 /// of the underlying composites, checks that univariatized round polynomial agrees with them on
 /// challenge point, and outputs sumcheck claims for `batch_verify` on the remaining variables.
-#[instrument(skip_all, level = "debug")]
 pub fn batch_verify_zerocheck_univariate_round<F, Composition, Challenger_>(
 	claims: &[ZerocheckClaim<F, Composition>],
 	skip_rounds: usize,
 	transcript: &mut VerifierTranscript<Challenger_>,
 ) -> Result<BatchZerocheckUnivariateOutput<F>, Error>
+where
+	F: TowerField,
+	Composition: CompositionPoly<F>,
+	Challenger_: Challenger,
+{
+	batch_verify_zerocheck_univariate_round_with_subfield_coeffs::<F, F, _, _>(
+		claims,
+		skip_rounds,
+		transcript,
+	)
+}
+
+/// Verify a batched zerocheck univariate round whose batching coefficients are sampled from a
+/// subfield `FSub` of `F`.
+///
+/// This is the counterpart of
+/// [`batch_prove_zerocheck_univariate_round_with_subfield_coeffs`](super::prove::batch_prove_zerocheck_univariate_round_with_subfield_coeffs).
+/// The batching soundness error grows from $1 / |F|$ to $1 / |FSub|$, while the univariate
+/// challenge is still sampled from `F`.
+#[instrument(skip_all, level = "debug")]
+pub fn batch_verify_zerocheck_univariate_round_with_subfield_coeffs<
+	F,
+	FSub,
+	Composition,
+	Challenger_,
+>(
+	claims: &[ZerocheckClaim<F, Composition>],
+	skip_rounds: usize,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<BatchZerocheckUnivariateOutput<F>, Error>
+where
+	F: TowerField + ExtensionField<FSub>,
+	FSub: TowerField,
+	Composition: CompositionPoly<F>,
+	Challenger_: Challenger,
+{
+	let batch_coeffs = (0..claims.len())
+		.map(|_| transcript.sample_subfield::<FSub, F>())
+		.collect();
+	verify_batched_univariate_round(claims, skip_rounds, batch_coeffs, transcript)
+}
+
+/// Verify a batched zerocheck univariate round, optionally with externally supplied batching
+/// coefficients.
+///
+/// This is the counterpart of
+/// [`batch_prove_zerocheck_univariate_round_with_batch_coeffs`](super::prove::batch_prove_zerocheck_univariate_round_with_batch_coeffs).
+/// With `external_batch_coeffs` set, the coefficients written by the prover must equal the given
+/// ones, one per claim.
+#[instrument(skip_all, level = "debug")]
+pub fn batch_verify_zerocheck_univariate_round_with_batch_coeffs<F, Composition, Challenger_>(
+	claims: &[ZerocheckClaim<F, Composition>],
+	skip_rounds: usize,
+	external_batch_coeffs: Option<Vec<F>>,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<BatchZerocheckUnivariateOutput<F>, Error>
+where
+	F: TowerField,
+	Composition: CompositionPoly<F>,
+	Challenger_: Challenger,
+{
+	let Some(batch_coeffs) = external_batch_coeffs else {
+		return batch_verify_zerocheck_univariate_round(claims, skip_rounds, transcript);
+	};
+	if batch_coeffs.len() != claims.len() {
+		bail!(Error::IncorrectNumberOfBatchCoeffs {
+			expected: claims.len(),
+			got: batch_coeffs.len(),
+		});
+	}
+
+	let written_batch_coeffs: Vec<F> = transcript.message().read_scalar_slice(claims.len())?;
+	if written_batch_coeffs != batch_coeffs {
+		bail!(VerificationError::IncorrectBatchCoeffs);
+	}
+	verify_batched_univariate_round(claims, skip_rounds, batch_coeffs, transcript)
+}
+
+fn verify_batched_univariate_round<F, Composition, Challenger_>(
+	claims: &[ZerocheckClaim<F, Composition>],
+	skip_rounds: usize,
+	batch_coeffs: Vec<F>,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<BatchZerocheckUnivariateOutput<F>, Error>
 where
 	F: TowerField,
 	Composition: CompositionPoly<F>,
@@ -68,35 +282,39 @@ where
 	let max_domain_size = claims
 		.iter()
 		.map(|claim| {
-			domain_size(claim.max_individual_degree(), skip_rounds + claim.n_vars() - max_n_vars)
+			tight_domain_size(
+				claim.max_individual_degree(),
+				skip_rounds + claim.n_vars() - max_n_vars,
+			)
 		})
 		.max()
 		.unwrap_or(0);
 	let zeros_prefix_len = (1 << (skip_rounds + min_n_vars - max_n_vars)).min(max_domain_size);
 
-	let mut batch_coeffs = Vec::with_capacity(claims.len());
-	let mut max_degree = 0;
-	for claim in claims {
-		let next_batch_coeff = transcript.sample();
-		batch_coeffs.push(next_batch_coeff);
-		max_degree = max_degree.max(claim.max_individual_degree() + 1);
-	}
+	let max_degree = claims
+		.iter()
+		.map(|claim| claim.max_individual_degree() + 1)
+		.max()
+		.unwrap_or(0);
 
-	let round_evals = transcript
-		.message()
-		.read_scalar_slice(max_domain_size - zeros_prefix_len)?;
+	let round_evals = LagrangeRoundEvals {
+		zeros_prefix_len,
+		evals: transcript
+			.message()
+			.read_scalar_slice(max_domain_size - zeros_prefix_len)?,
+	};
 	let univariate_challenge = transcript.sample();
 
-	let evaluation_domain = EvaluationDomainFactory::<F>::create(
-		&IsomorphicEvaluationDomainFactory::<F::Canonical>::default(),
-		max_domain_size,
-	)?;
+	let max_round_degree = claims
+		.iter()
+		.map(|claim| {
+			round_degree(claim.max_individual_degree(), skip_rounds + claim.n_vars() - max_n_vars)
+		})
+		.max()
+		.unwrap_or(0);
+	check_round_degree(&round_evals, max_round_degree)?;
 
-	let lagrange_coeffs = evaluation_domain.lagrange_evals(univariate_challenge);
-	let sum = inner_product_unchecked::<F, F>(
-		round_evals,
-		lagrange_coeffs[zeros_prefix_len..].iter().copied(),
-	);
+	let sum = update_claim(&round_evals, univariate_challenge)?;
 
 	let batch_verify_start = BatchVerifyStart {
 		batch_coeffs,
@@ -112,3 +330,83 @@ where
 
 	Ok(output)
 }
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+	use binius_field::BinaryField128b;
+	use binius_math::evaluate_univariate;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	#[test]
+	fn test_check_round_degree() {
+		type F = BinaryField128b;
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let domain_size = 16;
+		let domain = EvaluationDomainFactory::<F>::create(
+			&IsomorphicEvaluationDomainFactory::<F>::default(),
+			domain_size,
+		)
+		.unwrap();
+
+		let degree = 5;
+		let coeffs = (0..=degree)
+			.map(|_| <F as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+		let round_evals = LagrangeRoundEvals {
+			zeros_prefix_len: 0,
+			evals: domain
+				.finite_points()
+				.iter()
+				.map(|&point| evaluate_univariate(&coeffs, point))
+				.collect(),
+		};
+
+		assert!(check_round_degree(&round_evals, degree).is_ok());
+		assert!(check_round_degree(&round_evals, domain_size - 1).is_ok());
+		assert_matches!(
+			check_round_degree(&round_evals, degree - 1),
+			Err(Error::Verification(VerificationError::RoundDegreeTooHigh { max_degree: 4 }))
+		);
+	}
+
+	#[test]
+	fn test_optimal_skip_rounds_minimizes_cost() {
+		for (composition_degree, n_vars, field_bits) in
+			[(2, 20, 16), (3, 20, 16), (4, 12, 8), (2, 18, 32), (5, 4, 8)]
+		{
+			let optimal = optimal_skip_rounds(composition_degree, n_vars, field_bits);
+			assert!(optimal <= n_vars);
+			assert!(domain_size(composition_degree, optimal) <= 1 << field_bits);
+
+			let optimal_cost =
+				zerocheck_prover_cost(composition_degree, optimal, n_vars, field_bits);
+			let feasible = (0..=n_vars)
+				.filter(|&skip| domain_size(composition_degree, skip) <= 1 << field_bits);
+			for skip_rounds in feasible {
+				assert!(
+					optimal_cost
+						<= zerocheck_prover_cost(
+							composition_degree,
+							skip_rounds,
+							n_vars,
+							field_bits
+						)
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_optimal_skip_rounds_trades_off_univariate_and_multilinear_rounds() {
+		// Skipping is worth it with a cheap domain field, but not all the way to n_vars.
+		let optimal = optimal_skip_rounds(2, 20, 16);
+		assert!(optimal > 0 && optimal < 20);
+
+		// The domain field bounds the univariate domain.
+		assert_eq!(optimal_skip_rounds(4, 12, 3), 1);
+	}
+}
@@ -10,7 +10,7 @@
 //! [Reed–Solomon]: <https://en.wikipedia.org/wiki/Reed%E2%80%93Solomon_error_correction>
 //! [LCH14]: <https://arxiv.org/abs/1404.3458>
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 
 use binius_field::{BinaryField, ExtensionField, PackedField, RepackedExtension};
 use binius_maybe_rayon::prelude::*;
@@ -25,11 +25,12 @@ where
 	P: PackedField,
 	P::Scalar: BinaryField,
 {
-	ntt: DynamicDispatchNTT<P::Scalar>,
+	ntt: Arc<DynamicDispatchNTT<P::Scalar>>,
 	log_dimension: usize,
 	#[getset(get_copy = "pub")]
 	log_inv_rate: usize,
 	multithreaded: bool,
+	subspace_eval_cache: Option<Arc<SubspaceEvalCache<P::Scalar>>>,
 	_p_marker: PhantomData<P>,
 }
 
@@ -60,17 +61,81 @@ where
 		let multithreaded =
 			!matches!(ntt_options.thread_settings, ThreadingSettings::SingleThreaded);
 
+		Ok(Self {
+			ntt: Arc::new(ntt),
+			log_dimension,
+			log_inv_rate,
+			multithreaded,
+			subspace_eval_cache: None,
+			_p_marker: PhantomData,
+		})
+	}
+
+	/// Constructs a code that encodes with a shared NTT instance.
+	///
+	/// This allows several codes over the same field to share the NTT twiddle precomputation. The
+	/// NTT domain must be large enough to hold the codewords, ie. at least
+	/// `2^(log_dimension + log_inv_rate)`. The threading behaviour of the encoder follows the
+	/// threading settings the NTT was constructed with.
+	pub fn with_ntt(
+		log_dimension: usize,
+		log_inv_rate: usize,
+		ntt: Arc<DynamicDispatchNTT<P::Scalar>>,
+	) -> Result<Self, Error> {
+		let log_len = log_dimension + log_inv_rate;
+		if AdditiveNTT::<P::Scalar>::log_domain_size(ntt.as_ref()) < log_len {
+			bail!(Error::DomainTooSmall {
+				log_required_domain_size: log_len,
+			});
+		}
+
+		let multithreaded = matches!(
+			ntt.as_ref(),
+			DynamicDispatchNTT::MultiThreaded(_) | DynamicDispatchNTT::MultiThreadedPrecompute(_)
+		);
+
 		Ok(Self {
 			ntt,
 			log_dimension,
 			log_inv_rate,
 			multithreaded,
+			subspace_eval_cache: None,
 			_p_marker: PhantomData,
 		})
 	}
 
-	pub const fn get_ntt(&self) -> &impl AdditiveNTT<P> {
-		&self.ntt
+	/// Precomputes the subspace evaluations read by FRI folding, see [`SubspaceEvalCache`].
+	///
+	/// [`Self::get_subspace_eval`] then serves them from memory. This is worthwhile for a code
+	/// that is kept around to verify many proofs.
+	pub fn with_subspace_eval_cache(mut self) -> Self {
+		self.subspace_eval_cache = Some(Arc::new(SubspaceEvalCache::new(&self)));
+		self
+	}
+
+	/// Returns the normalized subspace polynomial evaluation used to fold round `round` at
+	/// `index`, see [`AdditiveNTT::get_subspace_eval`].
+	///
+	/// The value is read from the [`SubspaceEvalCache`] if the code was constructed with
+	/// [`Self::with_subspace_eval_cache`].
+	#[inline]
+	pub fn get_subspace_eval(&self, round: usize, index: usize) -> P::Scalar {
+		match &self.subspace_eval_cache {
+			Some(cache) => cache.get(round, index),
+			None => self.get_ntt().get_subspace_eval(round, index),
+		}
+	}
+
+	pub fn get_ntt(&self) -> &impl AdditiveNTT<P> {
+		self.ntt.as_ref()
+	}
+
+	/// The base-2 log of the dimension of the subspace the encoding NTT operates over.
+	///
+	/// This is at least [`Self::log_len`], and larger when the code shares an NTT constructed for
+	/// a bigger domain.
+	pub fn log_subspace_dim(&self) -> usize {
+		AdditiveNTT::<P::Scalar>::log_domain_size(self.ntt.as_ref())
 	}
 
 	/// The dimension.
@@ -138,14 +203,70 @@ where
 			(0..(1 << self.log_inv_rate))
 				.into_par_iter()
 				.zip(code.par_chunks_exact_mut(msgs_len))
-				.try_for_each(|(i, data)| self.ntt.forward_transform(data, i, log_batch_size))
+				.try_for_each(|(i, data)| self.encode_coset_block(data, i, log_batch_size))
 		} else {
 			(0..(1 << self.log_inv_rate))
 				.zip(code.chunks_exact_mut(msgs_len))
-				.try_for_each(|(i, data)| self.ntt.forward_transform(data, i, log_batch_size))
+				.try_for_each(|(i, data)| self.encode_coset_block(data, i, log_batch_size))
 		}
 	}
 
+	/// Encode a batch of interleaved messages into the codeword block of a single coset, in-place.
+	///
+	/// The codeword is the concatenation of `inv_rate()` blocks, the `i`-th of which holds the
+	/// evaluations of the message polynomial over the `i`-th coset of the NTT domain. Each block
+	/// has the size of the message and depends only on the message and the coset index.
+	fn encode_coset_block(
+		&self,
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+	) -> Result<(), Error> {
+		self.ntt.forward_transform(data, coset, log_batch_size)
+	}
+
+	/// Encode a batch of interleaved messages of extension field elements block by block.
+	///
+	/// Returns an iterator over the `inv_rate()` codeword blocks, in codeword order. Concatenated,
+	/// the blocks equal the codeword produced by [`Self::encode_ext_batch_inplace`]. The additive
+	/// NTT evaluates each coset from the full message, so the message must be held in memory, but
+	/// only one codeword block is allocated at a time. This lets the caller consume the codeword,
+	/// for example by hashing or writing it out, without materializing it in full.
+	///
+	/// ## Throws
+	///
+	/// * If `message` does not contain exactly `dim() << log_batch_size` field elements.
+	pub fn encode_ext_batch_blocks<'a, PE: RepackedExtension<P>>(
+		&'a self,
+		message: &'a [PE],
+		log_batch_size: usize,
+	) -> Result<impl Iterator<Item = Result<Vec<PE>, Error>> + 'a, Error> {
+		if self.dim() % P::WIDTH != 0 {
+			bail!(Error::PackingWidthMustDivideDimension);
+		}
+		let expected_len = (self.dim() / PE::WIDTH) << log_batch_size;
+		if message.len() != expected_len {
+			bail!(Error::IncorrectMessageLength { expected_len });
+		}
+
+		let _scope = tracing::trace_span!(
+			"Reed–Solomon blocked encode",
+			log_len = self.log_len(),
+			log_batch_size = log_batch_size,
+			symbol_bits = P::Scalar::N_BITS,
+		)
+		.entered();
+		Ok((0..(1 << self.log_inv_rate)).map(move |i| {
+			let mut block = message.to_vec();
+			self.encode_coset_block(
+				PE::cast_bases_mut(&mut block),
+				i,
+				log_batch_size + PE::Scalar::LOG_DEGREE,
+			)?;
+			Ok(block)
+		}))
+	}
+
 	/// Encode a batch of interleaved messages of extension field elements in-place in a provided
 	/// buffer.
 	///
@@ -168,3 +289,196 @@ where
 		self.encode_batch_inplace(PE::cast_bases_mut(code), log_batch_size + PE::Scalar::LOG_DEGREE)
 	}
 }
+
+/// Precomputed subspace polynomial evaluations of a fixed [`ReedSolomonCode`].
+///
+/// FRI folding at round `round` of a codeword of the code evaluates
+/// [`AdditiveNTT::get_subspace_eval`] at `round` and at an index below `2^(log_len - round - 1)`,
+/// for every round below `log_dim`. The cache holds all of these values, so a long-lived verifier
+/// checking many proofs under the same code reads them from memory instead of recomputing them
+/// from the NTT twiddles on every query. The cache holds about `len()` field elements.
+///
+/// A code built with [`ReedSolomonCode::with_subspace_eval_cache`] uses the cache for folding.
+#[derive(Debug, Clone)]
+pub struct SubspaceEvalCache<F> {
+	evals: Vec<Vec<F>>,
+}
+
+impl<F: BinaryField> SubspaceEvalCache<F> {
+	/// Precomputes the subspace evaluations for all fold rounds of `rs_code`.
+	pub fn new<P: PackedField<Scalar = F>>(rs_code: &ReedSolomonCode<P>) -> Self {
+		let ntt = rs_code.get_ntt();
+		let evals = (0..rs_code.log_dim())
+			.map(|round| {
+				(0..1 << (rs_code.log_len() - round - 1))
+					.map(|index| ntt.get_subspace_eval(round, index))
+					.collect()
+			})
+			.collect();
+		Self { evals }
+	}
+
+	/// The number of fold rounds the cache holds evaluations for.
+	pub fn n_rounds(&self) -> usize {
+		self.evals.len()
+	}
+
+	/// Returns the cached value of [`AdditiveNTT::get_subspace_eval`] at `round` and `index`.
+	///
+	/// ## Preconditions
+	///
+	/// * `round` must be less than [`Self::n_rounds`]
+	/// * `index` must be less than `2^(log_len - round - 1)` for the code the cache was built from
+	#[inline]
+	pub fn get(&self, round: usize, index: usize) -> F {
+		self.evals[round][index]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{
+		BinaryField16b, PackedBinaryField1x128b, PackedBinaryField8x16b, PackedField,
+	};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	#[test]
+	fn test_with_shared_ntt_encodes_identically() {
+		type P = PackedBinaryField8x16b;
+		type PE = PackedBinaryField1x128b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let log_dimension = 6;
+		let log_inv_rate = 2;
+
+		let standalone =
+			ReedSolomonCode::<P>::new(log_dimension, log_inv_rate, &NTTOptions::default()).unwrap();
+		let ntt = Arc::new(
+			DynamicDispatchNTT::new(log_dimension + log_inv_rate + 1, &NTTOptions::default())
+				.unwrap(),
+		);
+		let shared = ReedSolomonCode::<P>::with_ntt(log_dimension, log_inv_rate, ntt).unwrap();
+
+		let msg = repeat_with(|| PE::random(&mut rng))
+			.take(standalone.dim())
+			.collect::<Vec<_>>();
+
+		let mut expected = vec![PE::zero(); standalone.len()];
+		expected[..msg.len()].copy_from_slice(&msg);
+		standalone
+			.encode_ext_batch_inplace(&mut expected, 0)
+			.unwrap();
+
+		let mut actual = vec![PE::zero(); shared.len()];
+		actual[..msg.len()].copy_from_slice(&msg);
+		shared.encode_ext_batch_inplace(&mut actual, 0).unwrap();
+
+		assert_eq!(actual, expected);
+	}
+
+	#[test]
+	fn test_blocked_encode_matches_encode() {
+		type P = PackedBinaryField8x16b;
+		type PE = PackedBinaryField1x128b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let code = ReedSolomonCode::<P>::new(10, 2, &NTTOptions::default()).unwrap();
+		for log_batch_size in [0, 2] {
+			let msg = repeat_with(|| PE::random(&mut rng))
+				.take(code.dim() << log_batch_size)
+				.collect::<Vec<_>>();
+
+			let mut expected = vec![PE::zero(); code.len() << log_batch_size];
+			expected[..msg.len()].copy_from_slice(&msg);
+			code.encode_ext_batch_inplace(&mut expected, log_batch_size)
+				.unwrap();
+
+			let blocks = code
+				.encode_ext_batch_blocks(&msg, log_batch_size)
+				.unwrap()
+				.collect::<Result<Vec<_>, _>>()
+				.unwrap();
+			assert_eq!(blocks.len(), code.inv_rate());
+			assert!(blocks.iter().all(|block| block.len() == msg.len()));
+			assert_eq!(blocks.concat(), expected);
+		}
+	}
+
+	#[test]
+	fn test_blocked_encode_rejects_wrong_message_length() {
+		let code =
+			ReedSolomonCode::<PackedBinaryField8x16b>::new(6, 1, &NTTOptions::default()).unwrap();
+		let msg = vec![PackedBinaryField1x128b::zero(); code.dim() - 1];
+		assert!(matches!(
+			code.encode_ext_batch_blocks(&msg, 0),
+			Err(Error::IncorrectMessageLength { expected_len: 64 })
+		));
+	}
+
+	#[test]
+	fn test_log_subspace_dim() {
+		for (log_dimension, log_inv_rate) in [(0, 1), (4, 1), (6, 2), (10, 3)] {
+			let code = ReedSolomonCode::<BinaryField16b>::new(
+				log_dimension,
+				log_inv_rate,
+				&NTTOptions::default(),
+			)
+			.unwrap();
+			assert_eq!(code.log_subspace_dim(), log_dimension + log_inv_rate);
+		}
+
+		let ntt = Arc::new(DynamicDispatchNTT::new(12, &NTTOptions::default()).unwrap());
+		let code = ReedSolomonCode::<BinaryField16b>::with_ntt(6, 2, ntt).unwrap();
+		assert_eq!(code.log_subspace_dim(), 12);
+		assert!(code.log_subspace_dim() >= code.log_len());
+	}
+
+	#[test]
+	fn test_with_ntt_rejects_small_domain() {
+		let ntt = Arc::new(DynamicDispatchNTT::new(7, &NTTOptions::default()).unwrap());
+		assert!(matches!(
+			ReedSolomonCode::<BinaryField16b>::with_ntt(6, 2, ntt),
+			Err(Error::DomainTooSmall {
+				log_required_domain_size: 8
+			})
+		));
+	}
+
+	#[test]
+	fn test_subspace_eval_cache_matches_ntt() {
+		type P = PackedBinaryField8x16b;
+
+		let rs_code = ReedSolomonCode::<P>::new(5, 2, &NTTOptions::default()).unwrap();
+		let cache = SubspaceEvalCache::new(&rs_code);
+		assert_eq!(cache.n_rounds(), rs_code.log_dim());
+
+		let ntt = rs_code.get_ntt();
+		for round in 0..rs_code.log_dim() {
+			for index in 0..1 << (rs_code.log_len() - round - 1) {
+				assert_eq!(cache.get(round, index), ntt.get_subspace_eval(round, index));
+			}
+		}
+	}
+
+	#[test]
+	fn test_code_with_subspace_eval_cache_serves_cached_values() {
+		type P = PackedBinaryField8x16b;
+
+		let rs_code = ReedSolomonCode::<P>::new(5, 2, &NTTOptions::default()).unwrap();
+		let cached_code = ReedSolomonCode::<P>::new(5, 2, &NTTOptions::default())
+			.unwrap()
+			.with_subspace_eval_cache();
+		for round in 0..rs_code.log_dim() {
+			for index in 0..1 << (rs_code.log_len() - round - 1) {
+				assert_eq!(
+					cached_code.get_subspace_eval(round, index),
+					rs_code.get_subspace_eval(round, index)
+				);
+			}
+		}
+	}
+}
@@ -0,0 +1,250 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Tensor products of [Reed–Solomon] codes.
+//!
+//! The tensor code of a row code `C_r` and a column code `C_c` encodes a message arranged as a
+//! `C_c.dim() x C_r.dim()` matrix by encoding every row with `C_r` and then every column of the
+//! result with `C_c`. The base-2 logarithms of the dimension and the block length are the sums of
+//! those of the component codes.
+//!
+//! [Reed–Solomon]: super::reed_solomon
+
+use binius_field::{BinaryField, PackedField, RepackedExtension};
+use binius_ntt::Error;
+use binius_utils::bail;
+use getset::Getters;
+
+use super::reed_solomon::ReedSolomonCode;
+
+#[derive(Debug, Getters)]
+pub struct TensorCode<P>
+where
+	P: PackedField<Scalar: BinaryField>,
+{
+	/// The code applied to the rows of the message.
+	#[getset(get = "pub")]
+	row_code: ReedSolomonCode<P>,
+	/// The code applied to the columns of the row-encoded message.
+	#[getset(get = "pub")]
+	column_code: ReedSolomonCode<P>,
+}
+
+impl<P> TensorCode<P>
+where
+	P: PackedField<Scalar: BinaryField>,
+{
+	pub const fn new(row_code: ReedSolomonCode<P>, column_code: ReedSolomonCode<P>) -> Self {
+		Self {
+			row_code,
+			column_code,
+		}
+	}
+
+	pub const fn log_dim(&self) -> usize {
+		self.row_code.log_dim() + self.column_code.log_dim()
+	}
+
+	/// The dimension.
+	pub const fn dim(&self) -> usize {
+		1 << self.log_dim()
+	}
+
+	pub const fn log_len(&self) -> usize {
+		self.row_code.log_len() + self.column_code.log_len()
+	}
+
+	/// The block length.
+	#[allow(clippy::len_without_is_empty)]
+	pub const fn len(&self) -> usize {
+		1 << self.log_len()
+	}
+
+	/// Encode a message of extension field elements in-place in a provided buffer.
+	///
+	/// The message occupies the first [`Self::dim`] elements of the buffer and is read as a
+	/// row-major matrix with `row_code().dim()` columns. When the method completes, the buffer
+	/// holds the codeword as a row-major matrix with `row_code().len()` columns and
+	/// `column_code().len()` rows.
+	///
+	/// ## Throws
+	///
+	/// * If the `code` buffer does not have capacity for `len()` field elements.
+	/// * If the packing width of `PE` does not divide the dimension of the row code.
+	pub fn encode_ext_inplace<PE: RepackedExtension<P>>(
+		&self,
+		code: &mut [PE],
+	) -> Result<(), Error> {
+		if code.len() * PE::WIDTH < self.len() {
+			bail!(Error::BufferTooSmall {
+				log_code_len: self.log_len(),
+			});
+		}
+		if self.row_code.dim() % PE::WIDTH != 0 {
+			bail!(Error::PackingWidthMustDivideDimension);
+		}
+
+		let row_msg_len = self.row_code.dim() / PE::WIDTH;
+		let row_len = self.row_code.len() / PE::WIDTH;
+		let code = &mut code[..self.len() / PE::WIDTH];
+
+		// Spread the message rows out to the stride of the row codewords, starting from the last
+		// row so that no row is overwritten before it is moved.
+		for row in (1..self.column_code.dim()).rev() {
+			code.copy_within(row * row_msg_len..(row + 1) * row_msg_len, row * row_len);
+		}
+		for row in code[..self.column_code.dim() * row_len].chunks_exact_mut(row_len) {
+			self.row_code.encode_ext_batch_inplace(row, 0)?;
+		}
+
+		// The row codewords are the interleaved column messages.
+		self.column_code
+			.encode_ext_batch_inplace(code, self.row_code.log_len())
+	}
+}
+
+impl<P> ReedSolomonCode<P>
+where
+	P: PackedField<Scalar: BinaryField>,
+{
+	/// Composes this code as the row code with `column_code` into a [`TensorCode`].
+	pub const fn tensor(self, column_code: Self) -> TensorCode<P> {
+		TensorCode::new(self, column_code)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{
+		BinaryField128b, BinaryField16b, ExtensionField, PackedBinaryField1x128b,
+		PackedBinaryField8x16b, PackedExtension, PackedField,
+	};
+	use binius_ntt::{AdditiveNTT, NTTOptions};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	type P = PackedBinaryField8x16b;
+	type PE = PackedBinaryField1x128b;
+
+	/// Decodes an error-free codeword by inverting the NTT on the first coset of each code.
+	fn decode(code: &TensorCode<P>, codeword: &[PE]) -> Vec<PE> {
+		let log_degree = <BinaryField128b as ExtensionField<BinaryField16b>>::LOG_DEGREE;
+		let row_msg_len = code.row_code().dim();
+		let row_len = code.row_code().len();
+
+		let mut rows = codeword[..code.column_code().dim() * row_len].to_vec();
+		code.column_code()
+			.get_ntt()
+			.inverse_transform(
+				<PE as PackedExtension<BinaryField16b>>::cast_bases_mut(&mut rows),
+				0,
+				code.row_code().log_len() + log_degree,
+			)
+			.unwrap();
+
+		rows.chunks_exact_mut(row_len)
+			.flat_map(|row| {
+				let row_msg = &mut row[..row_msg_len];
+				code.row_code()
+					.get_ntt()
+					.inverse_transform(
+						<PE as PackedExtension<BinaryField16b>>::cast_bases_mut(row_msg),
+						0,
+						log_degree,
+					)
+					.unwrap();
+				row_msg.to_vec()
+			})
+			.collect()
+	}
+
+	fn new_code(
+		row_log_dim: usize,
+		row_log_inv_rate: usize,
+		column_log_dim: usize,
+		column_log_inv_rate: usize,
+	) -> TensorCode<P> {
+		let row_code =
+			ReedSolomonCode::new(row_log_dim, row_log_inv_rate, &NTTOptions::default()).unwrap();
+		let column_code =
+			ReedSolomonCode::new(column_log_dim, column_log_inv_rate, &NTTOptions::default())
+				.unwrap();
+		row_code.tensor(column_code)
+	}
+
+	fn random_message(code: &TensorCode<P>) -> Vec<PE> {
+		let mut rng = StdRng::seed_from_u64(0);
+		repeat_with(|| PE::random(&mut rng))
+			.take(code.dim())
+			.collect()
+	}
+
+	#[test]
+	fn test_tensor_encode_decode_roundtrip() {
+		for (row_log_dim, row_log_inv_rate, column_log_dim, column_log_inv_rate) in
+			[(3, 1, 3, 1), (4, 1, 3, 2), (3, 2, 5, 1)]
+		{
+			let code = new_code(row_log_dim, row_log_inv_rate, column_log_dim, column_log_inv_rate);
+			assert_eq!(code.log_dim(), row_log_dim + column_log_dim);
+			assert_eq!(
+				code.log_len(),
+				row_log_dim + row_log_inv_rate + column_log_dim + column_log_inv_rate
+			);
+
+			let msg = random_message(&code);
+			let mut codeword = vec![PE::zero(); code.len()];
+			codeword[..msg.len()].copy_from_slice(&msg);
+			code.encode_ext_inplace(&mut codeword).unwrap();
+
+			assert_eq!(decode(&code, &codeword), msg);
+		}
+	}
+
+	#[test]
+	fn test_tensor_encode_matches_row_then_column_encoding() {
+		let code = new_code(3, 1, 4, 1);
+
+		let msg = random_message(&code);
+		let mut codeword = vec![PE::zero(); code.len()];
+		codeword[..msg.len()].copy_from_slice(&msg);
+		code.encode_ext_inplace(&mut codeword).unwrap();
+
+		// Encode each row, then each column on its own.
+		let row_len = code.row_code().len();
+		let row_codewords = msg
+			.chunks_exact(code.row_code().dim())
+			.map(|row| {
+				let mut row_codeword = vec![PE::zero(); row_len];
+				row_codeword[..row.len()].copy_from_slice(row);
+				code.row_code()
+					.encode_ext_batch_inplace(&mut row_codeword, 0)
+					.unwrap();
+				row_codeword
+			})
+			.collect::<Vec<_>>();
+		for col in 0..row_len {
+			let mut column_codeword = vec![PE::zero(); code.column_code().len()];
+			for (dst, row_codeword) in column_codeword.iter_mut().zip(&row_codewords) {
+				*dst = row_codeword[col];
+			}
+			code.column_code()
+				.encode_ext_batch_inplace(&mut column_codeword, 0)
+				.unwrap();
+			for (row, &expected) in column_codeword.iter().enumerate() {
+				assert_eq!(codeword[row * row_len + col], expected);
+			}
+		}
+	}
+
+	#[test]
+	fn test_tensor_encode_rejects_small_buffer() {
+		let code = new_code(3, 1, 3, 1);
+		let mut codeword = vec![PE::zero(); code.len() - 1];
+		assert!(matches!(
+			code.encode_ext_inplace(&mut codeword),
+			Err(Error::BufferTooSmall { .. })
+		));
+	}
+}
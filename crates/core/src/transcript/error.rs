@@ -8,4 +8,6 @@ pub enum Error {
 	NotEnoughBytes,
 	#[error("Serialization error: {0}")]
 	Serialization(#[from] binius_utils::SerializationError),
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
 }
@@ -13,10 +13,18 @@
 //! the Merkle leaf opening at that index in the advice tape.
 
 mod error;
-
-use std::{iter::repeat_with, slice};
-
-use binius_field::{PackedField, TowerField};
+#[allow(dead_code)]
+#[doc(hidden)]
+pub mod test_utils;
+
+use std::{
+	fmt, io,
+	iter::repeat_with,
+	ops::{Deref, DerefMut},
+	slice,
+};
+
+use binius_field::{ExtensionField, PackedField, TowerField};
 use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 use bytes::{buf::UninitSlice, Buf, BufMut, Bytes, BytesMut};
 pub use error::Error;
@@ -30,7 +38,7 @@ use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
 /// data.
 #[derive(Debug)]
 pub struct ProverTranscript<Challenger> {
-	combined: FiatShamirBuf<BytesMut, Challenger>,
+	combined: FiatShamirBuf<ProofTape, Challenger>,
 	debug_assertions: bool,
 }
 
@@ -40,7 +48,7 @@ pub struct ProverTranscript<Challenger> {
 /// fully read out
 #[derive(Debug)]
 pub struct VerifierTranscript<Challenger> {
-	combined: FiatShamirBuf<Bytes, Challenger>,
+	combined: FiatShamirBuf<ProofSource, Challenger>,
 	debug_assertions: bool,
 }
 
@@ -59,14 +67,20 @@ impl<Inner: Buf, Challenger_: Challenger> Buf for FiatShamirBuf<Inner, Challenge
 		self.buffer.chunk()
 	}
 
-	fn advance(&mut self, cnt: usize) {
+	fn advance(&mut self, mut cnt: usize) {
 		assert!(cnt <= self.buffer.remaining());
-		// Get the slice that was written to the inner buf, observe that and advance
-		let readable = self.buffer.chunk();
-		// Because our internal buffer is created from vec, this should never happen.
-		assert!(cnt <= readable.len());
-		self.challenger.observer().put_slice(&readable[..cnt]);
-		self.buffer.advance(cnt);
+		// Observe the inner buf chunk by chunk, since a streamed proof is not contiguous.
+		while cnt > 0 {
+			let readable = self.buffer.chunk();
+			// Only empty if a streamed proof could not be read, which is reported on finalization.
+			if readable.is_empty() {
+				break;
+			}
+			let n = cnt.min(readable.len());
+			self.challenger.observer().put_slice(&readable[..n]);
+			self.buffer.advance(n);
+			cnt -= n;
+		}
 	}
 }
 
@@ -94,6 +108,140 @@ unsafe impl<Inner: BufMut, Challenger_: Challenger> BufMut for FiatShamirBuf<Inn
 	}
 }
 
+/// The proof tape of a [`ProverTranscript`].
+///
+/// Without a sink the whole proof is kept in memory. With a sink, the buffered bytes are written
+/// through to it once they exceed [`STREAM_FLUSH_THRESHOLD`], so only a bounded prefix of the proof
+/// is held in memory at any time. The first I/O error is recorded and reported on finalization,
+/// since [`BufMut`] methods cannot fail.
+#[derive(Default)]
+struct ProofTape {
+	buffer: BytesMut,
+	sink: Option<Box<dyn io::Write + Send + Sync>>,
+	error: Option<io::Error>,
+}
+
+/// Number of buffered proof bytes above which a streaming [`ProofTape`] writes through to its sink.
+const STREAM_FLUSH_THRESHOLD: usize = 1 << 16;
+
+impl ProofTape {
+	fn flush_buffer(&mut self) {
+		let Some(sink) = &mut self.sink else {
+			return;
+		};
+		if self.error.is_none() {
+			if let Err(err) = sink.write_all(&self.buffer) {
+				self.error = Some(err);
+			}
+		}
+		self.buffer.clear();
+	}
+
+	fn maybe_flush(&mut self) {
+		if self.sink.is_some() && self.buffer.len() >= STREAM_FLUSH_THRESHOLD {
+			self.flush_buffer();
+		}
+	}
+}
+
+impl fmt::Debug for ProofTape {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ProofTape")
+			.field("buffer", &self.buffer)
+			.field("streaming", &self.sink.is_some())
+			.field("error", &self.error)
+			.finish()
+	}
+}
+
+unsafe impl BufMut for ProofTape {
+	fn remaining_mut(&self) -> usize {
+		self.buffer.remaining_mut()
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		self.buffer.advance_mut(cnt);
+		self.maybe_flush();
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		self.buffer.chunk_mut()
+	}
+
+	fn put_slice(&mut self, src: &[u8]) {
+		self.buffer.put_slice(src);
+		self.maybe_flush();
+	}
+}
+
+/// The proof tape of a [`VerifierTranscript`].
+///
+/// Without a reader the whole proof is held in memory. With a reader, the proof is pulled from it
+/// in chunks of [`STREAM_FLUSH_THRESHOLD`] bytes as the verifier consumes it. The proof length is
+/// known up front, so [`Buf::remaining`] is exact either way. The first I/O error is recorded and
+/// reported on finalization, since [`Buf`] methods cannot fail; the unread part of the proof is
+/// then treated as missing.
+#[derive(Default)]
+struct ProofSource {
+	buffer: Bytes,
+	reader: Option<Box<dyn io::Read + Send + Sync>>,
+	unread: usize,
+	error: Option<io::Error>,
+}
+
+impl ProofSource {
+	fn fill_buffer(&mut self) -> io::Result<()> {
+		let Some(reader) = &mut self.reader else {
+			return Ok(());
+		};
+		let mut chunk = vec![0u8; self.unread.min(STREAM_FLUSH_THRESHOLD)];
+		if let Err(err) = reader.read_exact(&mut chunk) {
+			self.unread = 0;
+			return Err(err);
+		}
+		self.unread -= chunk.len();
+		self.buffer = Bytes::from(chunk);
+		Ok(())
+	}
+}
+
+impl fmt::Debug for ProofSource {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ProofSource")
+			.field("buffer", &self.buffer)
+			.field("unread", &self.unread)
+			.field("error", &self.error)
+			.finish()
+	}
+}
+
+impl Buf for ProofSource {
+	fn remaining(&self) -> usize {
+		self.buffer.remaining() + self.unread
+	}
+
+	fn chunk(&self) -> &[u8] {
+		self.buffer.chunk()
+	}
+
+	fn advance(&mut self, mut cnt: usize) {
+		assert!(cnt <= self.remaining());
+		loop {
+			let n = cnt.min(self.buffer.len());
+			self.buffer.advance(n);
+			cnt -= n;
+			if self.buffer.is_empty() && self.unread > 0 {
+				if let Err(err) = self.fill_buffer() {
+					self.error.get_or_insert(err);
+				}
+			}
+			if cnt == 0 || self.buffer.is_empty() {
+				break;
+			}
+		}
+	}
+}
+
 impl<Challenger_: Default + Challenger> ProverTranscript<Challenger_> {
 	/// Creates a new prover transcript.
 	///
@@ -118,8 +266,12 @@ impl<Challenger_: Default + Challenger> Default for ProverTranscript<Challenger_
 }
 
 impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
+	/// Returns the proof bytes.
 	pub fn finalize(self) -> Vec<u8> {
-		self.combined.buffer.to_vec()
+		// Only a `StreamingProverTranscript` has a sink, and it never hands out its transcript by
+		// value.
+		debug_assert!(self.combined.buffer.sink.is_none());
+		self.combined.buffer.buffer.to_vec()
 	}
 
 	/// Sets the debug flag.
@@ -170,6 +322,83 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 			debug_assertions: self.debug_assertions,
 		}
 	}
+
+	/// Samples a challenge from a subfield `FSub` and lifts it into `F`.
+	///
+	/// Only the bytes of one `FSub` element are squeezed. The challenge is uniform over `FSub`, so
+	/// soundness bounds that depend on the size of the challenge space must use `|FSub|`.
+	pub fn sample_subfield<FSub, F>(&mut self) -> F
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		CanSample::<FSub>::sample(self).into()
+	}
+
+	/// Hints the challenger that about `n_bytes` of randomness are about to be sampled.
+	///
+	/// See [`Challenger::reserve_output`]. The transcript is the same whether or not this is called.
+	pub fn reserve_output(&mut self, n_bytes: usize) {
+		self.combined.challenger.reserve_output(n_bytes);
+	}
+}
+
+/// A prover transcript that writes the proof through to a sink.
+///
+/// The proof bytes are identical to those of [`ProverTranscript::new`], but instead of being
+/// accumulated in memory they are written to the sink in chunks as the protocol runs. This is
+/// useful for proofs that are too large to hold in memory.
+///
+/// It dereferences to a [`ProverTranscript`], so it can be passed to any prover taking one, but it
+/// can only be finished with [`Self::finalize`], since the proof is not kept around to be returned
+/// or turned into a verifier transcript.
+#[derive(Debug)]
+pub struct StreamingProverTranscript<Challenger>(ProverTranscript<Challenger>);
+
+impl<Challenger_: Default + Challenger> StreamingProverTranscript<Challenger_> {
+	pub fn new(sink: impl io::Write + Send + Sync + 'static) -> Self {
+		Self(ProverTranscript {
+			combined: FiatShamirBuf {
+				buffer: ProofTape {
+					sink: Some(Box::new(sink)),
+					..Default::default()
+				},
+				challenger: Challenger_::default(),
+			},
+			debug_assertions: cfg!(debug_assertions),
+		})
+	}
+}
+
+impl<Challenger_: Challenger> StreamingProverTranscript<Challenger_> {
+	/// Writes the remaining proof bytes to the sink and flushes it.
+	///
+	/// Returns the first I/O error encountered while streaming the proof, if any.
+	pub fn finalize(mut self) -> Result<(), Error> {
+		let tape = &mut self.0.combined.buffer;
+		tape.flush_buffer();
+		if let Some(err) = tape.error.take() {
+			return Err(err.into());
+		}
+		if let Some(sink) = &mut tape.sink {
+			sink.flush()?;
+		}
+		Ok(())
+	}
+}
+
+impl<Challenger> Deref for StreamingProverTranscript<Challenger> {
+	type Target = ProverTranscript<Challenger>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<Challenger> DerefMut for StreamingProverTranscript<Challenger> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
 }
 
 impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
@@ -177,15 +406,49 @@ impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
 		Self {
 			combined: FiatShamirBuf {
 				challenger: Challenger_::default(),
-				buffer: Bytes::from(vec),
+				buffer: ProofSource {
+					buffer: Bytes::from(vec),
+					..Default::default()
+				},
 			},
 			debug_assertions: cfg!(debug_assertions),
 		}
 	}
+
+	/// Creates a new verifier transcript reading the proof from `reader`.
+	///
+	/// This is the counterpart of [`StreamingProverTranscript`]. The proof runs from the current
+	/// position of the reader to its end, and is pulled from it lazily as the verifier reads it, so
+	/// only a bounded part of the proof is held in memory at any time. The reader is seeked to the
+	/// end up front to learn the proof length, which the verifier uses to bound the proof size.
+	pub fn from_reader(
+		mut reader: impl io::Read + io::Seek + Send + Sync + 'static,
+	) -> Result<Self, Error> {
+		let start = reader.stream_position()?;
+		let end = reader.seek(io::SeekFrom::End(0))?;
+		reader.seek(io::SeekFrom::Start(start))?;
+
+		let mut source = ProofSource {
+			reader: Some(Box::new(reader)),
+			unread: usize::try_from(end.saturating_sub(start)).map_err(io::Error::other)?,
+			..Default::default()
+		};
+		source.fill_buffer()?;
+		Ok(Self {
+			combined: FiatShamirBuf {
+				challenger: Challenger_::default(),
+				buffer: source,
+			},
+			debug_assertions: cfg!(debug_assertions),
+		})
+	}
 }
 
 impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
-	pub fn finalize(self) -> Result<(), Error> {
+	pub fn finalize(mut self) -> Result<(), Error> {
+		if let Some(err) = self.combined.buffer.error.take() {
+			return Err(err.into());
+		}
 		if self.combined.buffer.has_remaining() {
 			return Err(Error::TranscriptNotEmpty {
 				remaining: self.combined.buffer.remaining(),
@@ -198,6 +461,25 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 		self.debug_assertions = debug;
 	}
 
+	/// Samples a challenge from a subfield `FSub` and lifts it into `F`.
+	///
+	/// Only the bytes of one `FSub` element are squeezed. The challenge is uniform over `FSub`, so
+	/// soundness bounds that depend on the size of the challenge space must use `|FSub|`.
+	pub fn sample_subfield<FSub, F>(&mut self) -> F
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		CanSample::<FSub>::sample(self).into()
+	}
+
+	/// Hints the challenger that about `n_bytes` of randomness are about to be sampled.
+	///
+	/// See [`Challenger::reserve_output`]. The transcript is the same whether or not this is called.
+	pub fn reserve_output(&mut self, n_bytes: usize) {
+		self.combined.challenger.reserve_output(n_bytes);
+	}
+
 	/// Returns a writable buffer that only observes the data written, without reading it from the
 	/// proof tape.
 	///
@@ -258,6 +540,17 @@ impl<B: Buf> TranscriptReader<'_, B> {
 		self.buffer
 	}
 
+	/// Returns a reader over another buffer with the same debug settings as this one.
+	///
+	/// This is used to read advice that has been split off the proof tape, for example to verify
+	/// independent openings in parallel.
+	pub fn with_buffer<'b, B2: Buf>(&self, buffer: &'b mut B2) -> TranscriptReader<'b, B2> {
+		TranscriptReader {
+			buffer,
+			debug_assertions: self.debug_assertions,
+		}
+	}
+
 	pub fn read<T: DeserializeBytes>(&mut self) -> Result<T, Error> {
 		let mode = SerializationMode::CanonicalTower;
 		T::deserialize(self.buffer(), mode).map_err(Into::into)
@@ -473,9 +766,12 @@ pub fn write_u64<B: BufMut>(transcript: &mut TranscriptWriter<B>, n: u64) {
 
 #[cfg(test)]
 mod tests {
+	use std::sync::{Arc, Mutex};
+
 	use binius_field::{
 		AESTowerField128b, AESTowerField16b, AESTowerField32b, AESTowerField8b, BinaryField128b,
 		BinaryField128bPolyval, BinaryField32b, BinaryField64b, BinaryField8b,
+		PackedBinaryField4x32b,
 	};
 	use groestl_crypto::Groestl256;
 	use rand::{thread_rng, RngCore};
@@ -656,4 +952,120 @@ mod tests {
 			.message()
 			.read_debug("test_transcript_debug_should_fail");
 	}
+
+	#[test]
+	fn test_sample_packed_matches_scalar_samples() {
+		type P = PackedBinaryField4x32b;
+
+		let mut packed_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut scalar_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+
+		let packed: P = packed_transcript.sample_packed();
+		let scalars: [BinaryField32b; P::WIDTH] = scalar_transcript.sample_array();
+		assert_eq!(packed.iter().collect::<Vec<_>>(), scalars);
+
+		let mut verifier_transcript = packed_transcript.into_verifier();
+		let mut scalar_verifier_transcript = scalar_transcript.into_verifier();
+		let packed: P = verifier_transcript.sample_packed();
+		let scalars: [BinaryField32b; P::WIDTH] = scalar_verifier_transcript.sample_array();
+		assert_eq!(packed.iter().collect::<Vec<_>>(), scalars);
+	}
+
+	#[test]
+	fn test_sample_bits_squeezes_whole_bytes() {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut same_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut raw_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+
+		let mut raw_bytes = [0u8; 6];
+		raw_transcript
+			.combined
+			.challenger
+			.sampler()
+			.copy_to_slice(&mut raw_bytes);
+
+		// 13 bits take two bytes, 8 bits one byte and 20 bits three bytes.
+		let samples = [13, 8, 20].map(|bits| transcript.sample_bits(bits));
+		assert_eq!(samples, [13, 8, 20].map(|bits| same_transcript.sample_bits(bits)));
+		assert_eq!(samples[0], u16::from_le_bytes([raw_bytes[0], raw_bytes[1]]) as usize & 0x1FFF);
+		assert_eq!(samples[1], raw_bytes[2] as usize);
+		assert_eq!(
+			samples[2],
+			u32::from_le_bytes([raw_bytes[3], raw_bytes[4], raw_bytes[5], 0]) as usize & 0xFFFFF
+		);
+
+		// Sampling zero bits consumes no randomness.
+		assert_eq!(transcript.sample_bits(0), 0);
+		let next: BinaryField8b = transcript.sample();
+		let raw_next: BinaryField8b = raw_transcript.sample();
+		assert_eq!(next, raw_next);
+	}
+
+	#[test]
+	fn test_streaming_transcript_matches_in_memory() {
+		#[derive(Clone, Default)]
+		struct SharedSink(Arc<Mutex<Vec<u8>>>);
+
+		impl io::Write for SharedSink {
+			fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+				self.0.lock().unwrap().write(buf)
+			}
+
+			fn flush(&mut self) -> io::Result<()> {
+				Ok(())
+			}
+		}
+
+		let mut rng = thread_rng();
+		// Write more than the flush threshold so that the sink is written to mid-protocol.
+		let rounds = repeat_with(|| {
+			repeat_with(|| {
+				BinaryField128b::new((rng.next_u64() as u128) << 64 | rng.next_u64() as u128)
+			})
+			.take(STREAM_FLUSH_THRESHOLD / 32)
+			.collect::<Vec<_>>()
+		})
+		.take(4)
+		.collect::<Vec<_>>();
+
+		let run = |transcript: &mut ProverTranscript<HasherChallenger<Groestl256>>| {
+			let mut challenges = Vec::new();
+			for scalars in &rounds {
+				transcript.message().write_scalar_slice(scalars);
+				challenges.push(CanSample::<BinaryField128b>::sample(transcript));
+				transcript.decommitment().write_scalar_slice(scalars);
+			}
+			challenges
+		};
+
+		let sink = SharedSink::default();
+		let mut streaming =
+			StreamingProverTranscript::<HasherChallenger<Groestl256>>::new(sink.clone());
+		let mut in_memory = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let challenges = run(&mut streaming);
+		assert_eq!(challenges, run(&mut in_memory));
+		assert!(!sink.0.lock().unwrap().is_empty());
+		streaming.finalize().unwrap();
+
+		let proof = in_memory.finalize();
+		let streamed = sink.0.lock().unwrap().clone();
+		assert_eq!(streamed, proof);
+
+		let mut verifier = VerifierTranscript::<HasherChallenger<Groestl256>>::from_reader(
+			io::Cursor::new(streamed),
+		)
+		.unwrap();
+		for (scalars, challenge) in rounds.iter().zip(challenges) {
+			let read: Vec<BinaryField128b> =
+				verifier.message().read_scalar_slice(scalars.len()).unwrap();
+			assert_eq!(&read, scalars);
+			assert_eq!(CanSample::<BinaryField128b>::sample(&mut verifier), challenge);
+			let read: Vec<BinaryField128b> = verifier
+				.decommitment()
+				.read_scalar_slice(scalars.len())
+				.unwrap();
+			assert_eq!(&read, scalars);
+		}
+		verifier.finalize().unwrap();
+	}
 }
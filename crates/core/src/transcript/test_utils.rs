@@ -0,0 +1,62 @@
+// Copyright 2025 Irreducible Inc.
+
+use super::ProverTranscript;
+use crate::fiat_shamir::Challenger;
+
+/// Runs `prove` on two fresh prover transcripts and asserts that both produce the same proof.
+///
+/// This catches nondeterminism leaking into the transcript, for example from the iteration order
+/// of a parallel computation. Returns the proof bytes.
+///
+/// ## Panics
+///
+/// Panics with the offset of the first differing byte if the two transcripts differ.
+pub fn assert_transcript_deterministic<Challenger_, R>(
+	mut prove: impl FnMut(&mut ProverTranscript<Challenger_>) -> R,
+) -> Vec<u8>
+where
+	Challenger_: Default + Challenger,
+{
+	let mut run = || {
+		let mut transcript = ProverTranscript::new();
+		prove(&mut transcript);
+		transcript.finalize()
+	};
+	let first = run();
+	let second = run();
+
+	if let Some(offset) = first.iter().zip(&second).position(|(a, b)| a != b) {
+		panic!("transcripts differ at byte {offset}");
+	}
+	assert_eq!(first.len(), second.len(), "transcripts differ in length");
+	first
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+	use groestl_crypto::Groestl256;
+
+	use super::*;
+	use crate::fiat_shamir::{CanSample, HasherChallenger};
+
+	#[test]
+	fn test_deterministic_prover_passes() {
+		let proof =
+			assert_transcript_deterministic::<HasherChallenger<Groestl256>, _>(|transcript| {
+				let challenge: BinaryField128b = transcript.sample();
+				transcript.message().write_scalar(challenge);
+			});
+		assert_eq!(proof.len(), 16);
+	}
+
+	#[test]
+	#[should_panic(expected = "transcripts differ at byte 0")]
+	fn test_nondeterministic_prover_panics() {
+		let mut run = 0u8;
+		assert_transcript_deterministic::<HasherChallenger<Groestl256>, _>(|transcript| {
+			run += 1;
+			transcript.message().write_bytes(&[run]);
+		});
+	}
+}
@@ -1,9 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::{
-	ext_base_mul, packed::set_packed_slice_unchecked, BinaryField128b, BinaryField16b,
-	BinaryField1b, BinaryField8b, ExtensionField, Field, PackedBinaryField2x128b, PackedExtension,
-	PackedField,
+	arch::{host_optimal_alignment, ArchOptimal, OPTIMAL_ALIGNMENT},
+	ext_base_mul, ext_base_mul_dispatch,
+	packed::set_packed_slice_unchecked,
+	BinaryField128b, BinaryField16b, BinaryField1b, BinaryField8b, ExtensionField, Field,
+	PackedBinaryField2x128b, PackedExtension, PackedField,
 };
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use rand::thread_rng;
@@ -11,30 +13,30 @@ use rand::thread_rng;
 // Constants for input sizes
 const EXT_WIDTH: usize = 64 * 1024; // Fixed extension width
 
-fn benchmark_packed_extension_mul<F>(
+type OptimalPackedBinaryField128b = <BinaryField128b as ArchOptimal>::OptimalThroughputPacked;
+
+fn benchmark_packed_extension_mul<F, PE>(
 	group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
 	label: &str,
 ) where
 	F: Field,
-	PackedBinaryField2x128b: PackedExtension<F>,
+	PE: PackedField<Scalar = BinaryField128b> + PackedExtension<F>,
 {
 	let mut rng = thread_rng();
 
+	// Keep the number of extension scalars fixed regardless of the packing width
+	let ext_width = EXT_WIDTH * PackedBinaryField2x128b::WIDTH / PE::WIDTH;
+
 	// Compute base width based on extension width and field degree
-	let base_width: usize = EXT_WIDTH / <BinaryField128b as ExtensionField<F>>::DEGREE;
+	let base_width: usize = ext_width / <BinaryField128b as ExtensionField<F>>::DEGREE;
 
 	// SIMD benchmark
 	group.bench_function(BenchmarkId::new(label, "spread"), |b| {
 		// Generate input arrays
-		let base_packed: Vec<<PackedBinaryField2x128b as PackedExtension<F>>::PackedSubfield> = (0
-			..base_width)
-			.map(|_| {
-				<<PackedBinaryField2x128b as PackedExtension<F>>::PackedSubfield>::random(&mut rng)
-			})
-			.collect();
-		let mut ext_packed: Vec<PackedBinaryField2x128b> = (0..EXT_WIDTH)
-			.map(|_| PackedBinaryField2x128b::random(&mut rng))
+		let base_packed: Vec<<PE as PackedExtension<F>>::PackedSubfield> = (0..base_width)
+			.map(|_| <<PE as PackedExtension<F>>::PackedSubfield>::random(&mut rng))
 			.collect();
+		let mut ext_packed: Vec<PE> = (0..ext_width).map(|_| PE::random(&mut rng)).collect();
 
 		b.iter(|| {
 			ext_base_mul(&mut ext_packed, &base_packed).unwrap();
@@ -42,23 +44,29 @@ fn benchmark_packed_extension_mul<F>(
 		black_box(ext_packed);
 	});
 
+	// The same loop, compiled for the widest SIMD extension detected on the host at runtime
+	group.bench_function(BenchmarkId::new(label, "spread-dispatch"), |b| {
+		let base_packed: Vec<<PE as PackedExtension<F>>::PackedSubfield> = (0..base_width)
+			.map(|_| <<PE as PackedExtension<F>>::PackedSubfield>::random(&mut rng))
+			.collect();
+		let mut ext_packed: Vec<PE> = (0..ext_width).map(|_| PE::random(&mut rng)).collect();
+
+		b.iter(|| {
+			ext_base_mul_dispatch(&mut ext_packed, &base_packed).unwrap();
+		});
+		black_box(ext_packed);
+	});
+
 	// Scalar-wise benchmark
 	group.bench_function(BenchmarkId::new(label, "scalar-wise"), |b| {
 		// Generate input arrays
-		let base_packed: Vec<<PackedBinaryField2x128b as PackedExtension<F>>::PackedSubfield> = (0
-			..base_width)
-			.map(|_| {
-				<<PackedBinaryField2x128b as PackedExtension<F>>::PackedSubfield>::random(&mut rng)
-			})
+		let base_packed: Vec<<PE as PackedExtension<F>>::PackedSubfield> = (0..base_width)
+			.map(|_| <<PE as PackedExtension<F>>::PackedSubfield>::random(&mut rng))
 			.collect();
 
-		let ext_packed: Vec<PackedBinaryField2x128b> = (0..EXT_WIDTH)
-			.map(|_| PackedBinaryField2x128b::random(&mut rng))
-			.collect();
+		let ext_packed: Vec<PE> = (0..ext_width).map(|_| PE::random(&mut rng)).collect();
 
-		let mut result: Vec<PackedBinaryField2x128b> = (0..EXT_WIDTH)
-			.map(|_| PackedBinaryField2x128b::default())
-			.collect();
+		let mut result: Vec<PE> = (0..ext_width).map(|_| PE::default()).collect();
 
 		b.iter(|| {
 			for (i, (ext, base)) in PackedField::iter_slice(&ext_packed)
@@ -81,13 +89,36 @@ fn benchmark_mul(c: &mut Criterion) {
 		.throughput(Throughput::Elements(EXT_WIDTH as u64 * PackedBinaryField2x128b::WIDTH as u64));
 
 	// Benchmark for 1b
-	benchmark_packed_extension_mul::<BinaryField1b>(&mut group, "mul_1b");
+	benchmark_packed_extension_mul::<BinaryField1b, PackedBinaryField2x128b>(&mut group, "mul_1b");
 
 	// Benchmark for 8b
-	benchmark_packed_extension_mul::<BinaryField8b>(&mut group, "mul_8b");
+	benchmark_packed_extension_mul::<BinaryField8b, PackedBinaryField2x128b>(&mut group, "mul_8b");
 
 	// Benchmark for 16b
-	benchmark_packed_extension_mul::<BinaryField16b>(&mut group, "mul_16b");
+	benchmark_packed_extension_mul::<BinaryField16b, PackedBinaryField2x128b>(
+		&mut group, "mul_16b",
+	);
+
+	group.finish();
+
+	// The same workload with the packing selected for the compilation target. The group name
+	// records the compiled and host SIMD widths, so a throughput gap caused by a build that does
+	// not enable the widest backend supported by the host is visible in the report.
+	let mut group = c.benchmark_group(format!(
+		"packed_extension_mul/optimal_{OPTIMAL_ALIGNMENT}b_host_{}b",
+		host_optimal_alignment()
+	));
+	group.throughput(Throughput::Elements((EXT_WIDTH * PackedBinaryField2x128b::WIDTH) as u64));
+
+	benchmark_packed_extension_mul::<BinaryField1b, OptimalPackedBinaryField128b>(
+		&mut group, "mul_1b",
+	);
+	benchmark_packed_extension_mul::<BinaryField8b, OptimalPackedBinaryField128b>(
+		&mut group, "mul_8b",
+	);
+	benchmark_packed_extension_mul::<BinaryField16b, OptimalPackedBinaryField128b>(
+		&mut group, "mul_16b",
+	);
 
 	group.finish();
 }
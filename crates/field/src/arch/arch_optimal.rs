@@ -67,3 +67,40 @@ cfg_if! {
 		pub type OptimalUnderlier = OptimalUnderlier128b;
 	}
 }
+
+/// Returns the widest SIMD register width in bits, among those the packed field backends can use,
+/// that the host CPU supports at runtime.
+///
+/// The optimal underlier and the SIMD implementations of the packed fields are selected at compile
+/// time from the enabled target features, so a binary built for a generic target does not use wider
+/// registers even when the host supports them. Comparing this value against [`OPTIMAL_ALIGNMENT`]
+/// detects such a mismatch; if the host value is larger, rebuilding with
+/// `RUSTFLAGS="-C target-cpu=native"` enables the wider backend.
+/// [`ext_base_mul_dispatch`](crate::ext_base_mul_dispatch) instead selects a wider code path at
+/// runtime.
+pub fn host_optimal_alignment() -> usize {
+	cfg_if! {
+		if #[cfg(target_arch = "x86_64")] {
+			if std::arch::is_x86_feature_detected!("avx512f") {
+				512
+			} else if std::arch::is_x86_feature_detected!("avx2") {
+				256
+			} else {
+				128
+			}
+		} else {
+			128
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_host_supports_compiled_alignment() {
+		// The binary can only run if the host supports the target features it was compiled with.
+		assert!(host_optimal_alignment() >= OPTIMAL_ALIGNMENT);
+	}
+}
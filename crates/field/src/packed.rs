@@ -20,7 +20,9 @@ use super::{
 	Error,
 };
 use crate::{
-	arithmetic_traits::InvertOrZero, underlier::WithUnderlier, BinaryField, Field, PackedExtension,
+	arithmetic_traits::InvertOrZero,
+	underlier::{UnderlierType, UnderlierWithBitOps, WithUnderlier},
+	BinaryField, Field, PackedExtension,
 };
 
 /// A packed field represents a vector of underlying field elements.
@@ -385,6 +387,49 @@ pub fn mul_by_subfield_scalar<P: PackedExtension<FS>, FS: Field>(val: P, multipl
 	}
 }
 
+/// Compares two packed field elements lane by lane, returning a mask whose lanes are all ones
+/// where `a` and `b` are equal and all zeros elsewhere.
+///
+/// Lanes are equal exactly when the XOR of their bits is zero. The zero test is done on the
+/// underlier, by OR-folding the bits of each lane into its lowest bit and spreading the negated
+/// result back over the lane, so no field arithmetic is performed. ANDing the underlier of a
+/// packed element with the mask selects the equal lanes.
+///
+/// The lanes of `P` must be stored as contiguous bit ranges of the underlier, as in the packed
+/// binary tower fields.
+#[inline]
+pub fn packed_eq_mask<P>(a: P, b: P) -> P
+where
+	P: PackedField + WithUnderlier<Underlier: UnderlierWithBitOps>,
+{
+	let lane_bits = P::Underlier::BITS / P::WIDTH;
+
+	// Fold the bits of each lane into its lowest bit. Right shifts by less than the lane width
+	// only bring higher bits of the same lane down to the lowest bit.
+	let mut nonzero = a.to_underlier() ^ b.to_underlier();
+	let mut shift = 1;
+	while shift < lane_bits {
+		nonzero |= nonzero >> shift;
+		shift <<= 1;
+	}
+
+	let mut lowest_bits = P::Underlier::ONE;
+	let mut shift = lane_bits;
+	while shift < P::Underlier::BITS {
+		lowest_bits |= lowest_bits << shift;
+		shift <<= 1;
+	}
+
+	// Spread the lowest bit of each equal lane over the whole lane.
+	let mut mask = !nonzero & lowest_bits;
+	let mut shift = 1;
+	while shift < lane_bits {
+		mask |= mask << shift;
+		shift <<= 1;
+	}
+	P::from_underlier(mask)
+}
+
 pub fn pack_slice<P: PackedField>(scalars: &[P::Scalar]) -> Vec<P> {
 	let mut packed_slice = vec![P::default(); scalars.len() / P::WIDTH];
 	for (i, scalar) in scalars.iter().enumerate() {
@@ -393,6 +438,35 @@ pub fn pack_slice<P: PackedField>(scalars: &[P::Scalar]) -> Vec<P> {
 	packed_slice
 }
 
+/// Packs a slice of scalars into a vector of packed field elements.
+///
+/// Unlike [`pack_slice`], which writes the scalars one at a time, this constructs each packed
+/// element from a whole chunk of `P::WIDTH` scalars.
+///
+/// Throws [`Error::MismatchedLengths`] if the number of scalars is not a multiple of `P::WIDTH`.
+pub fn pack_scalars<P: PackedField>(scalars: &[P::Scalar]) -> Result<Vec<P>, Error> {
+	Ok(packed_chunks(scalars)?.collect())
+}
+
+/// Lazily packs a slice of scalars, yielding one packed field element per chunk of `P::WIDTH`
+/// scalars.
+///
+/// This is the iterator form of [`pack_scalars`], for consumers that process the packed elements
+/// one at a time and need not allocate them all.
+///
+/// Throws [`Error::MismatchedLengths`] if the number of scalars is not a multiple of `P::WIDTH`.
+pub fn packed_chunks<P: PackedField>(
+	scalars: &[P::Scalar],
+) -> Result<impl ExactSizeIterator<Item = P> + '_, Error> {
+	if scalars.len() % P::WIDTH != 0 {
+		return Err(Error::MismatchedLengths);
+	}
+
+	Ok(scalars
+		.chunks_exact(P::WIDTH)
+		.map(|chunk| P::from_fn(|i| chunk[i])))
+}
+
 impl<F: Field> Broadcast<F> for F {
 	fn broadcast(scalar: F) -> Self {
 		scalar
@@ -478,6 +552,7 @@ impl<PT> PackedBinaryField for PT where PT: PackedField<Scalar: BinaryField> {}
 
 #[cfg(test)]
 mod tests {
+	use proptest::prelude::*;
 	use rand::{
 		distributions::{Distribution, Uniform},
 		rngs::StdRng,
@@ -688,4 +763,116 @@ mod tests {
 	fn test_iteration() {
 		run_for_all_packed_fields(&PackedFieldIterationTest);
 	}
+
+	#[test]
+	fn test_pack_scalars_rejects_partial_packed_element() {
+		let scalars = vec![BinaryField16b::ONE; PackedBinaryField8x16b::WIDTH + 1];
+		assert!(matches!(
+			pack_scalars::<PackedBinaryField8x16b>(&scalars),
+			Err(Error::MismatchedLengths)
+		));
+	}
+
+	#[test]
+	fn test_packed_chunks_rejects_partial_packed_element() {
+		let scalars = vec![BinaryField16b::ONE; 2 * PackedBinaryField8x16b::WIDTH - 1];
+		assert!(matches!(
+			packed_chunks::<PackedBinaryField8x16b>(&scalars),
+			Err(Error::MismatchedLengths)
+		));
+	}
+
+	proptest! {
+		#[test]
+		fn test_packed_chunks_matches_pack_scalars(values in any::<[u16; 64]>()) {
+			let scalars = values.map(BinaryField16b::new);
+			let chunks = packed_chunks::<PackedBinaryField8x16b>(&scalars).unwrap();
+			assert_eq!(chunks.len(), scalars.len() / PackedBinaryField8x16b::WIDTH);
+			assert_eq!(
+				chunks.collect::<Vec<_>>(),
+				pack_scalars::<PackedBinaryField8x16b>(&scalars).unwrap(),
+			);
+		}
+
+		#[test]
+		fn test_pack_scalars_matches_pack_slice(values in any::<[u16; 64]>()) {
+			let scalars = values.map(BinaryField16b::new);
+			assert_eq!(
+				pack_scalars::<PackedBinaryField8x16b>(&scalars).unwrap(),
+				pack_slice::<PackedBinaryField8x16b>(&scalars),
+			);
+			assert_eq!(
+				pack_scalars::<BinaryField16b>(&scalars).unwrap(),
+				pack_slice::<BinaryField16b>(&scalars),
+			);
+		}
+
+		#[test]
+		fn test_packed_eq_mask_matches_lanes(
+			a in any::<[u16; 64]>(),
+			b in any::<[u16; 64]>(),
+			equal in any::<[bool; 64]>(),
+		) {
+			let a = a.map(BinaryField16b::new);
+			let b = std::array::from_fn::<_, 64, _>(|i| {
+				if equal[i] { a[i] } else { BinaryField16b::new(b[i]) }
+			});
+			let a = pack_slice::<PackedBinaryField8x16b>(&a);
+			let b = pack_slice::<PackedBinaryField8x16b>(&b);
+			let mask = iter::zip(&a, &b)
+				.map(|(&a, &b)| packed_eq_mask(a, b))
+				.collect::<Vec<_>>();
+			for i in 0..64 {
+				let expected = if get_packed_slice(&a, i) == get_packed_slice(&b, i) {
+					BinaryField16b::new(u16::MAX)
+				} else {
+					BinaryField16b::ZERO
+				};
+				assert_eq!(get_packed_slice(&mask, i), expected);
+			}
+		}
+
+		#[test]
+		fn test_packed_eq_mask_matches_lanes_1b(a in any::<[bool; 128]>(), b in any::<[bool; 128]>()) {
+			let a = pack_slice::<PackedBinaryField128x1b>(&a.map(|bit| BinaryField1b::from(bit as u8)));
+			let b = pack_slice::<PackedBinaryField128x1b>(&b.map(|bit| BinaryField1b::from(bit as u8)));
+			let mask = packed_eq_mask(a[0], b[0]);
+			for i in 0..128 {
+				let expected = BinaryField1b::from((get_packed_slice(&a, i) == get_packed_slice(&b, i)) as u8);
+				assert_eq!(mask.get(i), expected);
+			}
+		}
+
+		#[test]
+		fn test_packed_eq_mask_selects_equal_lanes_128b(a in any::<[u128; 4]>(), equal in any::<[bool; 4]>()) {
+			let a = a.map(BinaryField128b::new);
+			let b = std::array::from_fn::<_, 4, _>(|i| {
+				if equal[i] { a[i] } else { a[i] + BinaryField128b::new(1 << (i * 31)) }
+			});
+			let a = pack_slice::<PackedBinaryField2x128b>(&a);
+			let b = pack_slice::<PackedBinaryField2x128b>(&b);
+			for (&a, &b) in iter::zip(&a, &b) {
+				let mask = packed_eq_mask(a, b);
+				let selected = PackedBinaryField2x128b::from_underlier(a.to_underlier() & mask.to_underlier());
+				for i in 0..2 {
+					let (expected_mask, expected_selected) = if a.get(i) == b.get(i) {
+						(BinaryField128b::new(u128::MAX), a.get(i))
+					} else {
+						(BinaryField128b::ZERO, BinaryField128b::ZERO)
+					};
+					assert_eq!(mask.get(i), expected_mask);
+					assert_eq!(selected.get(i), expected_selected);
+				}
+			}
+		}
+
+		#[test]
+		fn test_pack_scalars_matches_pack_slice_1b(values in any::<[bool; 256]>()) {
+			let scalars = values.map(|bit| BinaryField1b::from(bit as u8));
+			assert_eq!(
+				pack_scalars::<PackedBinaryField128x1b>(&scalars).unwrap(),
+				pack_slice::<PackedBinaryField128x1b>(&scalars),
+			);
+		}
+	}
 }
@@ -1,11 +1,13 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+#[cfg(feature = "rayon")]
 use binius_maybe_rayon::prelude::{
 	IndexedParallelIterator, IntoParallelRefMutIterator, ParallelIterator,
 };
 
 use crate::{Error, ExtensionField, Field, PackedExtension, PackedField};
 
+#[inline]
 pub fn ext_base_mul<PE: PackedExtension<F>, F: Field>(
 	lhs: &mut [PE],
 	rhs: &[PE::PackedSubfield],
@@ -13,6 +15,52 @@ pub fn ext_base_mul<PE: PackedExtension<F>, F: Field>(
 	ext_base_op(lhs, rhs, |_, lhs, broadcasted_rhs| PE::cast_ext(lhs.cast_base() * broadcasted_rhs))
 }
 
+/// Computes [`ext_base_mul`] with a copy of the loop compiled for the widest SIMD extension the
+/// host supports, selected at runtime.
+///
+/// The SIMD backends of the packed fields are selected at compile time, see
+/// [`host_optimal_alignment`](crate::arch::host_optimal_alignment), so a binary built for a generic
+/// target never uses AVX2 or AVX-512 in [`ext_base_mul`]. This entry point compiles the loop once
+/// per feature set and dispatches on the CPU features detected at runtime, which lets the compiler
+/// vectorize the portable arithmetic with the wider registers. The hand-written backends are still
+/// only used when enabled at compile time, so a build with `-C target-cpu=native` remains the
+/// fastest option; when it is, this function is equivalent to [`ext_base_mul`].
+pub fn ext_base_mul_dispatch<PE: PackedExtension<F>, F: Field>(
+	lhs: &mut [PE],
+	rhs: &[PE::PackedSubfield],
+) -> Result<(), Error> {
+	#[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))]
+	if std::arch::is_x86_feature_detected!("avx512f") {
+		// SAFETY: the host supports the target features the function is compiled with.
+		return unsafe { ext_base_mul_avx512::<PE, F>(lhs, rhs) };
+	}
+	#[cfg(target_arch = "x86_64")]
+	if std::arch::is_x86_feature_detected!("avx2") {
+		// SAFETY: the host supports the target features the function is compiled with.
+		return unsafe { ext_base_mul_avx2::<PE, F>(lhs, rhs) };
+	}
+	ext_base_mul(lhs, rhs)
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))]
+#[target_feature(enable = "avx512f")]
+unsafe fn ext_base_mul_avx512<PE: PackedExtension<F>, F: Field>(
+	lhs: &mut [PE],
+	rhs: &[PE::PackedSubfield],
+) -> Result<(), Error> {
+	ext_base_mul(lhs, rhs)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn ext_base_mul_avx2<PE: PackedExtension<F>, F: Field>(
+	lhs: &mut [PE],
+	rhs: &[PE::PackedSubfield],
+) -> Result<(), Error> {
+	ext_base_mul(lhs, rhs)
+}
+
+#[cfg(feature = "rayon")]
 pub fn ext_base_mul_par<PE: PackedExtension<F>, F: Field>(
 	lhs: &mut [PE],
 	rhs: &[PE::PackedSubfield],
@@ -22,6 +70,31 @@ pub fn ext_base_mul_par<PE: PackedExtension<F>, F: Field>(
 	})
 }
 
+/// Multiplies every extension field element of `lhs` by the same subfield scalar.
+///
+/// This is equivalent to [`ext_base_mul`] with a uniform `rhs`, without building the `rhs` buffer.
+pub fn ext_scale_by_base<PE: PackedExtension<F>, F: Field>(lhs: &mut [PE], scalar: F) {
+	let broadcast_scalar = PE::PackedSubfield::broadcast(scalar);
+	for lhs_elem in lhs {
+		*lhs_elem = PE::cast_ext(lhs_elem.cast_base() * broadcast_scalar);
+	}
+}
+
+/// Returns the subfield scalars corresponding to the `i`-th element of a `PE` slice, each one
+/// repeated `PE::Scalar::DEGREE` times so that it lines up with the subfield coordinates of
+/// `PE::cast_base`.
+///
+/// This relies on the memory layout guaranteed by [`PackedExtension`]: the `DEGREE` subfield
+/// coordinates of every extension scalar occupy consecutive lanes of `PE::PackedSubfield`, so that
+/// `PE::PackedSubfield::WIDTH == PE::WIDTH * PE::Scalar::DEGREE`. The binary tower packings satisfy
+/// this by construction. A custom packing that does not lay out the coordinates this way, for
+/// example one over a non-binary field with padded lanes, yields wrong results.
+///
+/// The extension degree must be a power of two, since the subfield lanes of an extension scalar
+/// are located with [`PackedField::spread`], which works on power-of-two blocks. This holds for
+/// every [`ExtensionField`] whose `DEGREE` keeps its default of `2^LOG_DEGREE`, and is checked at
+/// compile time.
+///
 /// # Safety
 ///
 /// Width of PackedSubfield is >= the width of the field implementing PackedExtension.
@@ -29,6 +102,9 @@ pub unsafe fn get_packed_subfields_at_pe_idx<PE: PackedExtension<F>, F: Field>(
 	packed_subfields: &[PE::PackedSubfield],
 	i: usize,
 ) -> PE::PackedSubfield {
+	assert_power_of_two_degree::<PE, F>();
+	debug_assert_eq!(PE::PackedSubfield::WIDTH, PE::WIDTH * PE::Scalar::DEGREE);
+
 	let bottom_most_scalar_idx = i * PE::WIDTH;
 	let bottom_most_scalar_idx_in_subfield_arr = bottom_most_scalar_idx / PE::PackedSubfield::WIDTH;
 	let bottom_most_scalar_idx_within_packed_subfield =
@@ -40,6 +116,45 @@ pub unsafe fn get_packed_subfields_at_pe_idx<PE: PackedExtension<F>, F: Field>(
 		.spread_unchecked(PE::LOG_WIDTH, block_idx)
 }
 
+/// Broadcasts the `block_idx`-th block of `PE::WIDTH` subfield scalars in `packed`, repeating
+/// each one `PE::Scalar::DEGREE` times so that it lines up with the subfield coordinates of
+/// `PE::cast_base`.
+///
+/// This is the bounds-checked form of the broadcast done by [`get_packed_subfields_at_pe_idx`],
+/// with the same layout and power-of-two degree requirements. A `PE::PackedSubfield` holds
+/// `PE::Scalar::DEGREE` such blocks.
+///
+/// # Panics
+///
+/// Panics if `block_idx` is not less than `PE::Scalar::DEGREE`.
+pub fn broadcast_subfield<PE: PackedExtension<F>, F: Field>(
+	packed: PE::PackedSubfield,
+	block_idx: usize,
+) -> PE::PackedSubfield {
+	assert_power_of_two_degree::<PE, F>();
+	assert_eq!(PE::PackedSubfield::WIDTH, PE::WIDTH * PE::Scalar::DEGREE);
+	assert!(
+		block_idx < PE::Scalar::DEGREE,
+		"block index {block_idx} is out of range for {} blocks",
+		PE::Scalar::DEGREE
+	);
+
+	// SAFETY: PE::LOG_WIDTH <= PE::PackedSubfield::LOG_WIDTH by the width check above, and
+	// block_idx < PE::Scalar::DEGREE = 2^(PE::PackedSubfield::LOG_WIDTH - PE::LOG_WIDTH).
+	unsafe { packed.spread_unchecked(PE::LOG_WIDTH, block_idx) }
+}
+
+/// Fails to compile if the extension degree of `PE::Scalar` over `F` is not `2^LOG_DEGREE`.
+const fn assert_power_of_two_degree<PE: PackedExtension<F>, F: Field>() {
+	const {
+		assert!(
+			<PE::Scalar as ExtensionField<F>>::DEGREE
+				== 1 << <PE::Scalar as ExtensionField<F>>::LOG_DEGREE,
+			"packed extension operations require a power-of-two extension degree"
+		)
+	}
+}
+
 /// Refer to the functions above for examples of closures to pass
 /// Func takes in the following parameters
 ///
@@ -51,6 +166,10 @@ pub unsafe fn get_packed_subfields_at_pe_idx<PE: PackedExtension<F>, F: Field>(
 /// broadcasted_rhs: a broadcasted version of PE::WIDTH subfield scalars
 /// with each one occurring PE::PackedSubfield::WIDTH/PE::WIDTH times in  a row
 /// such that the bits of the broadcasted scalars align with the lhs scalars
+///
+/// The broadcast assumes the [`PackedExtension`] memory layout and a power-of-two extension
+/// degree, see [`get_packed_subfields_at_pe_idx`] for the exact requirements.
+#[inline]
 pub fn ext_base_op<PE, F, Func>(
 	lhs: &mut [PE],
 	rhs: &[PE::PackedSubfield],
@@ -61,6 +180,9 @@ where
 	F: Field,
 	Func: Fn(usize, PE, PE::PackedSubfield) -> PE,
 {
+	assert_power_of_two_degree::<PE, F>();
+	debug_assert_eq!(PE::PackedSubfield::WIDTH, PE::WIDTH * PE::Scalar::DEGREE);
+
 	if lhs.len() != rhs.len() * PE::Scalar::DEGREE {
 		return Err(Error::MismatchedLengths);
 	}
@@ -76,6 +198,7 @@ where
 
 /// A multithreaded version of the funcion directly above, use for long arrays
 /// on the prover side
+#[cfg(feature = "rayon")]
 pub fn ext_base_op_par<PE, F, Func>(
 	lhs: &mut [PE],
 	rhs: &[PE::PackedSubfield],
@@ -86,6 +209,9 @@ where
 	F: Field,
 	Func: Fn(usize, PE, PE::PackedSubfield) -> PE + std::marker::Sync,
 {
+	assert_power_of_two_degree::<PE, F>();
+	debug_assert_eq!(PE::PackedSubfield::WIDTH, PE::WIDTH * PE::Scalar::DEGREE);
+
 	if lhs.len() != rhs.len() * PE::Scalar::DEGREE {
 		return Err(Error::MismatchedLengths);
 	}
@@ -104,12 +230,15 @@ where
 mod tests {
 	use proptest::prelude::*;
 
+	use super::broadcast_subfield;
+	#[cfg(feature = "rayon")]
+	use crate::ext_base_mul_par;
 	use crate::{
-		ext_base_mul, ext_base_mul_par,
+		ext_base_mul, ext_base_mul_dispatch, ext_scale_by_base,
 		packed::{get_packed_slice, pack_slice},
 		underlier::WithUnderlier,
-		BinaryField128b, BinaryField16b, BinaryField8b, PackedBinaryField16x16b,
-		PackedBinaryField2x128b, PackedBinaryField32x8b,
+		BinaryField128b, BinaryField16b, BinaryField8b, Error, ExtensionField,
+		PackedBinaryField16x16b, PackedBinaryField2x128b, PackedBinaryField32x8b, PackedField,
 	};
 
 	fn strategy_8b_scalars() -> impl Strategy<Value = [BinaryField8b; 32]> {
@@ -127,6 +256,15 @@ mod tests {
 			.prop_map(|arr| arr.map(<BinaryField128b>::from_underlier))
 	}
 
+	fn strategy_scalars<F>(len: usize) -> impl Strategy<Value = Vec<F>>
+	where
+		F: WithUnderlier + core::fmt::Debug,
+		F::Underlier: Arbitrary,
+	{
+		proptest::collection::vec(any::<F::Underlier>(), len)
+			.prop_map(|scalars| scalars.into_iter().map(F::from_underlier).collect())
+	}
+
 	proptest! {
 		#[test]
 		fn test_base_ext_mul_8(base_scalars in strategy_8b_scalars(), ext_scalars in strategy_128b_scalars()){
@@ -140,6 +278,18 @@ mod tests {
 			}
 		}
 
+		#[test]
+		fn test_base_ext_mul_dispatch(base_scalars in strategy_8b_scalars(), ext_scalars in strategy_128b_scalars()){
+			let base_packed = pack_slice::<PackedBinaryField32x8b>(&base_scalars);
+			let mut ext_packed = pack_slice::<PackedBinaryField2x128b>(&ext_scalars);
+			let mut expected = ext_packed.clone();
+
+			ext_base_mul_dispatch(&mut ext_packed, &base_packed).unwrap();
+			ext_base_mul(&mut expected, &base_packed).unwrap();
+
+			assert_eq!(ext_packed, expected);
+		}
+
 		#[test]
 		fn test_base_ext_mul_16(base_scalars in strategy_16b_scalars(), ext_scalars in strategy_128b_scalars()){
 			let base_packed = pack_slice::<PackedBinaryField16x16b>(&base_scalars);
@@ -151,8 +301,96 @@ mod tests {
 				assert_eq!(ext * *base, get_packed_slice(&ext_packed, i));
 			}
 		}
+	}
+
+	// Three subfield packed elements, so that `lhs` holds `3 * DEGREE` extension packed elements.
+	const ODD_SUBFIELD_PACKED_LEN: usize = 3;
+
+	proptest! {
+		#[test]
+		fn test_base_ext_mul_odd_packed_count(
+			base_scalars in strategy_scalars::<BinaryField8b>(
+				ODD_SUBFIELD_PACKED_LEN * PackedBinaryField32x8b::WIDTH
+			),
+			ext_scalars in strategy_scalars::<BinaryField128b>(
+				ODD_SUBFIELD_PACKED_LEN * PackedBinaryField32x8b::WIDTH
+			),
+		) {
+			let base_packed = pack_slice::<PackedBinaryField32x8b>(&base_scalars);
+			let mut ext_packed = pack_slice::<PackedBinaryField2x128b>(&ext_scalars);
+			let degree = <BinaryField128b as ExtensionField<BinaryField8b>>::DEGREE;
+			prop_assert_eq!(ext_packed.len(), ODD_SUBFIELD_PACKED_LEN * degree);
+
+			ext_base_mul(&mut ext_packed, &base_packed).unwrap();
+
+			for (i, (base, ext)) in base_scalars.iter().zip(ext_scalars).enumerate() {
+				prop_assert_eq!(ext * *base, get_packed_slice(&ext_packed, i));
+			}
+		}
+
+		#[test]
+		fn test_base_ext_mul_odd_packed_count_mismatched_lengths(
+			ext_scalars in strategy_scalars::<BinaryField128b>(
+				ODD_SUBFIELD_PACKED_LEN * PackedBinaryField32x8b::WIDTH + 2
+			),
+		) {
+			let base_packed = vec![PackedBinaryField32x8b::default(); ODD_SUBFIELD_PACKED_LEN];
+			let ext_packed = pack_slice::<PackedBinaryField2x128b>(&ext_scalars);
+			let n_packed = ext_packed.len();
+
+			for len in [n_packed - 2, n_packed] {
+				let mut lhs = ext_packed[..len].to_vec();
+				prop_assert!(matches!(
+					ext_base_mul(&mut lhs, &base_packed),
+					Err(Error::MismatchedLengths)
+				));
+			}
+		}
+	}
+
+	proptest! {
+		#[test]
+		fn test_ext_scale_by_base_matches_uniform_ext_base_mul(
+			scalar in any::<<BinaryField8b as WithUnderlier>::Underlier>(),
+			ext_scalars in strategy_128b_scalars()
+		) {
+			let scalar = BinaryField8b::from_underlier(scalar);
+			let mut ext_packed = pack_slice::<PackedBinaryField2x128b>(&ext_scalars);
+			let mut expected = ext_packed.clone();
+
+			let uniform_rhs = pack_slice::<PackedBinaryField32x8b>(&[scalar; 32]);
+			ext_base_mul(&mut expected, &uniform_rhs).unwrap();
+
+			ext_scale_by_base(&mut ext_packed, scalar);
+			prop_assert_eq!(ext_packed, expected);
+		}
+
+		#[test]
+		fn test_broadcast_subfield_matches_lane_replication(base_scalars in strategy_8b_scalars()) {
+			type PE = PackedBinaryField2x128b;
+			let degree = <BinaryField128b as ExtensionField<BinaryField8b>>::DEGREE;
+			let packed = PackedBinaryField32x8b::from_scalars(base_scalars);
+
+			for block_idx in 0..degree {
+				let broadcast = broadcast_subfield::<PE, BinaryField8b>(packed, block_idx);
+				for (lane, scalar) in broadcast.iter().enumerate() {
+					assert_eq!(scalar, base_scalars[block_idx * PE::WIDTH + lane / degree]);
+				}
+			}
+		}
+	}
 
+	#[test]
+	#[should_panic]
+	fn test_broadcast_subfield_rejects_out_of_range_block() {
+		broadcast_subfield::<PackedBinaryField2x128b, BinaryField8b>(
+			PackedBinaryField32x8b::default(),
+			16,
+		);
+	}
 
+	#[cfg(feature = "rayon")]
+	proptest! {
 		#[test]
 		fn test_base_ext_mul_par_8(base_scalars in strategy_8b_scalars(), ext_scalars in strategy_128b_scalars()){
 			let base_packed = pack_slice::<PackedBinaryField32x8b>(&base_scalars);
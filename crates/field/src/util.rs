@@ -2,10 +2,14 @@
 
 use std::iter;
 
+#[cfg(feature = "rayon")]
 use binius_maybe_rayon::prelude::*;
+#[cfg(feature = "rayon")]
 use binius_utils::checked_arithmetics::checked_int_div;
 
-use crate::{packed::get_packed_slice_unchecked, ExtensionField, Field, PackedField};
+#[cfg(feature = "rayon")]
+use crate::{packed::get_packed_slice_unchecked, PackedField};
+use crate::{ExtensionField, Field};
 
 /// Computes the inner product of two vectors without checking that the lengths are equal
 pub fn inner_product_unchecked<F, FE>(
@@ -21,6 +25,7 @@ where
 
 /// Calculate inner product for potentially big slices of xs and ys.
 /// The number of elements in xs has to be less or equal to the number of elements in ys.
+#[cfg(feature = "rayon")]
 pub fn inner_product_par<FX, PX, PY>(xs: &[PX], ys: &[PY]) -> FX
 where
 	PX: PackedField<Scalar = FX>,
@@ -79,7 +84,7 @@ pub fn powers<F: Field>(val: F) -> impl Iterator<Item = F> {
 	iter::successors(Some(F::ONE), move |&power| Some(power * val))
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rayon"))]
 mod tests {
 	use super::*;
 	use crate::PackedBinaryField4x32b;
@@ -8,7 +8,10 @@ use std::{
 };
 
 use binius_field::{Field, PackedField, TowerField};
-use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_utils::{
+	bytes::{Buf, BufMut},
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
+};
 
 use super::error::Error;
 
@@ -17,7 +20,7 @@ use super::error::Error;
 /// Arithmetic expressions are trees, where the leaves are either constants or variables, and the
 /// non-leaf nodes are arithmetic operations, such as addition, multiplication, etc. They are
 /// specific representations of multivariate polynomials.
-#[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ArithExpr<F: Field> {
 	Const(F),
 	Var(usize),
@@ -26,6 +29,101 @@ pub enum ArithExpr<F: Field> {
 	Pow(Box<ArithExpr<F>>, u64),
 }
 
+// The serialization impls are written by hand rather than derived: the derived impls recurse into
+// the boxed subexpressions with a fresh `&mut` wrapper around the buffer at every level, which never
+// terminates during monomorphization. Recursing through a type-erased buffer avoids this while
+// keeping the encoding identical to the derived one.
+impl<F: Field + SerializeBytes> SerializeBytes for ArithExpr<F> {
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		self.serialize_erased(&mut write_buf, mode)
+	}
+}
+
+impl<F: Field + DeserializeBytes> DeserializeBytes for ArithExpr<F> {
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		Self::deserialize_erased(&mut read_buf, mode)
+	}
+}
+
+impl<F: Field> ArithExpr<F> {
+	fn serialize_erased(
+		&self,
+		write_buf: &mut dyn BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError>
+	where
+		F: SerializeBytes,
+	{
+		match self {
+			Self::Const(value) => {
+				0u8.serialize(&mut *write_buf, mode)?;
+				value.serialize(write_buf, mode)
+			}
+			Self::Var(index) => {
+				1u8.serialize(&mut *write_buf, mode)?;
+				index.serialize(write_buf, mode)
+			}
+			Self::Add(x, y) => {
+				2u8.serialize(&mut *write_buf, mode)?;
+				x.serialize_erased(write_buf, mode)?;
+				y.serialize_erased(write_buf, mode)
+			}
+			Self::Mul(x, y) => {
+				3u8.serialize(&mut *write_buf, mode)?;
+				x.serialize_erased(write_buf, mode)?;
+				y.serialize_erased(write_buf, mode)
+			}
+			Self::Pow(x, exp) => {
+				4u8.serialize(&mut *write_buf, mode)?;
+				x.serialize_erased(write_buf, mode)?;
+				exp.serialize(write_buf, mode)
+			}
+		}
+	}
+
+	fn deserialize_erased(
+		read_buf: &mut dyn Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		F: DeserializeBytes,
+	{
+		let variant_index = u8::deserialize(&mut *read_buf, mode)?;
+		Ok(match variant_index {
+			0 => Self::Const(F::deserialize(read_buf, mode)?),
+			1 => Self::Var(usize::deserialize(read_buf, mode)?),
+			2 => Self::Add(
+				Box::new(Self::deserialize_erased(read_buf, mode)?),
+				Box::new(Self::deserialize_erased(read_buf, mode)?),
+			),
+			3 => Self::Mul(
+				Box::new(Self::deserialize_erased(read_buf, mode)?),
+				Box::new(Self::deserialize_erased(read_buf, mode)?),
+			),
+			4 => Self::Pow(
+				Box::new(Self::deserialize_erased(&mut *read_buf, mode)?),
+				u64::deserialize(read_buf, mode)?,
+			),
+			_ => {
+				return Err(SerializationError::UnknownEnumVariant {
+					name: "ArithExpr",
+					index: variant_index,
+				})
+			}
+		})
+	}
+}
+
 impl<F: Field + Display> Display for ArithExpr<F> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
@@ -408,4 +506,24 @@ mod tests {
 		.pow(3);
 		assert_eq!(expr.try_convert_field::<BinaryField8b>().unwrap(), expected);
 	}
+
+	#[test]
+	fn test_serialization_roundtrip() {
+		type F = BinaryField128b;
+
+		let expr = ((ArithExpr::Var(0) + ArithExpr::Const(F::new(3))) * ArithExpr::Var(2)).pow(5);
+
+		let mut buf = Vec::new();
+		expr.serialize(&mut buf, SerializationMode::CanonicalTower)
+			.unwrap();
+		let deserialized =
+			ArithExpr::<F>::deserialize(buf.as_slice(), SerializationMode::CanonicalTower).unwrap();
+		assert_eq!(deserialized, expr);
+
+		buf[0] = 5;
+		assert_matches!(
+			ArithExpr::<F>::deserialize(buf.as_slice(), SerializationMode::CanonicalTower),
+			Err(SerializationError::UnknownEnumVariant { index: 5, .. })
+		);
+	}
 }
@@ -44,6 +44,8 @@ pub enum SerializationError {
 	DeserializerNotImplented,
 	#[error("Multiple deserializers with the same name {name} has been registered")]
 	DeserializerNameConflict { name: String },
+	#[error("Invalid construction of {name}")]
+	InvalidConstruction { name: &'static str },
 	#[error("FromUtf8Error: {0}")]
 	FromUtf8Error(#[from] std::string::FromUtf8Error),
 }